@@ -0,0 +1,68 @@
+//! Example of axes added to the canvas, in addition to the default axes
+//!
+
+extern crate astrup;
+
+use std::f64;
+
+use astrup::{Axis, Chart, Figure, Line, Locator, Plot, View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..201).map(|i| -2.5 + 0.025 * f64::from(i)).collect();
+    let y_data: Vec<f64> = x_data.iter().map(|x| x * x * x - 3.0 * x).collect();
+
+    // Axes through the origin, in stead of along the edges of the canvas. The origin is in the
+    // middle of the canvas, and the labels are placed at the ends of the axes.
+    let mut x_axis = Axis::new(f64::NEG_INFINITY, 0.0, f64::INFINITY, 0.0);
+    x_axis.set_label_content("x")
+          .set_label_centroid(0.98, 0.46)
+          .set_locator(&Locator::FixedStep(1.0))
+          .set_minor_locator(Some(Locator::Subdivisions(4)));
+    let mut y_axis = Axis::new(0.0, f64::NEG_INFINITY, 0.0, f64::INFINITY);
+    y_axis.set_label_content("f(x)")
+          .set_label_angle(0.0)
+          .set_label_centroid(0.44, 0.98)
+          .set_locator(&Locator::FixedStep(2.0));
+
+    let mut origin_plot = Plot::new();
+    origin_plot.set_title("Axes through the origin")
+               .display_x_axis(false)
+               .display_y_axis(false)
+               .add_axis(&x_axis)
+               .add_axis(&y_axis)
+               .set_local_frame(0.0, 0.5, 0.0, 1.0)
+               .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    // A mirrored axis along the top of the canvas, and a reference axis at the local maximum
+    let mut top_axis = Axis::new(f64::NEG_INFINITY, f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    top_axis.set_tick_label_offset(0.03)
+            .set_positive_tick_length(0.0)
+            .set_negative_tick_length(0.01)
+            .set_minor_locator(Some(Locator::Subdivisions(2)));
+    let mut reference_axis = Axis::new(-1.0, -2.0, -1.0, 2.0);
+    reference_axis.set_color_rgb(0.8, 0.2, 0.2)
+                  .set_tick_color_rgb(0.8, 0.2, 0.2)
+                  .set_tick_label_color_rgb(0.8, 0.2, 0.2)
+                  .set_tick_label_offset(0.03)
+                  .set_label_content("local maximum")
+                  .set_label_color_rgb(0.8, 0.2, 0.2)
+                  .set_locator(&Locator::explicit(&[-2.0, 0.0, 2.0]));
+
+    let mut mirrored_plot = Plot::new();
+    mirrored_plot.set_title("Mirrored and reference axes")
+                 .set_canvas_local_frame(0.10, 0.95, 0.10, 0.85)
+                 .add_axis(&top_axis)
+                 .add_axis(&reference_axis)
+                 .set_local_frame(0.5, 1.0, 0.0, 1.0)
+                 .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&origin_plot)
+       .add_plot(&mirrored_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,61 @@
+//! Example of the default axes placed at zero, at a data value and outward from the canvas, with
+//! hidden spines and a box frame
+//!
+
+extern crate astrup;
+
+use astrup::{AxisPosition, Chart, Figure, Line, Plot, View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..201).map(|i| -2.5 + 0.025 * f64::from(i)).collect();
+    let y_data: Vec<f64> = x_data.iter().map(|x| x * x * x - 3.0 * x).collect();
+
+    // The axes cross at the origin, and there are no grid lines
+    let mut zero_plot = Plot::new();
+    zero_plot.set_title("Axes at zero")
+             .set_x_axis_position(AxisPosition::Zero)
+             .set_y_axis_position(AxisPosition::Zero)
+             .display_horizontal_gridlines(false)
+             .display_vertical_gridlines(false)
+             .set_local_frame(0.0, 0.5, 0.5, 1.0)
+             .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    // The horizontal axis at the local minimum, inside a box frame
+    let mut data_plot = Plot::new();
+    data_plot.set_title("Horizontal axis at y = -2, in a box")
+             .set_x_axis_position(AxisPosition::Data(-2.0))
+             .display_box_frame(true)
+             .set_local_frame(0.5, 1.0, 0.5, 1.0)
+             .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    // The axes are moved away from the canvas, together with their labels
+    let mut outward_plot = Plot::new();
+    outward_plot.set_title("Outward axes")
+                .set_x_label("x")
+                .set_y_label("f(x)")
+                .set_x_axis_position(AxisPosition::Outward(0.03))
+                .set_y_axis_position(AxisPosition::Outward(0.03))
+                .set_local_frame(0.0, 0.5, 0.0, 0.5)
+                .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    // Only the ticks of the vertical axis are left, and there are lines along all other sides
+    let mut spine_plot = Plot::new();
+    spine_plot.set_title("Hidden left spine")
+              .display_left_spine(false)
+              .display_top_spine(true)
+              .display_right_spine(true)
+              .set_local_frame(0.5, 1.0, 0.0, 0.5)
+              .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&zero_plot)
+       .add_plot(&data_plot)
+       .add_plot(&outward_plot)
+       .add_plot(&spine_plot)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,42 @@
+//! Example of non-linear axis transforms: symmetric log, logit, and a custom square root scale
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, Line, Plot, Scale, Scatter, View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..200).map(|i| f64::from(i) / 10.0 - 10.0).collect();
+    let cubic: Vec<f64> = x_data.iter().map(|x| x.powi(5)).collect();
+
+    let mut symlog_plot = Plot::new();
+    symlog_plot.set_title("Symmetric log")
+               .set_y_scale(Scale::SymLog(1.0))
+               .set_local_frame(0.0, 0.33, 0.0, 1.0)
+               .add_chart(&Chart::Line(Line::new(&x_data, &cubic)));
+
+    let probability: Vec<f64> = x_data.iter().map(|x| 1.0 / (1.0 + (-x).exp())).collect();
+    let mut logit_plot = Plot::new();
+    logit_plot.set_title("Logit")
+              .set_y_scale(Scale::Logit)
+              .set_local_frame(0.33, 0.67, 0.0, 1.0)
+              .add_chart(&Chart::Line(Line::new(&x_data, &probability)));
+
+    let counts: Vec<f64> = x_data.iter().map(|x| (x + 10.0).powi(2)).collect();
+    let mut sqrt_plot = Plot::new();
+    sqrt_plot.set_title("Square root")
+             .set_y_scale(Scale::custom(|val| val.sqrt(), |val| val * val))
+             .set_local_frame(0.67, 1.0, 0.0, 1.0)
+             .add_chart(&Chart::Scatter(Scatter::new(&x_data, &counts)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&symlog_plot)
+       .add_plot(&logit_plot)
+       .add_plot(&sqrt_plot)
+       .set_width(1200)
+       .set_height(400);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
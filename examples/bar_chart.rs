@@ -0,0 +1,58 @@
+//! Example of grouped, stacked, and horisontal bar charts
+//!
+
+extern crate astrup;
+
+use astrup::{Bar, BarLayout, BarOrientation, Chart, Figure, HtmlColor, Plot, View};
+
+fn main() {
+    let positions = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    let heights_1 = vec![3.0, 5.0, 2.0, 6.0, 4.0];
+    let heights_2 = vec![2.0, 4.0, 3.0, 2.0, 5.0];
+    let heights_3 = vec![1.0, 2.0, 4.0, 3.0, 1.0];
+
+    // Grouped series, automatically colored
+    let mut grouped = Bar::new(&positions, &heights_1);
+    grouped.add_series(&heights_2)
+           .add_series(&heights_3);
+
+    let mut grouped_plot = Plot::new();
+    grouped_plot.set_title("Grouped")
+                .add_chart(&Chart::Bar(grouped))
+                .set_local_frame(0.0, 0.5, 0.5, 1.0);
+
+    // Stacked series
+    let mut stacked = Bar::new(&positions, &heights_1);
+    stacked.set_layout(BarLayout::Stacked)
+           .set_bar_width(0.6)
+           .add_series(&heights_2)
+           .set_color_html(&HtmlColor::Lightskyblue);
+
+    let mut stacked_plot = Plot::new();
+    stacked_plot.set_title("Stacked")
+                .add_chart(&Chart::Bar(stacked))
+                .set_local_frame(0.5, 1.0, 0.5, 1.0);
+
+    // Horisontal bars with a highlighted bar and a non-zero baseline
+    let mut horisontal = Bar::new(&positions, &heights_1);
+    horisontal.set_orientation(BarOrientation::Horizontal)
+              .set_baseline(1.0)
+              .set_color_rgb_u8(127, 127, 127)
+              .set_bar_color_html(3, &HtmlColor::Orangered);
+
+    let mut horisontal_plot = Plot::new();
+    horisontal_plot.set_title("Horisontal")
+                   .add_chart(&Chart::Bar(horisontal))
+                   .set_local_frame(0.0, 1.0, 0.0, 0.5);
+
+    let mut fig = Figure::new();
+    fig.add_plot(&grouped_plot)
+       .add_plot(&stacked_plot)
+       .add_plot(&horisontal_plot)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,60 @@
+//! Example of vertical and horisontal box plots
+//!
+
+extern crate astrup;
+extern crate rand;
+
+use rand::distributions::{IndependentSample, Normal};
+use rand::{SeedableRng, StdRng};
+
+use astrup::{BoxOrientation, BoxPlot, Chart, Figure, Plot, View, Whiskers};
+
+fn main() {
+    // Create a seedable rng so that the samples are equal from run to run
+    let seed: Vec<usize> = vec![8, 8, 8, 8, 8, 8, 8, 8];
+    let mut seeded_rng: StdRng = SeedableRng::from_seed(seed.as_slice());
+
+    let mut groups = Vec::<Vec<f64>>::new();
+    for &(mean, std_dev) in &[(0.0, 1.0), (1.0, 2.0), (-1.0, 0.5), (2.0, 1.5)] {
+        let normal = Normal::new(mean, std_dev);
+        groups.push((0..200).map(|_| normal.ind_sample(&mut seeded_rng)).collect());
+    }
+    let labels = ["Control", "Treatment A", "Treatment B", "Treatment C"];
+
+    // Tukey whiskers with notched boxes
+    let mut vertical = BoxPlot::new(&groups[0]);
+    vertical.add_group(&groups[1])
+            .add_group(&groups[2])
+            .add_group(&groups[3])
+            .set_group_labels(&labels)
+            .set_notched(true);
+
+    let mut vertical_plot = Plot::new();
+    vertical_plot.set_title("Notched, Tukey whiskers")
+                 .add_chart(&Chart::BoxPlot(vertical))
+                 .set_local_frame(0.0, 1.0, 0.5, 1.0);
+
+    // Horisontal boxes with whiskers at the 5th and 95th percentiles
+    let mut horisontal = BoxPlot::new(&groups[0]);
+    horisontal.add_group(&groups[1])
+              .add_group(&groups[2])
+              .add_group(&groups[3])
+              .set_orientation(BoxOrientation::Horizontal)
+              .set_whiskers(Whiskers::Percentile(5.0, 95.0))
+              .set_color_rgb_u8(34, 174, 51);
+
+    let mut horisontal_plot = Plot::new();
+    horisontal_plot.set_title("Horisontal, percentile whiskers")
+                   .add_chart(&Chart::BoxPlot(horisontal))
+                   .set_local_frame(0.0, 1.0, 0.0, 0.5);
+
+    let mut fig = Figure::new();
+    fig.add_plot(&vertical_plot)
+       .add_plot(&horisontal_plot)
+       .set_width(1000)
+       .set_height(1000);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,55 @@
+//! Example of bar and scatter charts on categorical axes
+//!
+
+extern crate astrup;
+
+use astrup::{Bar, BarOrientation, CategoryOrder, Chart, Figure, Plot, Scatter, View};
+
+fn main() {
+    let fruits = ["Banana", "Apple", "Cherry", "Durian", "Elderberry", "Fig", "Grape"];
+    let sales = [42.0, 35.0, 12.0, 3.0, 8.0, 15.0, 27.0];
+
+    // The names are rotated when they would overlap, so leave some room below the canvas
+    let mut bar_plot = Plot::new();
+    bar_plot.set_title("Fruit sales")
+            .set_category_order(CategoryOrder::Ascending)
+            .set_category_padding(0.3)
+            .set_canvas_local_frame(0.10, 0.95, 0.20, 0.95)
+            .set_local_frame(0.0, 0.5, 0.5, 1.0)
+            .add_chart(&Chart::Bar(Bar::with_categories(&fruits, &sales)));
+
+    let regions = ["North", "South", "East", "West"];
+    let mut horizontal_bar = Bar::with_categories(&regions, &[3.0, 5.0, 2.0, 4.0]);
+    horizontal_bar.set_orientation(BarOrientation::Horizontal);
+    let region_order = CategoryOrder::custom(&["West", "East", "South", "North"]);
+    let mut horizontal_bar_plot = Plot::new();
+    horizontal_bar_plot.set_title("Regions")
+                       .set_category_order(region_order)
+                       .set_local_frame(0.5, 1.0, 0.5, 1.0)
+                       .add_chart(&Chart::Bar(horizontal_bar));
+
+    // Measurements by group, where every group has several samples
+    let groups = [
+        "control", "control", "control", "low dose", "low dose", "high dose", "high dose",
+        "high dose",
+    ];
+    let responses = [1.0, 1.3, 0.8, 1.9, 2.2, 3.1, 2.7, 3.4];
+    let mut scatter = Scatter::with_categories(&groups, &responses);
+    scatter.set_point_size(0.01);
+    let mut scatter_plot = Plot::new();
+    scatter_plot.set_title("Response by group")
+                .set_y_label("Response")
+                .set_local_frame(0.0, 1.0, 0.0, 0.5)
+                .add_chart(&Chart::Scatter(scatter));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&bar_plot)
+       .add_plot(&horizontal_bar_plot)
+       .add_plot(&scatter_plot)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
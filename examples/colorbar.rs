@@ -0,0 +1,68 @@
+//! Example of colorbars, beside a heatmap with a fixed value range and below an image with a
+//! logarithmic value scale
+//!
+
+extern crate astrup;
+extern crate ndarray;
+
+use ndarray::Array2;
+
+use astrup::{Chart, Colorbar, ColorbarLocation, Colormap, Figure, Heatmap, Image, Plot, Scale,
+             View};
+
+fn main() {
+    // Monthly mean temperatures in degrees Celsius, over a few years
+    let temperature = Array2::from_shape_fn((4, 12), |(year, month)| {
+        let season = (2.0 * std::f64::consts::PI * (month as f64 - 0.5) / 12.0).cos();
+        8.0 - 10.0 * season + 0.3 * year as f64
+    });
+
+    let mut heatmap = Heatmap::new(&temperature);
+    heatmap.set_colormap(Colormap::Coolwarm)
+           .set_value_range(-5.0, 20.0);
+
+    let mut temperature_colorbar = Colorbar::new();
+    temperature_colorbar.set_label("Temperature [°C]");
+
+    let mut heatmap_plot = Plot::new();
+    heatmap_plot.set_title("Monthly temperature")
+                .set_x_label("Month")
+                .set_y_label("Year")
+                .display_horizontal_gridlines(false)
+                .display_vertical_gridlines(false)
+                .set_colorbar(&temperature_colorbar)
+                .set_local_frame(0.0, 0.5, 0.0, 1.0)
+                .add_chart(&Chart::Heatmap(heatmap));
+
+    // Intensity of a point source, spanning several orders of magnitude
+    let intensity = Array2::from_shape_fn((100, 100), |(row, col)| {
+        let (x, y) = (col as f64 - 49.5, row as f64 - 49.5);
+        1.0e4 / (1.0 + x * x + y * y)
+    });
+
+    let mut image = Image::new(&intensity);
+    image.set_colormap(Colormap::Inferno)
+         .set_value_scale(Scale::Log10);
+
+    let mut intensity_colorbar = Colorbar::new();
+    intensity_colorbar.set_location(ColorbarLocation::Bottom)
+                      .set_label("Intensity");
+
+    let mut image_plot = Plot::new();
+    image_plot.set_title("Point source")
+              .display_horizontal_gridlines(false)
+              .display_vertical_gridlines(false)
+              .set_colorbar(&intensity_colorbar)
+              .set_local_frame(0.5, 1.0, 0.0, 1.0)
+              .add_chart(&Chart::Image(image));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&heatmap_plot)
+       .add_plot(&image_plot)
+       .set_width(1200)
+       .set_height(600);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,58 @@
+//! Example of measurements with error bars, attached to a scatter chart and a line chart, and
+//! drawn on their own
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, ErrorBar, Figure, Line, Plot, Scatter, View};
+
+fn main() {
+    let time = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let position = vec![1.1, 3.8, 9.2, 15.7, 25.3, 35.9];
+    let position_error = vec![0.4, 0.6, 0.9, 1.1, 1.6, 2.0];
+    let time_error = vec![0.1, 0.1, 0.15, 0.15, 0.2, 0.2];
+
+    let mut measurement_errors = ErrorBar::new(&time, &position);
+    measurement_errors.set_x_errors(&time_error)
+                      .set_y_errors(&position_error);
+    let mut measurements = Scatter::new(&time, &position);
+    measurements.set_point_size(0.006)
+                .set_error_bars(&measurement_errors);
+
+    let model_position: Vec<f64> = time.iter().map(|t| t * t).collect();
+    let model_lower = vec![0.2, 0.5, 0.8, 1.2, 1.5, 1.9];
+    let model_upper = vec![0.5, 1.0, 1.6, 2.3, 3.1, 4.0];
+    let mut model_errors = ErrorBar::new(&time, &model_position);
+    model_errors.set_y_errors_asymmetric(&model_lower, &model_upper)
+                .set_cap_width(0.02);
+    let mut model = Line::new(&time, &model_position);
+    model.set_error_bars(&model_errors);
+
+    let mut plot = Plot::new();
+    plot.set_title("Uniformly accelerated motion")
+        .set_x_label("Time")
+        .set_y_label("Position")
+        .set_local_frame(0.0, 1.0, 0.5, 1.0)
+        .add_chart(&Chart::Line(model))
+        .add_chart(&Chart::Scatter(measurements));
+
+    let mut standalone_errors = ErrorBar::new(&time, &position);
+    standalone_errors.set_y_errors(&position_error)
+                     .set_line_width(0.004)
+                     .set_cap_width(0.0);
+
+    let mut standalone_plot = Plot::new();
+    standalone_plot.set_title("Error bars without points")
+                   .set_local_frame(0.0, 1.0, 0.0, 0.5)
+                   .add_chart(&Chart::ErrorBar(standalone_errors));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&plot)
+       .add_plot(&standalone_plot)
+       .set_width(800)
+       .set_height(1000);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -11,7 +11,7 @@ use ndarray::Array;
 use rand::distributions::{IndependentSample, Normal};
 use rand::{SeedableRng, StdRng};
 
-use astrup::{Chart, Figure, HtmlColor, Line, Plot, Scatter, View};
+use astrup::{Chart, Figure, HtmlColor, Legend, Line, Plot, Scatter, View};
 
 fn main() {
     // Create data and collect them in an ndarray array
@@ -19,16 +19,20 @@ fn main() {
     let x_data =
         Array::from_iter((0..num_samples).map(|x| -5.0 + 10.0 * (x as f64) / num_samples as f64));
 
-    // Construct a line plot
+    // Construct a line plot, with a legend in four columns
+    let mut legend = Legend::new();
+    legend.set_num_columns(4);
     let mut line_plot = Plot::new();
     line_plot.set_y_min(-1.2)
-             .set_local_frame(0.0, 0.7, 0.51, 1.0);
+             .set_local_frame(0.0, 0.7, 0.51, 1.0)
+             .set_legend(&legend);
 
     // Create multiple lines and append them to the line plot
     for shift in 0..8 {
         let y_data = Array::from_iter((0..num_samples).map(|i| (x_data[i] - shift as f64 * PI / 8.0).sin()));
 
-        let line1 = Line::new(&x_data, &y_data);
+        let mut line1 = Line::new(&x_data, &y_data);
+        line1.set_label(&format!("shift {}π/8", shift));
         line_plot.add_chart(&Chart::Line(line1));
     }
 
@@ -0,0 +1,56 @@
+//! Example of plots placed in a figure grid, with a plot spanning two columns, and with rows and
+//! columns of different sizes
+//!
+
+extern crate astrup;
+
+use std::f64::consts::PI;
+
+use astrup::{Chart, Figure, Line, Plot, Scatter, StrokeStyle, View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..201).map(|i| 0.05 * f64::from(i)).collect();
+    let signal: Vec<f64> = x_data.iter().map(|x| (-0.2 * x).exp() * (3.0 * x).sin()).collect();
+    let envelope: Vec<f64> = x_data.iter().map(|x| (-0.2 * x).exp()).collect();
+
+    // The signal spans the whole top row
+    let mut envelope_line = Line::new(&x_data, &envelope);
+    envelope_line.set_stroke_style(StrokeStyle::Dashed);
+    let mut signal_plot = Plot::new();
+    signal_plot.set_title("Damped oscillation")
+               .add_chart(&Chart::Line(Line::new(&x_data, &signal)))
+               .add_chart(&Chart::Line(envelope_line));
+
+    // The phase portrait is placed in the lower left cell
+    let velocity: Vec<f64> = x_data.iter()
+        .map(|x| (-0.2 * x).exp() * (3.0 * (3.0 * x).cos() - 0.2 * (3.0 * x).sin()))
+        .collect();
+    let mut phase_plot = Plot::new();
+    phase_plot.set_title("Phase portrait")
+              .set_x_label("position")
+              .set_y_label("velocity")
+              .add_chart(&Chart::Line(Line::new(&signal, &velocity)));
+
+    // The peaks are placed in the lower right cell
+    let peaks_x: Vec<f64> = (0..5).map(|i| (0.5 + 2.0 * f64::from(i)) * PI / 3.0).collect();
+    let peaks_y: Vec<f64> = peaks_x.iter().map(|x| (-0.2 * x).exp()).collect();
+    let mut peak_plot = Plot::new();
+    peak_plot.set_title("Peaks")
+             .add_chart(&Chart::Scatter(Scatter::new(&peaks_x, &peaks_y)));
+
+    let mut fig = Figure::new();
+    fig.set_title("Grid layout")
+       .set_grid(2, 2)
+       .set_grid_height_ratios(&[2.0, 3.0])
+       .set_grid_width_ratios(&[3.0, 2.0])
+       .set_grid_spacing(0.02, 0.02)
+       .add_plot_spanning(&signal_plot, 0, 0, 1, 2)
+       .add_plot_at(&phase_plot, 1, 0)
+       .add_plot_at(&peak_plot, 1, 1)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
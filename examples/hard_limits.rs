@@ -0,0 +1,38 @@
+//! Example of soft and hard data ranges
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, Line, Plot, View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..500).map(|i| f64::from(i) * 0.02).collect();
+    let y_data: Vec<f64> = x_data.iter().map(|x| x.sin() * x).collect();
+
+    // The range is extended to the nearest nice marks
+    let mut soft_plot = Plot::new();
+    soft_plot.set_title("Soft limits")
+             .set_x_range(1.3, 7.7)
+             .set_y_range(-3.3, 5.5)
+             .set_local_frame(0.0, 0.5, 0.0, 1.0)
+             .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    // The range is used exactly as it is, and the line is cut at the canvas boundary
+    let mut hard_plot = Plot::new();
+    hard_plot.set_title("Hard limits")
+             .set_x_range(1.3, 7.7)
+             .set_y_range(-3.3, 5.5)
+             .set_hard_limits(true)
+             .set_local_frame(0.5, 1.0, 0.0, 1.0)
+             .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&soft_plot)
+       .add_plot(&hard_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
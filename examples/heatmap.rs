@@ -0,0 +1,43 @@
+//! Example of a confusion matrix displayed as an annotated heatmap
+//!
+
+extern crate astrup;
+extern crate ndarray;
+
+use ndarray::arr2;
+
+use astrup::{Chart, Colormap, Figure, Heatmap, Plot, View};
+
+fn main() {
+    let confusion_matrix = arr2(&[
+        [0.86, 0.09, 0.03, 0.02],
+        [0.12, 0.74, 0.10, 0.04],
+        [0.02, 0.08, 0.81, 0.09],
+        [0.01, 0.03, 0.14, 0.82],
+    ]);
+    let classes = ["cat", "dog", "fox", "owl"];
+
+    let mut heatmap = Heatmap::new(&confusion_matrix);
+    heatmap.set_row_labels(&classes)
+           .set_column_labels(&classes)
+           .set_colormap(Colormap::Plasma)
+           .set_value_range(0.0, 1.0)
+           .display_annotations(true);
+
+    let mut plot = Plot::new();
+    plot.set_title("Confusion matrix")
+        .set_x_label("Predicted")
+        .set_y_label("True")
+        .display_horizontal_gridlines(false)
+        .display_vertical_gridlines(false)
+        .add_chart(&Chart::Heatmap(heatmap));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&plot)
+       .set_width(800)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,77 @@
+//! Example of histograms with different binning rules, normalization, and rendering styles
+//!
+
+extern crate astrup;
+extern crate ndarray;
+extern crate rand;
+
+use ndarray::Array;
+use rand::distributions::{IndependentSample, Normal};
+use rand::{SeedableRng, StdRng};
+
+use astrup::{Bins, Chart, Figure, Histogram, HistogramStyle, HtmlColor, Plot, View};
+
+fn main() {
+    // Create a seedable rng so that the samples are equal from run to run
+    let seed: Vec<usize> = vec![8, 8, 8, 8, 8, 8, 8, 8];
+    let mut seeded_rng: StdRng = SeedableRng::from_seed(seed.as_slice());
+
+    let normal = Normal::new(0.0, 1.0);
+    let samples: Vec<f64> = (0..1000)
+        .map(|_| normal.ind_sample(&mut seeded_rng) as f64)
+        .collect();
+    let samples_array = Array::from_vec(samples.clone());
+
+    // Default binning (Sturges)
+    let sturges = Histogram::new(&samples);
+
+    let mut sturges_plot = Plot::new();
+    sturges_plot.set_title("Sturges")
+                .add_chart(&Chart::Histogram(sturges))
+                .set_local_frame(0.0, 0.5, 0.5, 1.0);
+
+    // Freedman-Diaconis binning, normalized to a density
+    let mut density = Histogram::new(&samples_array);
+    density.set_bins(Bins::FreedmanDiaconis)
+           .set_density(true)
+           .set_color_html(&HtmlColor::Seagreen);
+
+    let mut density_plot = Plot::new();
+    density_plot.set_title("Freedman-Diaconis density")
+                .add_chart(&Chart::Histogram(density))
+                .set_local_frame(0.5, 1.0, 0.5, 1.0);
+
+    // Fixed bin width, drawn as an outline
+    let mut step = Histogram::new(&samples);
+    step.set_bins(Bins::Width(0.25))
+        .set_style(HistogramStyle::Step);
+
+    let mut step_plot = Plot::new();
+    step_plot.set_title("Step")
+             .add_chart(&Chart::Histogram(step))
+             .set_local_frame(0.0, 0.5, 0.0, 0.5);
+
+    // Explicit edges, cumulative density
+    let mut cumulative = Histogram::new(&samples);
+    cumulative.set_bins(Bins::Edges(vec![-3.0, -2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 3.0]))
+              .set_density(true)
+              .set_cumulative(true)
+              .set_color_html(&HtmlColor::Darkorange);
+
+    let mut cumulative_plot = Plot::new();
+    cumulative_plot.set_title("Cumulative")
+                   .add_chart(&Chart::Histogram(cumulative))
+                   .set_local_frame(0.5, 1.0, 0.0, 0.5);
+
+    let mut fig = Figure::new();
+    fig.add_plot(&sturges_plot)
+       .add_plot(&density_plot)
+       .add_plot(&step_plot)
+       .add_plot(&cumulative_plot)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,69 @@
+//! Example of scalar and RGB images, with different interpolation and origin
+//!
+
+extern crate astrup;
+extern crate ndarray;
+
+use ndarray::{Array2, Array3};
+
+use astrup::{Chart, Colormap, Figure, Image, ImageInterpolation, ImageOrigin, Plot, View};
+
+fn main() {
+    // A small 2D gaussian bump
+    let bump = Array2::from_shape_fn((16, 16), |(row, col)| {
+        let y_coord = (row as f64 - 7.5) / 4.0;
+        let x_coord = (col as f64 - 7.5) / 4.0;
+        (-(x_coord * x_coord + y_coord * y_coord)).exp()
+    });
+
+    let nearest = Image::new(&bump);
+
+    let mut nearest_plot = Plot::new();
+    nearest_plot.set_title("Nearest")
+                .add_chart(&Chart::Image(nearest))
+                .set_local_frame(0.0, 0.5, 0.5, 1.0);
+
+    let mut bilinear = Image::new(&bump);
+    bilinear.set_interpolation(ImageInterpolation::Bilinear)
+            .set_colormap(Colormap::Magma)
+            .set_extent(-1.0, 1.0, -1.0, 1.0);
+
+    let mut bilinear_plot = Plot::new();
+    bilinear_plot.set_title("Bilinear")
+                 .add_chart(&Chart::Image(bilinear))
+                 .set_local_frame(0.5, 1.0, 0.5, 1.0);
+
+    // An RGB gradient, with red increasing along the rows and blue along the columns
+    let gradient = Array3::from_shape_fn((8, 8, 3), |(row, col, channel)| match channel {
+        0 => (row * 32) as u8,
+        2 => (col * 32) as u8,
+        _ => 64,
+    });
+
+    let upper = Image::with_rgb_u8(&gradient);
+
+    let mut upper_plot = Plot::new();
+    upper_plot.set_title("Origin upper")
+              .add_chart(&Chart::Image(upper))
+              .set_local_frame(0.0, 0.5, 0.0, 0.5);
+
+    let mut lower = Image::with_rgb_u8(&gradient);
+    lower.set_origin(ImageOrigin::Lower);
+
+    let mut lower_plot = Plot::new();
+    lower_plot.set_title("Origin lower")
+              .add_chart(&Chart::Image(lower))
+              .set_local_frame(0.5, 1.0, 0.0, 0.5);
+
+    let mut fig = Figure::new();
+    fig.add_plot(&nearest_plot)
+       .add_plot(&bilinear_plot)
+       .add_plot(&upper_plot)
+       .add_plot(&lower_plot)
+       .set_width(1000)
+       .set_height(1000);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,48 @@
+//! Example of inverted axes, with depth increasing downwards, and with temperature decreasing to
+//! the right and brightness increasing upwards, as in a Hertzsprung-Russell diagram
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, Line, Plot, Scatter, View};
+
+fn main() {
+    // Water temperature in degrees Celsius, down to 200 meters below the surface
+    let depth: Vec<f64> = (0..41).map(|i| 5.0 * f64::from(i)).collect();
+    let water_temperature: Vec<f64> = depth.iter()
+        .map(|depth| 4.0 + 14.0 / (1.0 + ((depth - 60.0) / 12.0).exp()))
+        .collect();
+
+    let mut depth_plot = Plot::new();
+    depth_plot.set_title("Temperature profile")
+              .set_x_label("Temperature [°C]")
+              .set_y_label("Depth [m]")
+              .invert_y_axis(true)
+              .set_local_frame(0.0, 0.5, 0.0, 1.0)
+              .add_chart(&Chart::Line(Line::new(&water_temperature, &depth)));
+
+    // Surface temperature in kelvin, and absolute magnitude, of a few bright stars
+    let star_temperature = vec![9940.0, 5772.0, 3600.0, 4286.0, 11000.0, 7600.0, 3500.0, 25000.0];
+    let magnitude = vec![1.42, 4.83, -5.85, -0.31, -6.98, 2.21, 2.0, -3.9];
+    let mut stars = Scatter::new(&star_temperature, &magnitude);
+    stars.set_point_size(0.008);
+
+    let mut star_plot = Plot::new();
+    star_plot.set_title("Bright stars")
+             .set_x_label("Temperature [K]")
+             .set_y_label("Absolute magnitude")
+             .invert_x_axis(true)
+             .invert_y_axis(true)
+             .set_local_frame(0.5, 1.0, 0.0, 1.0)
+             .add_chart(&Chart::Scatter(stars));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&depth_plot)
+       .add_plot(&star_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -15,7 +15,7 @@ extern crate serde_derive;
 use std::path::Path;
 use failure::Error;
 
-use astrup::{Chart, Figure, Line, LineStyle, Plot, Scatter, StrokeStyle, View};
+use astrup::{Chart, Figure, FillBetween, Line, LineStyle, Plot, Scatter, View};
 
 #[derive(Debug, Deserialize)]
 struct SurvivalRecord {
@@ -85,18 +85,14 @@ fn survival_charts(
     (survival_line, censored)
 }
 
-fn ci_charts(data: &[SurvivalRecord], stage: u8, red: f32, green: f32, blue: f32) -> (Line, Line) {
+fn ci_chart(data: &[SurvivalRecord], stage: u8, red: f32, green: f32, blue: f32) -> FillBetween {
     let (time, _, lower_ci, upper_ci) = extract_data(data, stage, 1);
 
-    let mut lower_line = Line::new(&time, &lower_ci);
-    lower_line.set_color_rgb(red / 255.0, green / 255.0, blue / 255.0)
-              .set_line_style(LineStyle::RightStair)
-              .set_stroke_style(StrokeStyle::Dotted);
-    let mut upper_line = Line::new(&time, &upper_ci);
-    upper_line.set_color_rgb(red / 255.0, green / 255.0, blue / 255.0)
-              .set_line_style(LineStyle::RightStair)
-              .set_stroke_style(StrokeStyle::Dotted);
-    (lower_line.clone(), upper_line.clone())
+    let mut ci_band = FillBetween::new(&time, &lower_ci, &upper_ci);
+    ci_band.set_color_rgb(red / 255.0, green / 255.0, blue / 255.0)
+           .set_line_style(LineStyle::RightStair)
+           .set_opacity(0.25);
+    ci_band
 }
 
 fn main() {
@@ -121,19 +117,16 @@ fn main() {
                          .set_y_label("Survival")
                          .set_local_frame(0.0, 1.0, 0.5, 1.0);
 
-            let (lower_stage_1, upper_stage_1) = ci_charts(&data, 1, 224.0, 52.0, 11.0);
-            //let (surv_stage_2, cens_stage_2) = survival_charts(&data, 2, 23.0, 108.0, 190.0);
-            //let (surv_stage_3, cens_stage_3) = survival_charts(&data, 3, 255.0, 200.0, 14.0);
-            let (lower_stage_4, upper_stage_4) = ci_charts(&data, 4, 34.0, 174.0, 51.0);
+            let ci_stage_1 = ci_chart(&data, 1, 224.0, 52.0, 11.0);
+            let ci_stage_4 = ci_chart(&data, 4, 34.0, 174.0, 51.0);
 
+            // The bands are added first, so that the lines are drawn on top of them
             let mut ci_plot = Plot::new();
-            ci_plot.add_chart(&Chart::Line(surv_stage_1))
-                   .add_chart(&Chart::Line(lower_stage_1))
-                   .add_chart(&Chart::Line(upper_stage_1))
+            ci_plot.add_chart(&Chart::FillBetween(ci_stage_1))
+                   .add_chart(&Chart::FillBetween(ci_stage_4))
+                   .add_chart(&Chart::Line(surv_stage_1))
                    .add_chart(&Chart::Scatter(cens_stage_1))
                    .add_chart(&Chart::Line(surv_stage_4))
-                   .add_chart(&Chart::Line(lower_stage_4))
-                   .add_chart(&Chart::Line(upper_stage_4))
                    .add_chart(&Chart::Scatter(cens_stage_4))
                    .set_x_label("Time")
                    .set_y_label("Survival")
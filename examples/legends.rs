@@ -0,0 +1,63 @@
+//! Example of legends inside and outside of the canvas
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, FillBetween, Legend, LegendLocation, Line, Plot, Scatter, StrokeStyle,
+             View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..101).map(|i| 0.05 * f64::from(i)).collect();
+    let growth: Vec<f64> = x_data.iter().map(|x| x * x).collect();
+    let decay: Vec<f64> = x_data.iter().map(|x| 25.0 * (-x).exp()).collect();
+    let samples_x: Vec<f64> = (0..11).map(|i| 0.5 * f64::from(i)).collect();
+    let samples_y: Vec<f64> = samples_x.iter().map(|x| x * x + (3.0 * x).sin()).collect();
+    let decay_lower: Vec<f64> = decay.iter().map(|y| 0.8 * y).collect();
+    let decay_upper: Vec<f64> = decay.iter().map(|y| 1.2 * y).collect();
+
+    let mut growth_line = Line::new(&x_data, &growth);
+    growth_line.set_label("x²");
+    let mut decay_line = Line::new(&x_data, &decay);
+    decay_line.set_label("25 exp(-x)")
+              .set_stroke_style(StrokeStyle::Dashed);
+    let mut decay_band = FillBetween::new(&x_data, &decay_lower, &decay_upper);
+    decay_band.set_label("±20 %");
+    let mut samples = Scatter::new(&samples_x, &samples_y);
+    samples.set_label("measured")
+           .set_point_size(0.006);
+
+    // The legend is placed in the corner with the fewest data points
+    let mut best_plot = Plot::new();
+    best_plot.set_title("Best placement")
+             .display_legend(true)
+             .set_local_frame(0.0, 0.5, 0.0, 1.0)
+             .add_chart(&Chart::Line(growth_line.clone()))
+             .add_chart(&Chart::FillBetween(decay_band.clone()))
+             .add_chart(&Chart::Line(decay_line.clone()))
+             .add_chart(&Chart::Scatter(samples.clone()));
+
+    // A larger legend outside of the canvas, without a frame
+    let mut outside_legend = Legend::new();
+    outside_legend.set_location(LegendLocation::OutsideRight)
+                  .display_frame(false)
+                  .set_font_size(0.025);
+
+    let mut outside_plot = Plot::new();
+    outside_plot.set_title("Outside the canvas")
+                .set_legend(&outside_legend)
+                .set_local_frame(0.5, 1.0, 0.0, 1.0)
+                .add_chart(&Chart::Line(growth_line))
+                .add_chart(&Chart::FillBetween(decay_band))
+                .add_chart(&Chart::Line(decay_line))
+                .add_chart(&Chart::Scatter(samples));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&best_plot)
+       .add_plot(&outside_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
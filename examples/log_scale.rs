@@ -0,0 +1,40 @@
+//! Example of power laws on logarithmic axes
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, Line, Plot, Scale, Scatter, View};
+
+fn main() {
+    let x_data: Vec<f64> = (1..200).map(|i| f64::from(i) * 0.5).collect();
+    let quadratic: Vec<f64> = x_data.iter().map(|x| x * x).collect();
+    let cubic: Vec<f64> = x_data.iter().map(|x| x.powi(3)).collect();
+    let exponential: Vec<f64> = x_data.iter().map(|x| (x / 10.0).exp()).collect();
+
+    let mut log_log_plot = Plot::new();
+    log_log_plot.set_title("Power laws")
+                .set_x_label("x")
+                .set_x_scale(Scale::Log10)
+                .set_y_scale(Scale::Log10)
+                .display_log_subdivisions(true)
+                .set_local_frame(0.0, 0.5, 0.0, 1.0)
+                .add_chart(&Chart::Line(Line::new(&x_data, &quadratic)))
+                .add_chart(&Chart::Line(Line::new(&x_data, &cubic)));
+
+    let mut semi_log_plot = Plot::new();
+    semi_log_plot.set_title("Exponential growth")
+                 .set_x_label("x")
+                 .set_y_scale(Scale::Ln)
+                 .set_local_frame(0.5, 1.0, 0.0, 1.0)
+                 .add_chart(&Chart::Scatter(Scatter::new(&x_data, &exponential)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&log_log_plot)
+       .add_plot(&semi_log_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
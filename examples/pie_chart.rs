@@ -0,0 +1,46 @@
+//! Example of a pie chart and a donut chart side by side
+//!
+
+extern crate astrup;
+
+use std::f64::consts::PI;
+
+use astrup::{Chart, Figure, Pie, PieDirection, Plot, View};
+
+fn main() {
+    let languages = ["Rust", "C", "C++", "Python", "Other"];
+    let lines_of_code = vec![48.0, 21.0, 12.0, 14.0, 5.0];
+
+    let mut pie = Pie::new(&lines_of_code);
+    pie.set_labels(&languages)
+       .set_start_angle(PI / 2.0)
+       .set_direction(PieDirection::Clockwise)
+       .set_explode(0, 0.1);
+
+    let mut donut = Pie::new(&lines_of_code);
+    donut.set_labels(&languages)
+         .set_start_angle(PI / 2.0)
+         .set_direction(PieDirection::Clockwise)
+         .set_inner_radius(0.5)
+         .set_percentage_precision(0);
+
+    let mut pie_plot = Plot::new();
+    pie_plot.set_title("Pie")
+            .set_local_frame(0.0, 0.5, 0.0, 1.0)
+            .add_chart(&Chart::Pie(pie));
+
+    let mut donut_plot = Plot::new();
+    donut_plot.set_title("Donut")
+              .set_local_frame(0.5, 1.0, 0.0, 1.0)
+              .add_chart(&Chart::Pie(donut));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&pie_plot)
+       .add_plot(&donut_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,58 @@
+//! Example of charts drawn against secondary axes, with data ranges of their own
+//!
+
+extern crate astrup;
+
+use astrup::{AxisPair, Chart, Figure, Formatter, Line, Plot, Scatter, View};
+
+fn main() {
+    // Temperature in degrees Celsius, and load in megawatts, every hour through two days
+    let hours: Vec<f64> = (0..49).map(f64::from).collect();
+    let temperature: Vec<f64> = hours.iter()
+        .map(|hour| 12.0 + 6.0 * ((hour - 9.0) * 2.0 * std::f64::consts::PI / 24.0).sin())
+        .collect();
+    let load: Vec<f64> = hours.iter()
+        .map(|hour| 1800.0 - 450.0 * ((hour - 3.0) * 2.0 * std::f64::consts::PI / 24.0).cos())
+        .collect();
+
+    let mut temperature_line = Line::new(&hours, &temperature);
+    temperature_line.set_color_rgb(0.8, 0.3, 0.1);
+    let mut load_line = Line::new(&hours, &load);
+    load_line.set_color_rgb(0.1, 0.3, 0.8)
+             .set_axis_pair(AxisPair::BottomRight);
+
+    let mut twin_plot = Plot::new();
+    twin_plot.set_title("Temperature and load")
+             .set_x_label("Hour")
+             .set_y_label("Temperature [°C]")
+             .set_secondary_y_label("Load [MW]")
+             .set_secondary_y_tick_formatter(Formatter::Decimals(0))
+             .set_local_frame(0.0, 0.5, 0.0, 1.0)
+             .add_chart(&Chart::Line(temperature_line))
+             .add_chart(&Chart::Line(load_line));
+
+    // The same samples against days on the top axis
+    let days: Vec<f64> = hours.iter().map(|hour| hour / 24.0).collect();
+    let mut daily_scatter = Scatter::new(&days, &temperature);
+    daily_scatter.set_color_rgb(0.8, 0.3, 0.1)
+                 .set_point_size(0.004)
+                 .set_axis_pair(AxisPair::TopLeft);
+
+    let mut top_plot = Plot::new();
+    top_plot.set_title("Hours and days")
+            .set_x_label("Hour")
+            .set_secondary_x_label("Day")
+            .set_local_frame(0.5, 1.0, 0.0, 1.0)
+            .add_chart(&Chart::Line(Line::new(&hours, &temperature)))
+            .add_chart(&Chart::Scatter(daily_scatter));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&twin_plot)
+       .add_plot(&top_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
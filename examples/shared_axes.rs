@@ -0,0 +1,50 @@
+//! Example of plots that share their axes in a figure grid. The residuals below the signal share
+//! its horizontal range, and the residuals against the fitted values share their vertical range.
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, Line, Plot, Scatter, View};
+
+fn main() {
+    // A measured signal, and a straight line fitted to it
+    let time: Vec<f64> = (0..60).map(|i| 0.25 * f64::from(i)).collect();
+    let measured: Vec<f64> = time.iter()
+        .map(|t| 1.5 + 0.8 * t + 0.6 * (2.3 * t).sin())
+        .collect();
+    let fitted: Vec<f64> = time.iter().map(|t| 1.5 + 0.8 * t).collect();
+    let residuals: Vec<f64> = measured.iter().zip(&fitted).map(|(m, f)| m - f).collect();
+
+    let mut signal_plot = Plot::new();
+    signal_plot.set_title("Signal")
+               .set_y_label("value")
+               .add_chart(&Chart::Scatter(Scatter::new(&time, &measured)))
+               .add_chart(&Chart::Line(Line::new(&time, &fitted)));
+
+    // The horizontal range is shared with the signal, and its tick labels are hidden there
+    let mut residual_plot = Plot::new();
+    residual_plot.set_x_label("time")
+                 .set_y_label("residual")
+                 .add_chart(&Chart::Scatter(Scatter::new(&time, &residuals)));
+
+    // The vertical range is shared with the residuals, and its tick labels are hidden here
+    let mut fitted_plot = Plot::new();
+    fitted_plot.set_x_label("fitted value")
+               .add_chart(&Chart::Scatter(Scatter::new(&fitted, &residuals)));
+
+    let mut fig = Figure::new();
+    fig.set_grid(2, 2)
+       .set_grid_height_ratios(&[3.0, 1.0])
+       .set_grid_width_ratios(&[3.0, 1.0])
+       .set_grid_shared_x(true)
+       .set_grid_shared_y(true)
+       .add_plot_at(&signal_plot, 0, 0)
+       .add_plot_at(&residual_plot, 1, 0)
+       .add_plot_at(&fitted_plot, 1, 1)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
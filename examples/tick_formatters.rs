@@ -0,0 +1,53 @@
+//! Example of tick labels written by the built-in and custom formatters
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, Formatter, Line, Plot, View};
+
+fn main() {
+    let days: Vec<f64> = (0..365).map(f64::from).collect();
+    let visitors: Vec<f64> = days.iter()
+        .map(|day| 2.5e6 + 1.5e6 * (day * 2.0 * std::f64::consts::PI / 365.0).sin())
+        .collect();
+    let conversion: Vec<f64> = days.iter()
+        .map(|day| 0.04 + 0.03 * (day / 60.0).cos())
+        .collect();
+
+    let mut visitor_plot = Plot::new();
+    visitor_plot.set_title("Visitors")
+                .set_x_tick_formatter(Formatter::custom(|day| format!("day {}", day)))
+                .set_y_tick_formatter(Formatter::SiPrefix(1))
+                .set_local_frame(0.0, 0.5, 0.5, 1.0)
+                .add_chart(&Chart::Line(Line::new(&days, &visitors)));
+
+    let mut scientific_plot = Plot::new();
+    scientific_plot.set_title("Visitors, scientific")
+                   .set_y_tick_formatter(Formatter::Scientific(1))
+                   .set_local_frame(0.5, 1.0, 0.5, 1.0)
+                   .add_chart(&Chart::Line(Line::new(&days, &visitors)));
+
+    let mut conversion_plot = Plot::new();
+    conversion_plot.set_title("Conversion rate")
+                   .set_y_tick_formatter(Formatter::Percent(0))
+                   .set_local_frame(0.0, 0.5, 0.0, 0.5)
+                   .add_chart(&Chart::Line(Line::new(&days, &conversion)));
+
+    let mut engineering_plot = Plot::new();
+    engineering_plot.set_title("Visitors, engineering")
+                    .set_y_tick_formatter(Formatter::Engineering(1))
+                    .set_local_frame(0.5, 1.0, 0.0, 0.5)
+                    .add_chart(&Chart::Line(Line::new(&days, &visitors)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&visitor_plot)
+       .add_plot(&scientific_plot)
+       .add_plot(&conversion_plot)
+       .add_plot(&engineering_plot)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
@@ -0,0 +1,47 @@
+//! Example of major and minor ticks placed by tick locators
+//!
+
+extern crate astrup;
+
+use astrup::{Chart, Figure, Line, Locator, Plot, Scale, View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..500).map(|i| f64::from(i) * 0.02).collect();
+    let y_data: Vec<f64> = x_data.iter().map(|x| x.sin() * x).collect();
+
+    // Ticks at every multiple of pi, with four minor ticks in between, and at most four ticks on
+    // the vertical axis
+    let mut linear_plot = Plot::new();
+    linear_plot.set_title("Fixed step and minor ticks")
+               .set_x_tick_locator(Locator::FixedStep(std::f64::consts::PI))
+               .set_x_minor_tick_locator(Locator::Subdivisions(5))
+               .set_y_tick_locator(Locator::MaxN(4))
+               .set_y_minor_tick_locator(Locator::Subdivisions(4))
+               .display_minor_vertical_gridlines(true)
+               .set_minor_tick_color_rgb(0.5, 0.5, 0.5)
+               .set_local_frame(0.0, 0.5, 0.0, 1.0)
+               .add_chart(&Chart::Line(Line::new(&x_data, &y_data)));
+
+    // Ticks at every power of ten, with minor ticks at their integer multiples
+    let growth_x: Vec<f64> = (0..50).map(f64::from).collect();
+    let growth_y: Vec<f64> = growth_x.iter().map(|x| 0.5 * 1.3_f64.powf(*x)).collect();
+    let mut log_plot = Plot::new();
+    log_plot.set_title("Log decades")
+            .set_y_scale(Scale::Log10)
+            .set_y_tick_locator(Locator::LogDecades)
+            .set_y_minor_tick_locator(Locator::Subdivisions(9))
+            .display_minor_horizontal_gridlines(true)
+            .set_x_tick_locator(Locator::explicit(&[0.0, 10.0, 25.0, 49.0]))
+            .set_local_frame(0.5, 1.0, 0.0, 1.0)
+            .add_chart(&Chart::Line(Line::new(&growth_x, &growth_y)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&linear_plot)
+       .add_plot(&log_plot)
+       .set_width(1000)
+       .set_height(500);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
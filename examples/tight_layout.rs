@@ -0,0 +1,52 @@
+//! Example of a figure with a tight layout, where the canvases are fitted to their titles, axis
+//! labels and tick labels
+//!
+
+extern crate astrup;
+
+use std::f64::consts::PI;
+
+use astrup::{Chart, Figure, Line, Plot, Scatter, View};
+
+fn main() {
+    let x_data: Vec<f64> = (0..101).map(|i| 0.1 * f64::from(i)).collect();
+    let growth: Vec<f64> = x_data.iter().map(|x| 12_500.0 * (0.4 * x).exp()).collect();
+    let decay: Vec<f64> = x_data.iter().map(|x| 0.002 * (-0.5 * x).exp()).collect();
+
+    // Wide tick labels on the vertical axis
+    let mut growth_plot = Plot::new();
+    growth_plot.set_title("Population")
+               .set_x_label("Time [years]")
+               .set_y_label("Individuals")
+               .add_chart(&Chart::Line(Line::new(&x_data, &growth)));
+
+    // Narrow tick labels, and no axis labels
+    let mut decay_plot = Plot::new();
+    decay_plot.set_title("Concentration")
+              .add_chart(&Chart::Line(Line::new(&x_data, &decay)));
+
+    // Rotated tick labels along the horizontal axis
+    let years = vec![1_990_000.0, 2_000_000.0, 2_010_000.0, 2_020_000.0];
+    let values = vec![3.0, 5.0, 4.0, 6.0];
+    let mut scatter = Scatter::new(&years, &values);
+    scatter.set_point_size(0.01);
+    let mut rotated_plot = Plot::new();
+    rotated_plot.set_title("Rotated tick labels")
+                .set_x_label("Seconds since start")
+                .set_x_tick_label_angle(PI / 4.0)
+                .add_chart(&Chart::Scatter(scatter));
+
+    let mut fig = Figure::new();
+    fig.set_title("Tight layout")
+       .set_tight_layout(true)
+       .set_grid(2, 2)
+       .add_plot_spanning(&growth_plot, 0, 0, 2, 1)
+       .add_plot_at(&decay_plot, 0, 1)
+       .add_plot_at(&rotated_plot, 1, 1)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
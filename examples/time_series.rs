@@ -0,0 +1,48 @@
+//! Example of time series, with dates and times on the horizontal axis
+//!
+
+extern crate astrup;
+extern crate chrono;
+
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+use astrup::{Chart, Figure, Line, Plot, Scatter, View};
+
+fn main() {
+    // Daily values over a few months
+    let first_day = NaiveDate::from_ymd_opt(2018, 1, 15).expect("Invalid date");
+    let days: Vec<NaiveDate> = (0..120).map(|k| first_day + Duration::days(k)).collect();
+    let daily_values: Vec<f64> = (0..120)
+        .map(|k| 20.0 + 5.0 * (f64::from(k) / 15.0).sin())
+        .collect();
+
+    let mut daily_plot = Plot::new();
+    daily_plot.set_title("Daily average")
+              .set_local_frame(0.0, 1.0, 0.5, 1.0)
+              .add_chart(&Chart::Line(Line::with_time(&days, &daily_values)));
+
+    // Measurements every ten minutes during one afternoon
+    let first_time = Utc.from_utc_datetime(&NaiveDate::from_ymd_opt(2018, 3, 2)
+        .and_then(|date| date.and_hms_opt(12, 0, 0))
+        .expect("Invalid time"));
+    let times: Vec<_> = (0..36)
+        .map(|k| first_time + Duration::minutes(10 * k))
+        .collect();
+    let load: Vec<f64> = (0..36).map(|k| f64::from(k % 7) + 0.1 * f64::from(k)).collect();
+
+    let mut load_plot = Plot::new();
+    load_plot.set_title("Load")
+             .set_x_time_format("%H:%M")
+             .set_local_frame(0.0, 1.0, 0.0, 0.5)
+             .add_chart(&Chart::Scatter(Scatter::with_time(&times, &load)));
+
+    let mut fig = Figure::new();
+    fig.add_plot(&daily_plot)
+       .add_plot(&load_plot)
+       .set_width(1000)
+       .set_height(800);
+
+    View::with_figure(fig)
+        .expect("Could not add figure to view")
+        .show();
+}
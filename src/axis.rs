@@ -1,13 +1,72 @@
 //! Definition of the Axis struct
 //!
 
+use std::cmp::Ordering;
 use std::f64;
+use std::mem;
 use failure::{err_msg, Error};
 use palette::Srgba;
 
 use cairo::{Context, FontSlant, FontWeight};
 
-use {color, coord, label, mark, shape, utils};
+use {color, coord, datetime, formatter, label, layout, locator, mark, scale, shape,
+     utils};
+use scale::AxisTransform;
+
+/// Determines where a default axis is placed across the canvas, that is, vertically for the
+/// horizontal axis, and horizontally for the vertical axis.
+///
+/// | Variant         | Position                                                                |
+/// | --------------- | ----------------------------------------------------------------------- |
+/// | Edge            | Along the bottom or the left edge of the canvas (default).              |
+/// | Zero            | At zero on the other axis, such that the axes cross at the origin.      |
+/// | Data(v)         | At the data value v on the other axis.                                  |
+/// | Outward(d)      | Outside of the edge of the canvas, at the distance d relative to it.    |
+///
+/// Values outside of the data range of the other axis, or outside of the domain of its scale, put
+/// the axis at the nearest edge in stead. The axis label stays beside the edge of the canvas,
+/// except with Outward, where it is moved together with the axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisPosition {
+    Edge,
+    Zero,
+    Data(f64),
+    Outward(f64),
+}
+
+impl Default for AxisPosition {
+    fn default() -> Self {
+        AxisPosition::Edge
+    }
+}
+
+impl AxisPosition {
+    /// Return the distance from the edge of the canvas to the axis, relative to the canvas, given
+    /// the scale and the data range of the other axis
+    pub(crate) fn local_offset(&self, scale: &scale::Scale, data_min: f64, data_max: f64) -> f64 {
+        match *self {
+            AxisPosition::Edge => 0.0,
+            AxisPosition::Zero => AxisPosition::Data(0.0).local_offset(scale, data_min, data_max),
+            AxisPosition::Data(val) => {
+                let offset = scale.map_range(val, data_min, data_max, 0.0, 1.0);
+                if offset.is_finite() {
+                    offset.max(0.0).min(1.0)
+                } else {
+                    0.0
+                }
+            }
+            AxisPosition::Outward(distance) => -distance,
+        }
+    }
+
+    /// Whether or not the axis label is moved together with the axis
+    pub(crate) fn moves_label(&self) -> bool {
+        match *self {
+            AxisPosition::Outward(_) => true,
+            _ => false,
+        }
+    }
+}
 
 /// ## Axis
 ///
@@ -21,35 +80,66 @@ pub struct Axis {
     direction: coord::Coord,
     color: color::Color,
     line_width: f64,
+    display_line: bool,
     data_range: [f64; 2],
+    is_range_fixed: bool,
+    is_inverted: bool,
     label: label::Label,
-    ca_num_marks: usize,
+    locator: locator::Locator,
+    minor_locator: Option<locator::Locator>,
+    formatter: formatter::Formatter,
+    custom_marks: Vec<(f64, String)>,
+    scale: scale::Scale,
+    display_log_subdivisions: bool,
+    is_time: bool,
+    time_format: Option<String>,
+    tick_label_angle: Option<f64>,
+    tick_label_offset: f64,
+    display_tick_labels: bool,
+    shared_tick_label: label::Label,
+    data_boundaries: Option<(coord::Coord, coord::Coord)>,
+    is_label_placed: bool,
+    mark_style: mark::Mark,
+    minor_mark_style: mark::Mark,
     marks: Vec<mark::Mark>,
+    mark_data_locations: Vec<f64>,
+    minor_marks: Vec<mark::Mark>,
 }
 
 impl Axis {
-    #[allow(dead_code)]
-    pub fn new() -> Axis {
-        let mut label = label::Label::new();
-        label.set_color_internal(color::CustomColor::AxisLabel.as_srgba());
-        Axis {
-            local_start: coord::Coord::new(),
-            local_end: coord::Coord::new(),
-            global_start: coord::Coord::new(),
-            global_end: coord::Coord::new(),
-            direction: coord::Coord::new(),
-            color: color::Color::with_custom(&color::CustomColor::AxisLine),
-            line_width: 0.0025,
-            data_range: [0.0, 1.0],
-            label: label,
-            ca_num_marks: 6,
-            marks: Vec::<mark::Mark>::new(),
+    /// Create and return a new axis from `(start_x, start_y)` to `(end_x, end_y)`, in the data
+    /// coordinates of the canvas it is added to. Infinite coordinates are at the edge of the
+    /// canvas, such that e.g. `Axis::new(f64::NEG_INFINITY, 0.0, f64::INFINITY, 0.0)` spans the
+    /// whole canvas horizontally at y = 0.
+    ///
+    /// The axis is fitted together with the default axes, and its marks are placed within the
+    /// horizontal data range it spans, or within the vertical one if it is vertical. It is not
+    /// taken into account when the data range of the canvas is determined.
+    pub fn new(start_x: f64, start_y: f64, end_x: f64, end_y: f64) -> Axis {
+        let start = coord::Coord::with_coordinates(start_x, start_y);
+        let end = coord::Coord::with_coordinates(end_x, end_y);
+        let mut axis = Axis::with_boundaries(
+            &coord::Coord::with_coordinates(0.0, 0.0),
+            &coord::Coord::with_coordinates(1.0, 0.0),
+        );
+        axis.data_boundaries = Some((start, end));
+        axis.set_tick_label_font_size(0.02)
+            .set_tick_label_offset(-0.03)
+            .set_minor_tick_length(0.0025)
+            .set_minor_tick_width(0.0015)
+            .set_label_font_size(0.025)
+            .set_label_font_slant(FontSlant::Italic);
+        if start_x == end_x {
+            axis.set_label_angle(f64::consts::PI / 2.0);
         }
+        axis
     }
 
-    pub fn with_boundaries(start: &coord::Coord, end: &coord::Coord) -> Axis {
+    pub(crate) fn with_boundaries(start: &coord::Coord, end: &coord::Coord) -> Axis {
         let mut label = label::Label::new();
         label.set_color_internal(color::CustomColor::AxisLabel.as_srgba());
+        let mut shared_tick_label = label::Label::new();
+        shared_tick_label.set_color_internal(color::CustomColor::TickLabel.as_srgba());
         Axis {
             local_start: start.clone(),
             local_end: end.clone(),
@@ -58,157 +148,669 @@ impl Axis {
             direction: start.unit_direction_to(end),
             color: color::Color::with_custom(&color::CustomColor::AxisLine),
             line_width: 0.0025,
+            display_line: true,
             data_range: [0.0, 1.0],
+            is_range_fixed: false,
+            is_inverted: false,
             label: label,
-            ca_num_marks: 6,
+            locator: locator::Locator::default(),
+            minor_locator: None,
+            formatter: formatter::Formatter::default(),
+            custom_marks: Vec::<(f64, String)>::new(),
+            scale: scale::Scale::Linear,
+            display_log_subdivisions: false,
+            is_time: false,
+            time_format: None,
+            tick_label_angle: None,
+            tick_label_offset: 0.0,
+            display_tick_labels: true,
+            shared_tick_label: shared_tick_label,
+            data_boundaries: None,
+            is_label_placed: false,
+            mark_style: mark::Mark::new(),
+            minor_mark_style: mark::Mark::new(),
             marks: Vec::<mark::Mark>::new(),
+            mark_data_locations: Vec::<f64>::new(),
+            minor_marks: Vec::<mark::Mark>::new(),
         }
     }
 
     // ----------------- APPEARANCE ---------------------------------------- //
 
-    /// Set axis color
-    pub fn set_color_internal(&mut self, color: Srgba) {
+    /// Set the color of the axis line
+    pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.set_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis line
+    pub fn set_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis line
+    pub fn set_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis line
+    pub fn set_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis line
+    pub fn set_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis line
+    pub fn set_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.set_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis line
+    pub(crate) fn set_color_internal(&mut self, color: Srgba) -> &mut Self {
         self.color.set_color(color);
+        self
     }
 
-    pub fn set_line_width(&mut self, val: f64) {
+    /// Set the width of the axis line
+    pub fn set_line_width(&mut self, val: f64) -> &mut Self {
         self.line_width = val;
+        self
+    }
+
+    /// Whether or not to draw the axis line. The ticks and tick labels are drawn regardless.
+    pub fn display_line(&mut self, val: bool) -> &mut Self {
+        self.display_line = val;
+        self
     }
 
     // ----------------- LABELS -------------------------------------------- //
 
-    pub fn set_label(&mut self, label: &label::Label) {
+    pub(crate) fn set_label(&mut self, label: &label::Label) -> &mut Self {
         self.label = label.clone();
+        self
     }
 
-    #[allow(dead_code)] // TODO: When axis becomes public
-    pub fn set_label_content(&mut self, content: &str) {
+    /// Set the axis label
+    pub fn set_label_content(&mut self, content: &str) -> &mut Self {
         self.label.set_content(content);
+        self
     }
 
-    #[allow(dead_code)] // TODO: When axis becomes public
-    pub fn set_label_angle(&mut self, angle: f64) {
+    /// Set the angle of the axis label, with zero at horisontal, and increasing counterclockwise
+    pub fn set_label_angle(&mut self, angle: f64) -> &mut Self {
         self.label.set_angle(angle);
+        self
     }
 
-    #[allow(dead_code)] // TODO: When axis becomes public
-    pub fn set_label_centroid(&mut self, x_coord: f64, y_coord: f64) {
-        self.label.set_centroid(x_coord, y_coord)
+    /// Set the centroid of the axis label, relative to the canvas. By default, the label of an
+    /// axis added to the canvas is placed beside its middle, on the side of the tick labels.
+    pub fn set_label_centroid(&mut self, x_coord: f64, y_coord: f64) -> &mut Self {
+        self.label.set_centroid(x_coord, y_coord);
+        self.is_label_placed = true;
+        self
     }
 
-    pub fn set_label_font_size(&mut self, val: f64) {
+    /// Set the font size of the axis label
+    pub fn set_label_font_size(&mut self, val: f64) -> &mut Self {
         self.label.set_font_size(val);
+        self
     }
 
-    pub fn set_label_font_slant(&mut self, font_slant: FontSlant) {
+    /// Set the font slant of the axis label
+    pub fn set_label_font_slant(&mut self, font_slant: FontSlant) -> &mut Self {
         self.label.set_font_slant(font_slant);
+        self
     }
 
-    pub fn set_label_font_weight(&mut self, font_weight: FontWeight) {
+    /// Set the font weight of the axis label
+    pub fn set_label_font_weight(&mut self, font_weight: FontWeight) -> &mut Self {
         self.label.set_font_weight(font_weight);
+        self
     }
 
-    pub fn set_label_font_family(&mut self) {
+    pub(crate) fn set_label_font_family(&mut self) -> &mut Self {
         // TODO:
         self.label.set_font_family();
+        self
     }
 
     /// Set the gaps around the axis label. See the Label struct for reference.
-    #[allow(dead_code)] // TODO: When axis becomes public
-    pub fn set_label_frame_gaps(&mut self, left: f64, right: f64, bottom: f64, top: f64) {
+    pub fn set_label_frame_gaps(
+        &mut self,
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+    ) -> &mut Self {
         self.label.set_frame_gaps(left, right, bottom, top);
+        self
+    }
+
+    /// Set the color of the axis label
+    pub fn set_label_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.set_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis label
+    pub fn set_label_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis label
+    pub fn set_label_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis label
+    pub fn set_label_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis label
+    pub fn set_label_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis label
+    pub fn set_label_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.set_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the axis label
+    pub(crate) fn set_label_color_internal(&mut self, color: Srgba) -> &mut Self {
+        self.label.set_color_internal(color);
+        self
     }
 
     // ----------------- TICKS --------------------------------------------- //
 
-    #[allow(dead_code)] // TODO: When axis becomes public
-    pub fn set_num_ticks(&mut self, val: usize) {
-        self.ca_num_marks = val;
+    /// Place about `val` marks on the axis. This is the same as the default locator,
+    /// `Locator::Auto(val)`.
+    pub fn set_num_ticks(&mut self, val: usize) -> &mut Self {
+        self.locator = locator::Locator::Auto(val);
+        self
     }
 
-    pub fn set_positive_tick_length(&mut self, val: f64) {
-        for mark in &mut self.marks {
-            mark.set_positive_tick_length(val);
-        }
+    /// Set the locator of the (major) marks of the axis. See the Locator enum for reference.
+    pub fn set_locator(&mut self, locator: &locator::Locator) -> &mut Self {
+        self.locator = locator.clone();
+        self
     }
 
-    pub fn set_negative_tick_length(&mut self, val: f64) {
-        for mark in &mut self.marks {
-            mark.set_negative_tick_length(val);
-        }
+    /// Set the locator of the minor marks of the axis. If None, there are no minor marks.
+    pub fn set_minor_locator(&mut self, locator: Option<locator::Locator>) -> &mut Self {
+        self.minor_locator = locator;
+        self
     }
 
-    pub fn set_tick_color_internal(&mut self, color: Srgba) {
-        for mark in &mut self.marks {
-            mark.set_tick_color_internal(color);
-        }
+    /// Set the formatter of the tick labels. See the Formatter enum for reference.
+    pub fn set_formatter(&mut self, formatter: &formatter::Formatter) -> &mut Self {
+        self.formatter = formatter.clone();
+        self
     }
 
-    pub fn set_tick_label_font_size(&mut self, val: f64) {
-        for mark in &mut self.marks {
-            mark.set_font_size(val);
-        }
+    /// Use marks at the given data locations, with the given labels, in stead of computing evenly
+    /// spaced marks. This is used for e.g. category labels.
+    pub(crate) fn set_custom_marks(&mut self, marks: &[(f64, String)]) {
+        self.custom_marks = marks.to_vec();
     }
 
-    pub fn set_tick_label_font_slant(&mut self, font_slant: FontSlant) {
-        for mark in &mut self.marks {
-            mark.set_font_slant(font_slant);
-        }
+    /// Set the scale of the axis. Marks on a logarithmic axis are placed at integer powers of the
+    /// base.
+    pub(crate) fn set_scale(&mut self, scale: &scale::Scale) {
+        self.scale = scale.clone();
     }
 
-    pub fn set_tick_label_font_weight(&mut self, font_weight: FontWeight) {
-        for mark in &mut self.marks {
-            mark.set_font_weight(font_weight);
-        }
+    /// Whether or not to place unlabeled marks between the powers of a logarithmic axis, e.g. at
+    /// 2, 3, ..., 9 times 10^n for base 10.
+    pub(crate) fn display_log_subdivisions(&mut self, val: bool) {
+        self.display_log_subdivisions = val;
+    }
+
+    /// Whether or not the data on this axis are times, in seconds since the UNIX epoch. Marks on a
+    /// time axis are snapped to calendar units, and labeled with dates and times.
+    pub(crate) fn set_time_mode(&mut self, val: bool) {
+        self.is_time = val;
+    }
+
+    /// Set the strftime format of the tick labels on a time axis, e.g. "%Y-%m-%d". By default, the
+    /// format is chosen from the spacing of the marks.
+    pub(crate) fn set_time_format(&mut self, format: Option<String>) {
+        self.time_format = format;
+    }
+
+    /// Set the length of the ticks, on both sides of the axis
+    pub fn set_tick_length(&mut self, val: f64) -> &mut Self {
+        self.style_marks(|mark| mark.set_tick_length(val));
+        self
+    }
+
+    /// Set the length of the ticks on the side of increasing x and/or y
+    pub fn set_positive_tick_length(&mut self, val: f64) -> &mut Self {
+        self.style_marks(|mark| mark.set_positive_tick_length(val));
+        self
+    }
+
+    /// Set the length of the ticks on the side of decreasing x and/or y
+    pub fn set_negative_tick_length(&mut self, val: f64) -> &mut Self {
+        self.style_marks(|mark| mark.set_negative_tick_length(val));
+        self
+    }
+
+    /// Set the tick width
+    pub fn set_tick_width(&mut self, val: f64) -> &mut Self {
+        self.style_marks(|mark| mark.set_tick_width(val));
+        self
+    }
+
+    /// Set the tick color
+    pub fn set_tick_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.set_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick color
+    pub fn set_tick_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick color
+    pub fn set_tick_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick color
+    pub fn set_tick_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick color
+    pub fn set_tick_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick color
+    pub fn set_tick_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.set_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick color
+    pub(crate) fn set_tick_color_internal(&mut self, color: Srgba) -> &mut Self {
+        self.style_marks(|mark| mark.set_tick_color_internal(color));
+        self
+    }
+
+    /// Set the length of the minor ticks, on both sides of the axis
+    pub fn set_minor_tick_length(&mut self, val: f64) -> &mut Self {
+        self.style_minor_marks(|mark| mark.set_tick_length(val));
+        self
+    }
+
+    /// Set the length of the minor ticks on the side of increasing x and/or y
+    pub fn set_minor_positive_tick_length(&mut self, val: f64) -> &mut Self {
+        self.style_minor_marks(|mark| mark.set_positive_tick_length(val));
+        self
+    }
+
+    /// Set the length of the minor ticks on the side of decreasing x and/or y
+    pub fn set_minor_negative_tick_length(&mut self, val: f64) -> &mut Self {
+        self.style_minor_marks(|mark| mark.set_negative_tick_length(val));
+        self
     }
 
-    pub fn set_tick_label_font_family(&mut self) {
+    /// Set the minor tick width
+    pub fn set_minor_tick_width(&mut self, val: f64) -> &mut Self {
+        self.style_minor_marks(|mark| mark.set_tick_width(val));
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub(crate) fn set_minor_tick_color_internal(&mut self, color: Srgba) -> &mut Self {
+        self.style_minor_marks(|mark| mark.set_tick_color_internal(color));
+        self
+    }
+
+    /// Set the font size of the tick labels
+    pub fn set_tick_label_font_size(&mut self, val: f64) -> &mut Self {
+        self.style_marks(|mark| mark.set_font_size(val));
+        self.shared_tick_label.set_font_size(val);
+        self
+    }
+
+    /// Set the font slant of the tick labels
+    pub fn set_tick_label_font_slant(&mut self, font_slant: FontSlant) -> &mut Self {
+        self.style_marks(|mark| mark.set_font_slant(font_slant));
+        self.shared_tick_label.set_font_slant(font_slant);
+        self
+    }
+
+    /// Set the font weight of the tick labels
+    pub fn set_tick_label_font_weight(&mut self, font_weight: FontWeight) -> &mut Self {
+        self.style_marks(|mark| mark.set_font_weight(font_weight));
+        self.shared_tick_label.set_font_weight(font_weight);
+        self
+    }
+
+    pub(crate) fn set_tick_label_font_family(&mut self) -> &mut Self {
         // TODO:
-        for mark in &mut self.marks {
-            mark.set_font_family();
-        }
+        self.style_marks(|mark| mark.set_font_family());
+        self
     }
 
-    pub fn set_tick_label_color_internal(&mut self, color: Srgba) {
-        for mark in &mut self.marks {
-            mark.set_label_color_internal(color);
-        }
+    /// Set the tick label color
+    pub fn set_tick_label_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.set_tick_label_color_internal(color.as_srgba());
+        self
     }
 
-    pub fn set_tick_label_offset(&mut self, val: f64) {
-        for mark in &mut self.marks {
-            mark.set_label_offset(val);
-        }
+    /// Set the tick label color
+    pub fn set_tick_label_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_tick_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick label color
+    pub fn set_tick_label_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_tick_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick label color
+    pub fn set_tick_label_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_tick_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick label color
+    pub fn set_tick_label_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_tick_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick label color
+    pub fn set_tick_label_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.set_tick_label_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the tick label color
+    pub(crate) fn set_tick_label_color_internal(&mut self, color: Srgba) -> &mut Self {
+        self.style_marks(|mark| mark.set_label_color_internal(color));
+        self.shared_tick_label.set_color_internal(color);
+        self
+    }
+
+    /// Set the distance from the axis to the centroids of the tick labels, relative to the
+    /// canvas. A negative offset puts the tick labels below a horizontal axis, and to the left of
+    /// a vertical axis.
+    pub fn set_tick_label_offset(&mut self, val: f64) -> &mut Self {
+        self.style_marks(|mark| mark.set_label_offset(val));
+        self.tick_label_offset = val;
+        self
+    }
+
+    /// Set the angle of the tick labels, with zero at horisontal, and increasing counterclockwise.
+    /// If None, the tick labels of a horizontal axis are rotated when they would overlap.
+    pub fn set_tick_label_angle(&mut self, angle: Option<f64>) -> &mut Self {
+        self.tick_label_angle = angle;
+        self
     }
 
     /// Set the gaps around the tick label, for all tick labels on this axis. See the Label struct
     /// for reference.
-    pub fn set_tick_label_frame_gaps(&mut self, left: f64, right: f64, bottom: f64, top: f64) {
+    pub fn set_tick_label_frame_gaps(
+        &mut self,
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+    ) -> &mut Self {
+        self.style_marks(|mark| mark.set_label_frame_gaps(left, right, bottom, top));
+        self
+    }
+
+    /// Whether or not to display the tick labels. The ticks are drawn regardless.
+    pub fn display_tick_labels(&mut self, val: bool) -> &mut Self {
+        self.display_tick_labels = val;
+        self
+    }
+
+    /// Apply a style to the marks of the axis, and to the marks that are placed later on
+    fn style_marks<F: Fn(&mut mark::Mark)>(&mut self, style: F) {
+        style(&mut self.mark_style);
         for mark in &mut self.marks {
-            mark.set_label_frame_gaps(left, right, bottom, top);
+            style(mark);
+        }
+    }
+
+    /// Apply a style to the minor marks of the axis, and to the minor marks that are placed later
+    /// on
+    fn style_minor_marks<F: Fn(&mut mark::Mark)>(&mut self, style: F) {
+        style(&mut self.minor_mark_style);
+        for mark in &mut self.minor_marks {
+            style(mark);
         }
     }
 
     // ----------------- DATA RANGE ---------------------------------------- //
 
-    pub fn set_data_range(&mut self, data_min: f64, data_max: f64) {
+    pub(crate) fn set_data_range(&mut self, data_min: f64, data_max: f64) {
         self.data_range = [data_min, data_max];
     }
 
-    pub fn data_min(&self) -> f64 {
+    /// Whether or not the data range is kept exactly as it is set. By default, the range is
+    /// extended to the nearest marks outside of it. With a fixed range, only the marks inside the
+    /// range are placed.
+    pub(crate) fn set_fixed_range(&mut self, val: bool) {
+        self.is_range_fixed = val;
+    }
+
+    pub(crate) fn data_min(&self) -> f64 {
         self.data_range[0]
     }
 
-    pub fn data_max(&self) -> f64 {
+    pub(crate) fn data_max(&self) -> f64 {
         self.data_range[1]
     }
 
+    /// Place an axis created in data coordinates on a canvas with the given data frame and scales.
+    /// Infinite coordinates are moved to the edge of the data frame, and the axis is turned such
+    /// that its marks increase from start to end. The scale and the fixed data range of the axis
+    /// are those of the direction it spans: horizontal, unless the axis is vertical. Horizontal
+    /// axes are time axes if `is_x_time` is true.
+    pub(crate) fn set_canvas_data_frame(
+        &mut self,
+        data_frame: &shape::Rectangle,
+        x_scale: &scale::Scale,
+        y_scale: &scale::Scale,
+        is_x_time: bool,
+    ) -> Result<(), Error> {
+        let (start, end) = match self.data_boundaries {
+            Some(ref boundaries) => boundaries.clone(),
+            None => return Ok(()),
+        };
+        let to_edge = |val: f64, min: f64, max: f64| {
+            if val == f64::NEG_INFINITY {
+                min
+            } else if val == f64::INFINITY {
+                max
+            } else {
+                val
+            }
+        };
+        let mut start_x = to_edge(start.x(), data_frame.left(), data_frame.right());
+        let mut end_x = to_edge(end.x(), data_frame.left(), data_frame.right());
+        let mut start_y = to_edge(start.y(), data_frame.bottom(), data_frame.top());
+        let mut end_y = to_edge(end.y(), data_frame.bottom(), data_frame.top());
+        let is_finite = start_x.is_finite() && end_x.is_finite() && start_y.is_finite()
+            && end_y.is_finite();
+        if !is_finite || (start_x == end_x && start_y == end_y) {
+            return Err(err_msg(format!(
+                "Could not place an axis from ({}, {}) to ({}, {})",
+                start.x(),
+                start.y(),
+                end.x(),
+                end.y()
+            )));
+        }
+        let is_horizontal = start_x != end_x;
+        if (is_horizontal && start_x > end_x) || (!is_horizontal && start_y > end_y) {
+            mem::swap(&mut start_x, &mut end_x);
+            mem::swap(&mut start_y, &mut end_y);
+        }
+
+        let to_local = |x: f64, y: f64| {
+            coord::Coord::with_coordinates(
+                x_scale.map_range(x, data_frame.left(), data_frame.right(), 0.0, 1.0),
+                y_scale.map_range(y, data_frame.bottom(), data_frame.top(), 0.0, 1.0),
+            )
+        };
+        self.local_start = to_local(start_x, start_y);
+        self.local_end = to_local(end_x, end_y);
+        self.direction = self.local_start.unit_direction_to(&self.local_end);
+        if is_horizontal {
+            self.scale = x_scale.clone();
+            self.data_range = [start_x, end_x];
+            self.is_time = is_x_time;
+        } else {
+            self.scale = y_scale.clone();
+            self.data_range = [start_y, end_y];
+            self.is_time = false;
+        }
+        self.is_range_fixed = true;
+
+        Ok(())
+    }
+
+    /// Whether or not the data locations of the marks decrease from the start to the end of the
+    /// axis, in stead of increasing
+    pub(crate) fn set_inverted(&mut self, val: bool) {
+        self.is_inverted = val;
+    }
+
+    /// Mirror the axis across the canvas, horizontally if `invert_x` is true and vertically if
+    /// `invert_y` is true, such that it follows the inverted directions of the canvas. The axis
+    /// keeps starting at its left or bottom end, and is inverted if it is mirrored along itself.
+    pub(crate) fn mirror(&mut self, invert_x: bool, invert_y: bool) {
+        let mirrored = |coord: &coord::Coord| {
+            coord::Coord::with_coordinates(
+                if invert_x { 1.0 - coord.x() } else { coord.x() },
+                if invert_y { 1.0 - coord.y() } else { coord.y() },
+            )
+        };
+        let start = mirrored(&self.local_start);
+        let end = mirrored(&self.local_end);
+        let is_horizontal = self.is_horizontal();
+        if (is_horizontal && invert_x) || (!is_horizontal && invert_y) {
+            self.local_start = end;
+            self.local_end = start;
+            self.is_inverted = !self.is_inverted;
+        } else {
+            self.local_start = start;
+            self.local_end = end;
+        }
+    }
+
+    /// Move the axis line and its marks by `(dx, dy)`, relative to the canvas. The axis label is
+    /// left where it is.
+    pub(crate) fn shift(&mut self, dx: f64, dy: f64) {
+        let shifted = |coord: &coord::Coord| {
+            coord::Coord::with_coordinates(coord.x() + dx, coord.y() + dy)
+        };
+        self.local_start = shifted(&self.local_start);
+        self.local_end = shifted(&self.local_end);
+        for mark in self.marks.iter_mut().chain(self.minor_marks.iter_mut()) {
+            let local = shifted(&mark.local_coord());
+            mark.set_local(local);
+        }
+    }
+
+    /// Move the axis label by `(dx, dy)`, relative to the canvas
+    pub(crate) fn shift_label(&mut self, dx: f64, dy: f64) {
+        let centroid = self.label.centroid();
+        self.label.set_centroid(centroid.x() + dx, centroid.y() + dy);
+    }
+
     // ----------------- GENERAL INTERNAL ---------------------------------- //
 
     /// Return the coordinates of the marks of this axis. Coordinates are relative to the global
     /// figure frame.
-    pub fn mark_coords(&self) -> Vec<coord::Coord> {
+    pub(crate) fn mark_coords(&self) -> Vec<coord::Coord> {
         let mut coords = Vec::<coord::Coord>::new();
         for mark in &self.marks {
             coords.push(mark.global_coord());
@@ -217,93 +819,344 @@ impl Axis {
         coords
     }
 
+    /// Return the coordinates of the minor marks of this axis. Coordinates are relative to the
+    /// global figure frame.
+    pub(crate) fn minor_mark_coords(&self) -> Vec<coord::Coord> {
+        self.minor_marks
+            .iter()
+            .map(|mark| mark.global_coord())
+            .collect()
+    }
+
     /// ## Compute marks
     ///
     /// Marks are used to determine the location of ticks and gridlines.
     ///
-    /// This method will return a list of evenly spaced marks according to the following method.
-    /// This assumes that the data range is known, and that know how many marks we want. The latter
-    /// is determined by a variable, and will be used more of a guide than as the actual number of
-    /// marks we get in the end.
+    /// The marks are placed by the locator of the axis, see the Locator enum. By default, this
+    /// gives a list of evenly spaced marks at a multiple of 1, 2 or 5 times a power of ten. This
+    /// assumes that the data range is known, and that know how many marks we want. The latter is
+    /// determined by the locator, and will be used more of a guide than as the actual number of
+    /// marks we get in the end. The data locations of the marks are transformed to positions in
+    /// the drawing framework using the data range and axis frame.
     ///
-    /// ### Method
+    /// When the range is fixed, it is used as it is, and only the marks inside it are placed. This
+    /// also holds for logarithmic and time axes.
     ///
-    /// 1. Find the orider of magnitude of the difference in the data range. Call this *p*.
-    /// 2a. Let min_point be min(data) rounded down to nearest *10^(p - 2)*.
-    /// 2b. Let max_point be max(data) rounded up to nearest *10^(p - 2)*.
-    /// 3. mark_distance = (max_point - min_point) / num_labels rounded to nearest *10^(p - 2)*
-    /// 4. Then, let mark_k = min_point + k*mark_distance, for k = 0 until mark_k is greater or
-    ///    equal to max(data).
-    /// 5. Transform between labels in the data framework (the above) and positions in the drawing
-    ///    framework using the data range and axis frame.
+    /// If custom marks are set, these are used as they are, and the data range is left untouched.
+    /// On a time axis, the marks are snapped to calendar units, and on a logarithmic axis, they are
+    /// placed at integer powers of the base in stead. On axes with other non-linear scales, the
+    /// marks are placed by the scale, and the data range is left untouched.
     ///
+    /// Then, the tick labels are written by the formatter, unless it is the default one, and
+    /// finally, the minor marks are placed by the minor locator, if any, within the final range.
+    pub(crate) fn compute_marks(&mut self) -> Result<(), Error> {
+        if !self.custom_marks.is_empty() {
+            self.compute_custom_marks();
+        } else if self.is_time {
+            self.compute_time_marks()?;
+        } else if self.scale.is_log() {
+            self.compute_log_marks()?;
+        } else if !self.scale.is_linear() {
+            self.compute_transformed_marks()?;
+        } else {
+            self.compute_located_marks(true)?;
+        }
+        self.format_tick_labels();
+        self.compute_minor_marks()
+    }
+
+    /// Write the labels of all labeled marks with the formatter, and set the text shared by all
+    /// of them. The default formatter leaves the labels as they are, and custom labels, e.g.
+    /// category names, are never formatted.
+    fn format_tick_labels(&mut self) {
+        self.shared_tick_label.set_content("");
+        if self.formatter.is_auto() || !self.custom_marks.is_empty() {
+            return;
+        }
+        let labeled_marks: Vec<usize> = (0..self.marks.len())
+            .filter(|&index| !self.marks[index].label().content().is_empty())
+            .collect();
+        let data_locations: Vec<f64> = labeled_marks
+            .iter()
+            .map(|&index| self.mark_data_locations[index])
+            .collect();
+        let (labels, shared_text) = self.formatter.format(&data_locations);
+        for (&index, content) in labeled_marks.iter().zip(labels) {
+            self.marks[index].set_label_content(&content);
+        }
+        self.shared_tick_label.set_content(&shared_text);
+    }
+
+    /// Place the marks given by the locator, labeled with their data location. If `extend_range`
+    /// is true, the data range is extended to the outermost marks, if the locator does so.
+    fn compute_located_marks(&mut self, extend_range: bool) -> Result<(), Error> {
+        let data_locations = self.locator
+            .locations(self.data_range[0], self.data_range[1])?;
+        let (min_data, max_data) = if extend_range && self.locator.extends_range() {
+            self.range_with_marks(
+                *data_locations
+                    .first()
+                    .ok_or_else(|| err_msg("No first element"))?,
+                *data_locations
+                    .last()
+                    .ok_or_else(|| err_msg("No final element"))?,
+            )
+        } else {
+            (self.data_range[0], self.data_range[1])
+        };
+        let labels = if self.is_time {
+            datetime::time_labels(
+                &data_locations,
+                self.time_format.as_ref().map(|format| format.as_str()),
+            )?
+        } else {
+            data_locations
+                .iter()
+                .map(|&data_location| utils::prettify(data_location))
+                .collect()
+        };
+        let marks: Vec<(f64, String)> = data_locations.into_iter().zip(labels).collect();
+        self.set_marks(&marks, min_data, max_data);
+
+        Ok(())
+    }
+
+    /// ## Compute log marks
+    ///
+    /// Marks on a logarithmic axis are placed at integer powers of the base, and labeled as such,
+    /// e.g. "10³". The data range is extended to the nearest powers below and above the data.
+    /// When the data spans more powers than the desired number of marks, only every k-th power is
+    /// marked, unless every power is asked for with `Locator::LogDecades`. Optionally, unlabeled
+    /// marks are placed at the integer multiples of each power.
     ///
-    ///  - The user can now set data range, but this function will override it. With this, the
-    ///  output looks nicer, but I assume that when the user puts a data range, the user assumes
-    ///  that this range should be used.
-    pub fn compute_marks(&mut self) -> Result<(), Error> {
-        let data_diff = self.data_range[1] - self.data_range[0];
-        let ca_dist = data_diff / (self.ca_num_marks as f64 - 1.0);
-        let omagn = utils::order_of_magnitude(ca_dist);
-
-        // Find for what k in (1, 2, 5) we shall round to the nearest ten power of
-        let mut smallest_diff = f64::MAX;
-        let mut round_number = 0f64;
-        for &i in &[1.0, 2.0, 5.0] {
-            let nearest = utils::round_nearest(ca_dist, omagn, i);
-            let diff = (ca_dist - nearest).abs();
-            if diff < smallest_diff {
-                smallest_diff = diff;
-                round_number = i;
+    /// Logarithmic axes can only display positive data, and an error is returned otherwise.
+    fn compute_log_marks(&mut self) -> Result<(), Error> {
+        let base = self.scale
+            .base()
+            .ok_or_else(|| err_msg("Log marks on a linear axis"))?;
+        if !(self.data_range[0] > 0.0 && self.data_range[1] > 0.0) {
+            return Err(err_msg(format!(
+                "Logarithmic axes can only display positive values, but the data range is [{}, {}]",
+                self.data_range[0], self.data_range[1]
+            )));
+        }
+        let max_num_marks = match self.locator {
+            locator::Locator::Auto(num_marks) | locator::Locator::MaxN(num_marks) => {
+                Some(num_marks)
             }
+            locator::Locator::LogDecades => None,
+            _ => return self.compute_located_marks(false),
+        };
+
+        // A small tolerance, such that data exactly at a power does not add an extra power
+        let tolerance = 1e-9;
+        let min_exponent = (self.scale.forward(self.data_range[0]) + tolerance).floor() as i32;
+        let mut max_exponent = (self.scale.forward(self.data_range[1]) - tolerance).ceil() as i32;
+        if max_exponent <= min_exponent {
+            max_exponent = min_exponent + 1;
         }
+        let num_powers = (max_exponent - min_exponent) as usize;
+        let exponent_step = match max_num_marks {
+            Some(num_marks) => {
+                let max_num_intervals = num_marks.max(2) - 1;
+                ((num_powers + max_num_intervals - 1) / max_num_intervals).max(1)
+            }
+            None => 1,
+        };
+        // Let the range end on a mark
+        let max_exponent = min_exponent
+            + (((num_powers + exponent_step - 1) / exponent_step) * exponent_step) as i32;
+
+        let (min_data, max_data) =
+            self.range_with_marks(base.powi(min_exponent), base.powi(max_exponent));
+        let mut marks = Vec::<(f64, String)>::new();
+        for exponent in (min_exponent..max_exponent + 1).step_by(exponent_step) {
+            marks.push((base.powi(exponent), self.scale.power_label(exponent)));
 
-        let actual_min_point = utils::round_down(self.data_range[0], omagn, round_number);
-        let ca_max_point = *self.data_range
-            .last()
-            .ok_or_else(|| err_msg("No final element"))?;
-        let mark_distance = utils::round_nearest(ca_dist, omagn, round_number);
-
-        let mut data_locations = vec![actual_min_point];
-        let mut data_location_k = actual_min_point;
-        let mut marks = Vec::<mark::Mark>::new();
-        let mut add_next = true;
-        while add_next {
-            data_location_k += mark_distance;
-            data_locations.push(data_location_k);
-            if data_location_k > ca_max_point {
-                add_next = false;
+            if self.display_log_subdivisions && exponent_step == 1 && exponent < max_exponent {
+                let mut multiple = 2.0;
+                while multiple < base {
+                    marks.push((multiple * base.powi(exponent), String::new()));
+                    multiple += 1.0;
+                }
             }
         }
-        let min_data = data_locations[0];
-        let max_data = *data_locations
-            .last()
-            .ok_or_else(|| err_msg("No final element"))?;
+        self.set_marks(&marks, min_data, max_data);
+
+        Ok(())
+    }
+
+    /// Place marks at calendar units, e.g. at midnight every second day or at the start of every
+    /// month. The data range is extended to the nearest marks below and above the data.
+    fn compute_time_marks(&mut self) -> Result<(), Error> {
+        let num_marks = match self.locator.num_marks() {
+            Some(num_marks) => num_marks,
+            None => return self.compute_located_marks(true),
+        };
+        let time_marks = datetime::time_marks(
+            self.data_range[0],
+            self.data_range[1],
+            num_marks,
+            self.time_format.as_ref().map(|format| format.as_str()),
+        )?;
+        let (min_data, max_data) = self.range_with_marks(
+            time_marks
+                .first()
+                .ok_or_else(|| err_msg("No time marks"))?
+                .0,
+            time_marks
+                .last()
+                .ok_or_else(|| err_msg("No time marks"))?
+                .0,
+        );
+        self.set_marks(&time_marks, min_data, max_data);
+
+        Ok(())
+    }
+
+    /// Place the marks given by the scale of the axis within the data range.
+    fn compute_transformed_marks(&mut self) -> Result<(), Error> {
+        let (min_data, max_data) = (self.data_range[0], self.data_range[1]);
+        if !(self.scale.in_domain(min_data) && self.scale.in_domain(max_data)) {
+            return Err(err_msg(format!(
+                "The data range [{}, {}] is outside of the domain of the axis scale",
+                min_data, max_data
+            )));
+        }
+        let num_marks = match self.locator.num_marks() {
+            Some(num_marks) => num_marks,
+            None => return self.compute_located_marks(false),
+        };
+        let marks = self.scale.marks(min_data, max_data, num_marks);
+        self.set_marks(&marks, min_data, max_data);
+
+        Ok(())
+    }
+
+    /// Place unlabeled minor marks within the data range, given by the minor locator. Minor marks
+    /// at the location of a (major) mark are left out.
+    fn compute_minor_marks(&mut self) -> Result<(), Error> {
+        self.minor_marks.clear();
+        let minor_locator = match self.minor_locator {
+            Some(ref minor_locator) => minor_locator.clone(),
+            None => return Ok(()),
+        };
+        let (min_data, max_data) = (self.data_range[0], self.data_range[1]);
+        let data_locations = match minor_locator {
+            locator::Locator::Subdivisions(num_intervals) => locator::subdivisions(
+                &self.mark_data_locations,
+                num_intervals,
+                self.scale.is_log(),
+            ),
+            locator::Locator::LogDecades => self.log_decades(min_data, max_data)?,
+            ref minor_locator => minor_locator.locations(min_data, max_data)?,
+        };
+        let tolerance = 1e-9 * (max_data - min_data).abs();
         for data_location in data_locations {
-            let mark_x = utils::map_range(
-                data_location,
-                min_data,
-                max_data,
-                self.local_start.x(),
-                self.local_end.x(),
-            );
-            let mark_y = utils::map_range(
-                data_location,
-                min_data,
-                max_data,
-                self.local_start.y(),
-                self.local_end.y(),
-            );
-            let mark_location = coord::Coord::with_coordinates(mark_x, mark_y);
-            let mut mark_k = mark::Mark::with_location(mark_location);
-            mark_k.set_label_content(&utils::prettify(data_location));
+            let is_major = self.mark_data_locations
+                .iter()
+                .any(|major_location| (major_location - data_location).abs() <= tolerance);
+            if is_major || !Axis::is_in_range(data_location, min_data, max_data) {
+                continue;
+            }
+            let mut minor_mark = self.minor_mark_style.clone();
+            minor_mark.set_local(self.mark_location(data_location, min_data, max_data));
+            self.minor_marks.push(minor_mark);
+        }
 
-            marks.push(mark_k);
+        Ok(())
+    }
+
+    /// Return the integer powers of the base of a logarithmic axis, covering
+    /// `[min_data, max_data]`
+    fn log_decades(&self, min_data: f64, max_data: f64) -> Result<Vec<f64>, Error> {
+        let base = self.scale.base().ok_or_else(|| {
+            err_msg("Marks can only be placed at the powers of the base on logarithmic axes")
+        })?;
+        let min_exponent = self.scale.forward(min_data).floor() as i32;
+        let max_exponent = self.scale.forward(max_data).ceil() as i32;
+        Ok((min_exponent..max_exponent + 1)
+            .map(|exponent| base.powi(exponent))
+            .collect())
+    }
+
+    /// Set the marks of the axis from `(data location, label)` pairs, and the data range to
+    /// `[min_data, max_data]`. Only the marks within the range are kept.
+    fn set_marks(&mut self, marks: &[(f64, String)], min_data: f64, max_data: f64) {
+        self.marks.clear();
+        self.mark_data_locations.clear();
+        for &(data_location, ref content) in marks {
+            if !Axis::is_in_range(data_location, min_data, max_data) {
+                continue;
+            }
+            let mut mark_k = self.mark_style.clone();
+            mark_k.set_local(self.mark_location(data_location, min_data, max_data));
+            mark_k.set_label_content(content);
+
+            self.marks.push(mark_k);
+            self.mark_data_locations.push(data_location);
         }
         self.data_range = [min_data, max_data];
-        self.marks = marks;
+    }
 
-        Ok(())
+    /// Return the data range of the axis, given the outermost marks. The range is extended to the
+    /// marks, unless it is fixed.
+    fn range_with_marks(&self, first_mark: f64, last_mark: f64) -> (f64, f64) {
+        if self.is_range_fixed {
+            (self.data_range[0], self.data_range[1])
+        } else {
+            (first_mark, last_mark)
+        }
+    }
+
+    /// Whether or not a mark at `data_location` is within `[min_data, max_data]`, allowing for
+    /// rounding errors in the mark locations.
+    fn is_in_range(data_location: f64, min_data: f64, max_data: f64) -> bool {
+        let tolerance = 1e-9 * (max_data - min_data).abs();
+        data_location >= min_data - tolerance && data_location <= max_data + tolerance
+    }
+
+    /// Return the location of a mark at `data_location`, relative to the axis frame
+    fn mark_location(&self, data_location: f64, min_data: f64, max_data: f64) -> coord::Coord {
+        // The smallest data location is at the end of an inverted axis
+        let (first, last) = if self.is_inverted {
+            (&self.local_end, &self.local_start)
+        } else {
+            (&self.local_start, &self.local_end)
+        };
+        let mark_x = self.scale
+            .map_range(data_location, min_data, max_data, first.x(), last.x());
+        let mark_y = self.scale
+            .map_range(data_location, min_data, max_data, first.y(), last.y());
+        coord::Coord::with_coordinates(mark_x, mark_y)
+    }
+
+    /// Place marks at the custom data locations within the data range.
+    fn compute_custom_marks(&mut self) {
+        let (min_data, max_data) = (self.data_range[0], self.data_range[1]);
+        let custom_marks = self.custom_marks.clone();
+        self.set_marks(&custom_marks, min_data, max_data);
+    }
+
+    /// Whether or not neighbouring tick labels on a horizontal axis would overlap when they are
+    /// not rotated. The label widths are estimated from the number of characters.
+    fn tick_labels_overlap(&self, canvas_frame: &shape::Rectangle) -> bool {
+        if self.direction.y().abs() > 0.0 {
+            return false;
+        }
+        let mut extents: Vec<(f64, f64)> = self.marks
+            .iter()
+            .filter(|mark| !mark.label().content().is_empty())
+            .map(|mark| {
+                let width = mark.label().estimated_width() * canvas_frame.diag_len();
+                (mark.local_x() * canvas_frame.width(), width)
+            })
+            .collect();
+        extents.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        // Leave some room between neighbouring labels
+        extents
+            .windows(2)
+            .any(|pair| pair[1].0 - pair[0].0 < 0.55 * (pair[0].1 + pair[1].1))
     }
 
     fn scale_size(&mut self, factor: f64) {
@@ -322,42 +1175,177 @@ impl Axis {
         let scale_factor = canvas_frame.diag_len();
         self.scale_size(scale_factor);
 
+        if !self.display_tick_labels {
+            for mark in &mut self.marks {
+                mark.set_label_content("");
+            }
+            self.shared_tick_label.set_content("");
+        }
+
+        // The label of an axis added to the canvas is placed beside its middle, on the side of
+        // the tick labels, unless it is placed by the user
+        if self.data_boundaries.is_some() && !self.is_label_placed {
+            let local_perp_direction = self.local_start.perp_direction(&self.local_end);
+            let label_offset = self.tick_label_offset + self.tick_label_offset.signum() * 0.06;
+            self.label.set_centroid(
+                0.5 * (self.local_start.x() + self.local_end.x())
+                    + local_perp_direction.x().abs() * label_offset,
+                0.5 * (self.local_start.y() + self.local_end.y())
+                    + local_perp_direction.y().abs() * label_offset,
+            );
+        }
         self.label.fit(canvas_frame);
 
+        let label_angle = self.tick_label_angle.unwrap_or_else(|| {
+            if self.tick_labels_overlap(canvas_frame) {
+                f64::consts::PI / 4.0
+            } else {
+                0.0
+            }
+        });
+        let (angle_cos, angle_sin) = (label_angle.cos(), label_angle.sin());
+
         for mark in &mut self.marks {
             mark.set_tick_direction(&unit_perp_direction);
-            let label_x = mark.local_x() + unit_perp_direction.x().abs() * mark.label_offset();
-            let label_y = mark.local_y() + unit_perp_direction.y().abs() * mark.label_offset();
+            let offset_x = unit_perp_direction.x().abs() * mark.label_offset();
+            let offset_y = unit_perp_direction.y().abs() * mark.label_offset();
+            let mut label_x = mark.local_x() + offset_x;
+            let mut label_y = mark.local_y() + offset_y;
+            // Rotated labels end at the tick, in stead of being centered on it. The label is
+            // shifted half its width along the text, away from the axis.
+            let along_offset = angle_cos * offset_x + angle_sin * offset_y;
+            if label_angle != 0.0 && along_offset.abs() > f64::EPSILON {
+                let half_width = 0.5 * mark.label().estimated_width() * canvas_frame.diag_len();
+                let away_from_axis = along_offset.signum();
+                label_x += away_from_axis * half_width * angle_cos / canvas_frame.width();
+                label_y += away_from_axis * half_width * angle_sin / canvas_frame.height();
+            }
             mark.set_label_centroid(label_x, label_y);
+            mark.set_label_angle(label_angle);
+            mark.fit(canvas_frame);
+        }
+
+        for mark in &mut self.minor_marks {
+            mark.set_tick_direction(&unit_perp_direction);
             mark.fit(canvas_frame);
         }
+
+        // The text shared by all tick labels is placed below the tick labels at the end of a
+        // horizontal axis, and above the end of a vertical axis
+        if self.direction.y().abs() > 0.0 {
+            self.shared_tick_label
+                .set_centroid(self.local_end.x(), self.local_end.y() + 0.04);
+        } else {
+            self.shared_tick_label.set_centroid(
+                self.local_end.x(),
+                self.local_end.y() + 2.0 * self.tick_label_offset,
+            );
+        }
+        self.shared_tick_label.fit(canvas_frame);
+    }
+
+    /// Return the frames that the axis label and the tick labels cover in the figure
+    pub(crate) fn text_extents(
+        &self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) -> Vec<shape::Rectangle> {
+        let mut extents = Vec::<shape::Rectangle>::new();
+        for mark in &self.marks {
+            extents.extend(mark.label().extent(cr, fig_rel_height, fig_rel_width));
+        }
+        extents.extend(self.label.extent(cr, fig_rel_height, fig_rel_width));
+        extents.extend(self.shared_tick_label.extent(cr, fig_rel_height, fig_rel_width));
+        extents
+    }
+
+    /// Whether the axis is closer to horizontal than to vertical
+    pub(crate) fn is_horizontal(&self) -> bool {
+        self.direction.x().abs() > self.direction.y().abs()
+    }
+
+    /// Return the offset of the tick labels from the axis, relative to the canvas. It is negative
+    /// for tick labels below or to the left of the axis.
+    pub(crate) fn tick_label_offset(&self) -> f64 {
+        self.tick_label_offset
+    }
+
+    /// Return how far the axis label must be moved away from the axis, relative to the figure, to
+    /// be `padding` clear of the tick labels. Labels of axes added to the canvas are placed beside
+    /// their tick labels already, and are left as they are.
+    pub(crate) fn label_clearance(
+        &self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+        padding: f64,
+    ) -> f64 {
+        if self.data_boundaries.is_some() {
+            return 0.0;
+        }
+        let label = match self.label.extent(cr, fig_rel_height, fig_rel_width) {
+            Some(extent) => extent,
+            None => return 0.0,
+        };
+        let tick_labels: Vec<shape::Rectangle> = self.marks
+            .iter()
+            .filter_map(|mark| mark.label().extent(cr, fig_rel_height, fig_rel_width))
+            .collect();
+        if tick_labels.is_empty() {
+            return 0.0;
+        }
+        let tick_labels = layout::bounding_frame(&tick_labels);
+
+        let is_horizontal = self.is_horizontal();
+        let is_beside = if is_horizontal {
+            label.left() < tick_labels.right() && tick_labels.left() < label.right()
+        } else {
+            label.bottom() < tick_labels.top() && tick_labels.bottom() < label.top()
+        };
+        if !is_beside {
+            return 0.0;
+        }
+        let overlap = match (is_horizontal, self.tick_label_offset < 0.0) {
+            (true, true) => label.top() - tick_labels.bottom(),
+            (true, false) => tick_labels.top() - label.bottom(),
+            (false, true) => label.right() - tick_labels.left(),
+            (false, false) => tick_labels.right() - label.left(),
+        };
+        (overlap + padding).max(0.0)
     }
 
     /// Draw axis on canvas.
     pub(crate) fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
-        // Draw ticks and tick labels
+        // Draw minor ticks, and then ticks and tick labels
+        for mark in &self.minor_marks {
+            mark.draw(cr, fig_rel_height, fig_rel_width);
+        }
         for mark in &self.marks {
             mark.draw(cr, fig_rel_height, fig_rel_width);
         }
 
         // Draw axis line
-        let line_color = self.color.as_srgba();
-        cr.set_source_rgba(
-            f64::from(line_color.red),
-            f64::from(line_color.green),
-            f64::from(line_color.blue),
-            f64::from(line_color.alpha),
-        );
-        cr.set_line_width(
-            self.line_width
-                * (self.direction.x().abs() * fig_rel_width
-                    + self.direction.y().abs() * fig_rel_height),
-        );
-        cr.move_to(self.global_start.x(), self.global_start.y());
-        cr.line_to(self.global_end.x(), self.global_end.y());
-        cr.stroke();
+        if self.display_line {
+            let line_color = self.color.as_srgba();
+            cr.set_source_rgba(
+                f64::from(line_color.red),
+                f64::from(line_color.green),
+                f64::from(line_color.blue),
+                f64::from(line_color.alpha),
+            );
+            cr.set_line_width(
+                self.line_width
+                    * (self.direction.x().abs() * fig_rel_width
+                        + self.direction.y().abs() * fig_rel_height),
+            );
+            cr.move_to(self.global_start.x(), self.global_start.y());
+            cr.line_to(self.global_end.x(), self.global_end.y());
+            cr.stroke();
+        }
 
-        // Draw axis label
+        // Draw axis label, and the text shared by the tick labels
         self.label.draw(cr, fig_rel_height, fig_rel_width);
+        self.shared_tick_label.draw(cr, fig_rel_height, fig_rel_width);
     }
 }
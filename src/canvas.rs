@@ -2,12 +2,14 @@
 //!
 
 use std::f64;
-use failure::Error;
+use failure::{err_msg, Error};
 
 use cairo::{Context, FontSlant, FontWeight};
 use palette::Srgba;
 
-use {axis, chart, color, coord, label, mark, shape};
+use {axis, category, chart, color, colorbar, coord, formatter, label, layout, legend, locator, mark,
+     scale, shape};
+use scale::AxisTransform;
 use utils::{Drawable, Plottable};
 
 /// ## Canvas
@@ -21,19 +23,73 @@ pub struct Canvas {
     global_frame: shape::Rectangle,
     data_frame: shape::Rectangle,
     user_data_frame: shape::Rectangle,
+    secondary_data_frame: shape::Rectangle,
+    user_secondary_data_frame: shape::Rectangle,
+    shared_x_range: Option<(f64, f64)>,
+    shared_y_range: Option<(f64, f64)>,
+    hard_x_limits: bool,
+    hard_y_limits: bool,
+    invert_x: bool,
+    invert_y: bool,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    secondary_x_scale: scale::Scale,
+    secondary_y_scale: scale::Scale,
+    display_log_subdivisions: bool,
+    x_time_format: Option<String>,
+    category_order: category::CategoryOrder,
+    category_padding: f64,
+    x_categories: Vec<String>,
+    y_categories: Vec<String>,
+    x_tick_label_angle: Option<f64>,
+    y_tick_label_angle: Option<f64>,
+    display_x_tick_labels: bool,
+    display_y_tick_labels: bool,
+    x_locator: locator::Locator,
+    y_locator: locator::Locator,
+    x_minor_locator: Option<locator::Locator>,
+    y_minor_locator: Option<locator::Locator>,
+    x_formatter: formatter::Formatter,
+    y_formatter: formatter::Formatter,
+    secondary_x_locator: locator::Locator,
+    secondary_y_locator: locator::Locator,
+    secondary_x_formatter: formatter::Formatter,
+    secondary_y_formatter: formatter::Formatter,
+    tick_length: f64,
+    tick_width: f64,
+    tick_color: color::Color,
+    minor_tick_length: f64,
+    minor_tick_width: f64,
+    minor_tick_color: color::Color,
     grid_width: f64,
     grid_color: color::Color,
+    minor_grid_width: f64,
+    minor_grid_color: color::Color,
     grid: Vec<mark::GridLine>,
     display_horizontal_gridlines: bool,
     display_vertical_gridlines: bool,
+    display_minor_horizontal_gridlines: bool,
+    display_minor_vertical_gridlines: bool,
     display_horizontal_axis: bool,
     display_vertical_axis: bool,
+    x_axis_position: axis::AxisPosition,
+    y_axis_position: axis::AxisPosition,
+    display_bottom_spine: bool,
+    display_left_spine: bool,
+    display_top_spine: Option<bool>,
+    display_right_spine: Option<bool>,
     hor_marks: Vec<mark::Mark>, // TODO: Use these in stead of axis
     ver_marks: Vec<mark::Mark>,
     axes: Vec<axis::Axis>,
+    user_axes: Vec<axis::Axis>,
     charts: Vec<chart::Chart>,
+    legend: Option<legend::Legend>,
+    colorbar: Option<colorbar::Colorbar>,
+    tight_layout: bool,
     default_x_axis_label: label::Label, // For convenience: plot.set_label() etc.
     default_y_axis_label: label::Label,
+    secondary_x_axis_label: label::Label,
+    secondary_y_axis_label: label::Label,
 }
 
 impl Canvas {
@@ -48,27 +104,92 @@ impl Canvas {
         y_axis_label.set_angle(f64::consts::PI / 2.0);
         y_axis_label.set_color_internal(color::CustomColor::AxisLabel.as_srgba());
         y_axis_label.set_font_slant(FontSlant::Italic);
+        let mut secondary_x_axis_label = label::Label::with_centroid(0.5, 1.11);
+        secondary_x_axis_label.set_font_size(0.025);
+        secondary_x_axis_label.set_color_internal(color::CustomColor::AxisLabel.as_srgba());
+        secondary_x_axis_label.set_font_slant(FontSlant::Italic);
+        let mut secondary_y_axis_label = label::Label::with_centroid(1.13, 0.5);
+        secondary_y_axis_label.set_font_size(0.025);
+        secondary_y_axis_label.set_angle(f64::consts::PI / 2.0);
+        secondary_y_axis_label.set_color_internal(color::CustomColor::AxisLabel.as_srgba());
+        secondary_y_axis_label.set_font_slant(FontSlant::Italic);
         let mut local_frame = shape::Rectangle::with_boundaries(0.10, 0.95, 0.10, 0.95);
         local_frame.set_color_internal(color::CustomColor::CanvasBorder.as_srgba());
+        local_frame.set_border_thickness(0.0025);
+        local_frame.display_border(false);
         Canvas {
             color: color::Color::with_custom(&color::CustomColor::CanvasBackground),
             local_frame: local_frame,
             global_frame: shape::Rectangle::new(),
             data_frame: shape::Rectangle::new(),
             user_data_frame: shape::Rectangle::new(),
+            secondary_data_frame: shape::Rectangle::new(),
+            user_secondary_data_frame: shape::Rectangle::new(),
+            shared_x_range: None,
+            shared_y_range: None,
+            hard_x_limits: false,
+            hard_y_limits: false,
+            invert_x: false,
+            invert_y: false,
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            secondary_x_scale: scale::Scale::Linear,
+            secondary_y_scale: scale::Scale::Linear,
+            display_log_subdivisions: false,
+            x_time_format: None,
+            category_order: category::CategoryOrder::Appearance,
+            category_padding: 0.2,
+            x_categories: Vec::<String>::new(),
+            y_categories: Vec::<String>::new(),
+            x_tick_label_angle: None,
+            y_tick_label_angle: None,
+            display_x_tick_labels: true,
+            display_y_tick_labels: true,
+            x_locator: locator::Locator::default(),
+            y_locator: locator::Locator::default(),
+            x_minor_locator: None,
+            y_minor_locator: None,
+            x_formatter: formatter::Formatter::default(),
+            y_formatter: formatter::Formatter::default(),
+            secondary_x_locator: locator::Locator::default(),
+            secondary_y_locator: locator::Locator::default(),
+            secondary_x_formatter: formatter::Formatter::default(),
+            secondary_y_formatter: formatter::Formatter::default(),
+            tick_length: 0.01,
+            tick_width: 0.0025,
+            tick_color: color::Color::with_custom(&color::CustomColor::Tick),
+            minor_tick_length: 0.005,
+            minor_tick_width: 0.0015,
+            minor_tick_color: color::Color::with_custom(&color::CustomColor::Tick),
             grid_width: 0.004,
             grid_color: color::Color::with_custom(&color::CustomColor::GridLine),
+            minor_grid_width: 0.002,
+            minor_grid_color: color::Color::with_custom(&color::CustomColor::GridLine),
             grid: Vec::<mark::GridLine>::new(),
             display_horizontal_gridlines: true,
             display_vertical_gridlines: true,
+            display_minor_horizontal_gridlines: false,
+            display_minor_vertical_gridlines: false,
             display_horizontal_axis: true,
             display_vertical_axis: true,
+            x_axis_position: axis::AxisPosition::default(),
+            y_axis_position: axis::AxisPosition::default(),
+            display_bottom_spine: true,
+            display_left_spine: true,
+            display_top_spine: None,
+            display_right_spine: None,
             hor_marks: Vec::<mark::Mark>::new(),
             ver_marks: Vec::<mark::Mark>::new(),
             axes: Vec::<axis::Axis>::new(),
+            user_axes: Vec::<axis::Axis>::new(),
             charts: Vec::<chart::Chart>::new(),
+            legend: None,
+            colorbar: None,
+            tight_layout: false,
             default_x_axis_label: x_axis_label,
             default_y_axis_label: y_axis_label,
+            secondary_x_axis_label: secondary_x_axis_label,
+            secondary_y_axis_label: secondary_y_axis_label,
         }
     }
 
@@ -79,6 +200,17 @@ impl Canvas {
         self.local_frame.set_boundaries(left, right, bottom, top);
     }
 
+    /// Whether the local frame is computed by a tight layout of the figure. If so, no fixed room
+    /// is made for titles, labels and legends when the canvas is fitted.
+    pub(crate) fn set_tight_layout(&mut self, val: bool) {
+        self.tight_layout = val;
+    }
+
+    /// Return the global frame, relative to the figure. The canvas must be fitted first.
+    pub(crate) fn global_frame(&self) -> shape::Rectangle {
+        self.global_frame.clone()
+    }
+
     // TODO: local frame appearance and possibility to draw it.
 
     // ----------------- BACKGROUND COLOR ---------------------------------- //
@@ -98,6 +230,9 @@ impl Canvas {
     /// function). But the final range is also determined by: the number of marks (ticks), and that
     /// each tick should be one of *n x {1, 2, 5} x 10^p* for some integer *n* and power *p*. See
     /// more of how this is actually determined [here](struct.Axis.html#method.compute_marks).
+    ///
+    /// With hard limits, see `set_hard_x_limits()` and `set_hard_y_limits()`, the range is used
+    /// exactly as it is.
     pub fn set_data_range(&mut self, x_min: f64, x_max: f64, y_min: f64, y_max: f64) {
         self.user_data_frame
             .set_boundaries(x_min, x_max, y_min, y_max);
@@ -159,6 +294,95 @@ impl Canvas {
         self.user_data_frame.set_top(y_max);
     }
 
+    /// Set the data range of the secondary horizontal axis, along the top of the canvas
+    ///
+    /// *Note*:
+    /// This is a soft suggestion, and can be overwritten before the final result for aestethics.
+    /// See more [here](struct.Canvas.html#method.set_data_range).
+    pub fn set_secondary_x_range(&mut self, x_min: f64, x_max: f64) {
+        self.user_secondary_data_frame.set_left(x_min);
+        self.user_secondary_data_frame.set_right(x_max);
+    }
+
+    /// Set the data range of the secondary vertical axis, along the right side of the canvas
+    ///
+    /// *Note*:
+    /// This is a soft suggestion, and can be overwritten before the final result for aestethics.
+    /// See more [here](struct.Canvas.html#method.set_data_range).
+    pub fn set_secondary_y_range(&mut self, y_min: f64, y_max: f64) {
+        self.user_secondary_data_frame.set_bottom(y_min);
+        self.user_secondary_data_frame.set_top(y_max);
+    }
+
+    /// Whether or not the horizontal data range is used exactly as it is, in stead of being
+    /// extended to the nearest marks outside of it.
+    pub fn set_hard_x_limits(&mut self, val: bool) {
+        self.hard_x_limits = val;
+    }
+
+    /// Whether or not the vertical data range is used exactly as it is, in stead of being
+    /// extended to the nearest marks outside of it.
+    pub fn set_hard_y_limits(&mut self, val: bool) {
+        self.hard_y_limits = val;
+    }
+
+    // ----------------- SCALE --------------------------------------------- //
+
+    /// Whether or not the horizontal data locations decrease to the right, on the canvas and on
+    /// every horizontal axis
+    pub fn invert_x_axis(&mut self, val: bool) {
+        self.invert_x = val;
+    }
+
+    /// Whether or not the vertical data locations decrease upwards, on the canvas and on every
+    /// vertical axis
+    pub fn invert_y_axis(&mut self, val: bool) {
+        self.invert_y = val;
+    }
+
+    /// Set the scale of the horizontal axis
+    pub fn set_x_scale(&mut self, scale: scale::Scale) {
+        self.x_scale = scale;
+    }
+
+    /// Set the scale of the vertical axis
+    pub fn set_y_scale(&mut self, scale: scale::Scale) {
+        self.y_scale = scale;
+    }
+
+    /// Set the scale of the secondary horizontal axis
+    pub fn set_secondary_x_scale(&mut self, scale: scale::Scale) {
+        self.secondary_x_scale = scale;
+    }
+
+    /// Set the scale of the secondary vertical axis
+    pub fn set_secondary_y_scale(&mut self, scale: scale::Scale) {
+        self.secondary_y_scale = scale;
+    }
+
+    /// Whether or not to place unlabeled marks between the powers of logarithmic axes
+    pub fn display_log_subdivisions(&mut self, val: bool) {
+        self.display_log_subdivisions = val;
+    }
+
+    /// Set the strftime format of the tick labels on a horizontal time axis
+    pub fn set_x_time_format(&mut self, format: &str) {
+        self.x_time_format = Some(String::from(format));
+    }
+
+    // ----------------- CATEGORIES ---------------------------------------- //
+
+    /// Set the order of the categories on categorical axes
+    pub fn set_category_order(&mut self, order: category::CategoryOrder) {
+        self.category_order = order;
+    }
+
+    /// Set the gap between neighbouring categories on categorical axes, as a fraction of the
+    /// distance between them.
+    pub fn set_category_padding(&mut self, val: f64) {
+        self.category_padding = val;
+    }
+
     // ----------------- AXES APPEARANCE ----------------------------------- //
 
     /// Whether or not to display horizontal axis
@@ -171,6 +395,43 @@ impl Canvas {
         self.display_vertical_axis = val;
     }
 
+    /// Set where the horizontal axis is placed, relative to the vertical data range
+    pub fn set_x_axis_position(&mut self, position: axis::AxisPosition) {
+        self.x_axis_position = position;
+    }
+
+    /// Set where the vertical axis is placed, relative to the horizontal data range
+    pub fn set_y_axis_position(&mut self, position: axis::AxisPosition) {
+        self.y_axis_position = position;
+    }
+
+    /// Whether or not to draw the line of the horizontal axis
+    pub fn display_bottom_spine(&mut self, val: bool) {
+        self.display_bottom_spine = val;
+    }
+
+    /// Whether or not to draw the line of the vertical axis
+    pub fn display_left_spine(&mut self, val: bool) {
+        self.display_left_spine = val;
+    }
+
+    /// Whether or not to draw a line along the top of the canvas. By default, there is one only
+    /// when a chart is drawn against the secondary horizontal axis.
+    pub fn display_top_spine(&mut self, val: bool) {
+        self.display_top_spine = Some(val);
+    }
+
+    /// Whether or not to draw a line along the right side of the canvas. By default, there is one
+    /// only when a chart is drawn against the secondary vertical axis.
+    pub fn display_right_spine(&mut self, val: bool) {
+        self.display_right_spine = Some(val);
+    }
+
+    /// Whether or not to draw a border around the whole canvas, regardless of where the axes are
+    pub fn display_box_frame(&mut self, val: bool) {
+        self.local_frame.display_border(val);
+    }
+
     /// Set the color of all axes on the canvas
     pub fn set_axes_color_internal(&mut self, color: Srgba) {
         for axis in &mut self.axes {
@@ -275,13 +536,96 @@ impl Canvas {
         self.default_y_axis_label.set_color_internal(color);
     }
 
+    /// Set the label content of the secondary horisontal axis
+    pub fn set_secondary_x_axis_label_content(&mut self, content: &str) {
+        self.secondary_x_axis_label.set_content(content);
+    }
+
+    /// Set the label content of the secondary vertical axis
+    pub fn set_secondary_y_axis_label_content(&mut self, content: &str) {
+        self.secondary_y_axis_label.set_content(content);
+    }
+
     // ----------------- TICKS --------------------------------------------- //
 
+    /// Set the locator of the marks on the horizontal axis
+    pub fn set_x_locator(&mut self, locator: locator::Locator) {
+        self.x_locator = locator;
+    }
+
+    /// Set the locator of the marks on the vertical axis
+    pub fn set_y_locator(&mut self, locator: locator::Locator) {
+        self.y_locator = locator;
+    }
+
+    /// Set the locator of the minor marks on the horizontal axis
+    pub fn set_x_minor_locator(&mut self, locator: locator::Locator) {
+        self.x_minor_locator = Some(locator);
+    }
+
+    /// Set the locator of the minor marks on the vertical axis
+    pub fn set_y_minor_locator(&mut self, locator: locator::Locator) {
+        self.y_minor_locator = Some(locator);
+    }
+
+    /// Set the formatter of the tick labels on the horizontal axis
+    pub fn set_x_formatter(&mut self, formatter: formatter::Formatter) {
+        self.x_formatter = formatter;
+    }
+
+    /// Set the formatter of the tick labels on the vertical axis
+    pub fn set_y_formatter(&mut self, formatter: formatter::Formatter) {
+        self.y_formatter = formatter;
+    }
+
+    /// Set the locator of the marks on the secondary horizontal axis
+    pub fn set_secondary_x_locator(&mut self, locator: locator::Locator) {
+        self.secondary_x_locator = locator;
+    }
+
+    /// Set the locator of the marks on the secondary vertical axis
+    pub fn set_secondary_y_locator(&mut self, locator: locator::Locator) {
+        self.secondary_y_locator = locator;
+    }
+
+    /// Set the formatter of the tick labels on the secondary horizontal axis
+    pub fn set_secondary_x_formatter(&mut self, formatter: formatter::Formatter) {
+        self.secondary_x_formatter = formatter;
+    }
+
+    /// Set the formatter of the tick labels on the secondary vertical axis
+    pub fn set_secondary_y_formatter(&mut self, formatter: formatter::Formatter) {
+        self.secondary_y_formatter = formatter;
+    }
+
+    /// Set the tick length
+    pub fn set_tick_length(&mut self, val: f64) {
+        self.tick_length = val;
+    }
+
+    /// Set the tick width
+    pub fn set_tick_width(&mut self, val: f64) {
+        self.tick_width = val;
+    }
+
     /// Set tick color
     pub fn set_tick_color_internal(&mut self, color: Srgba) {
-        for axis in &mut self.axes {
-            axis.set_tick_color_internal(color);
-        }
+        self.tick_color.set_color(color);
+    }
+
+    /// Set the minor tick length
+    pub fn set_minor_tick_length(&mut self, val: f64) {
+        self.minor_tick_length = val;
+    }
+
+    /// Set the minor tick width
+    pub fn set_minor_tick_width(&mut self, val: f64) {
+        self.minor_tick_width = val;
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_internal(&mut self, color: Srgba) {
+        self.minor_tick_color.set_color(color);
     }
 
     /// Set tick label color
@@ -304,6 +648,26 @@ impl Canvas {
         }
     }
 
+    /// Set the angle of the tick labels on the horizontal axis
+    pub fn set_x_tick_label_angle(&mut self, val: f64) {
+        self.x_tick_label_angle = Some(val);
+    }
+
+    /// Set the angle of the tick labels on the vertical axis
+    pub fn set_y_tick_label_angle(&mut self, val: f64) {
+        self.y_tick_label_angle = Some(val);
+    }
+
+    /// Whether or not to display the tick labels of the default horizontal axis
+    pub fn display_x_tick_labels(&mut self, val: bool) {
+        self.display_x_tick_labels = val;
+    }
+
+    /// Whether or not to display the tick labels of the default vertical axis
+    pub fn display_y_tick_labels(&mut self, val: bool) {
+        self.display_y_tick_labels = val;
+    }
+
     pub fn set_tick_label_font_weight(&mut self, font_weight: FontWeight) {
         for axis in &mut self.axes {
             axis.set_tick_label_font_weight(font_weight);
@@ -339,101 +703,188 @@ impl Canvas {
         self.grid_color.set_color(color);
     }
 
+    /// Whether or not to display horizontal grid lines at the minor marks
+    pub fn display_minor_horizontal_gridlines(&mut self, val: bool) {
+        self.display_minor_horizontal_gridlines = val;
+    }
+
+    /// Whether or not to display vertical grid lines at the minor marks
+    pub fn display_minor_vertical_gridlines(&mut self, val: bool) {
+        self.display_minor_vertical_gridlines = val;
+    }
+
+    /// Set the line width of the minor gridlines
+    pub fn set_minor_gridline_width(&mut self, val: f64) {
+        self.minor_grid_width = val;
+    }
+
+    /// Set the minor grid line color
+    pub fn set_minor_grid_color_internal(&mut self, color: Srgba) {
+        self.minor_grid_color.set_color(color);
+    }
+
     // ----------------- GENERAL INTERNAL ---------------------------------- //
 
-    // /// Add an additional axis to the canvas
-    // #[allow(dead_code)]
-    // pub fn add_axis(&mut self, axis: axis::Axis) {
-    //     In order to create an axis, one have to specify a start and end point of the axis. This
-    //     start and end point should be in data coordinates, and the axis should be fitted with
-    //     the rest.
-    //
-    //     To be consistent, the default x and y axis should be fitted in the same way. This means
-    //     that they sould be decoupled from the data range determination in set_default_axes()
-    //     It should suffice to use marks in this funciton. set_default_axes() could be substituted
-    //     with something like set_default_marks().
-    //
-    //     Given a known data_frame and local_frame, it should be possible to add new axis with
-    //     let axis = Axis::new(start_x, start_y, end_x, end_y); and fit this to the existing
-    //     canvas.
-    //
-    //     self.axes.push(axis);
-    // }
+    /// Add an additional axis to the canvas. The axis is defined in data coordinates, and is
+    /// fitted to the data frame of the canvas together with the default axes.
+    pub fn add_axis(&mut self, axis: axis::Axis) {
+        self.user_axes.push(axis);
+    }
 
     /// Add an additional chart to the canvas
     pub fn add_chart(&mut self, chart: chart::Chart) {
         self.charts.push(chart);
     }
 
-    /// Compute grid lines given a vertical and a horisontal axis
+    /// Set the legend of the canvas
+    pub fn set_legend(&mut self, legend: legend::Legend) {
+        self.legend = Some(legend);
+    }
+
+    /// Whether or not to display a legend. A default legend is added if there is none.
+    pub fn display_legend(&mut self, val: bool) {
+        if !val {
+            self.legend = None;
+        } else if self.legend.is_none() {
+            self.legend = Some(legend::Legend::new());
+        }
+    }
+
+    /// Set the colorbar of the canvas
+    pub fn set_colorbar(&mut self, colorbar: colorbar::Colorbar) {
+        self.colorbar = Some(colorbar);
+    }
+
+    /// Whether or not to display a colorbar. A default colorbar is added if there is none.
+    pub fn display_colorbar(&mut self, val: bool) {
+        if !val {
+            self.colorbar = None;
+        } else if self.colorbar.is_none() {
+            self.colorbar = Some(colorbar::Colorbar::new());
+        }
+    }
+
+    /// Compute grid lines given a vertical and a horisontal axis. The minor grid lines are
+    /// computed first, such that the grid lines at the (major) marks are drawn on top of them.
     fn compute_grid(&mut self, ver_axis: &axis::Axis, hor_axis: &axis::Axis) {
-        let scale_factor = self.global_frame.diag_len();
-        //let scale_factor = self.global_frame.height().min(self.global_frame.width());
-        let grid_color = self.grid_color.as_srgba();
+        if self.display_minor_horizontal_gridlines {
+            let (width, color) = (self.minor_grid_width, self.minor_grid_color.as_srgba());
+            self.add_horizontal_gridlines(&ver_axis.minor_mark_coords(), width, color);
+        }
+        if self.display_minor_vertical_gridlines {
+            let (width, color) = (self.minor_grid_width, self.minor_grid_color.as_srgba());
+            self.add_vertical_gridlines(&hor_axis.minor_mark_coords(), width, color);
+        }
         if self.display_horizontal_gridlines {
-            for coord in ver_axis.mark_coords() {
-                let mut gridline = mark::GridLine::with_boundaries(
-                    coord.x(),
-                    coord.y(),
-                    self.global_frame.right(),
-                    coord.y(),
-                );
-                gridline.set_color_internal(grid_color);
-                gridline.set_width(self.grid_width);
-                gridline.scale_size(scale_factor);
-                self.grid.push(gridline);
-            }
+            let (width, color) = (self.grid_width, self.grid_color.as_srgba());
+            self.add_horizontal_gridlines(&ver_axis.mark_coords(), width, color);
         }
         if self.display_vertical_gridlines {
-            for coord in hor_axis.mark_coords() {
-                let mut gridline = mark::GridLine::with_boundaries(
-                    coord.x(),
-                    coord.y(),
-                    coord.x(),
-                    self.global_frame.top(),
-                );
-                gridline.set_color_internal(grid_color);
-                gridline.set_width(self.grid_width);
-                gridline.scale_size(scale_factor);
-                self.grid.push(gridline);
-            }
+            let (width, color) = (self.grid_width, self.grid_color.as_srgba());
+            self.add_vertical_gridlines(&hor_axis.mark_coords(), width, color);
         }
     }
 
-    /// Find the smallest data frame including all data points from all charts
-    fn find_largest_chart_data_frame(&self) -> Option<shape::Rectangle> {
-        if self.charts.is_empty() {
+    /// Add horizontal grid lines across the canvas, from the given marks on a vertical axis
+    fn add_horizontal_gridlines(&mut self, coords: &[coord::Coord], width: f64, color: Srgba) {
+        let scale_factor = self.global_frame.diag_len();
+        for coord in coords {
+            let mut gridline = mark::GridLine::with_boundaries(
+                self.global_frame.left(),
+                coord.y(),
+                self.global_frame.right(),
+                coord.y(),
+            );
+            gridline.set_color_internal(color);
+            gridline.set_width(width);
+            gridline.scale_size(scale_factor);
+            self.grid.push(gridline);
+        }
+    }
+
+    /// Add vertical grid lines across the canvas, from the given marks on a horizontal axis
+    fn add_vertical_gridlines(&mut self, coords: &[coord::Coord], width: f64, color: Srgba) {
+        let scale_factor = self.global_frame.diag_len();
+        for coord in coords {
+            let mut gridline = mark::GridLine::with_boundaries(
+                coord.x(),
+                self.global_frame.bottom(),
+                coord.x(),
+                self.global_frame.top(),
+            );
+            gridline.set_color_internal(color);
+            gridline.set_width(width);
+            gridline.scale_size(scale_factor);
+            self.grid.push(gridline);
+        }
+    }
+
+    /// Find the smallest data frame including all data points from the charts for which
+    /// `is_included` is true
+    fn find_largest_chart_data_frame<F>(&self, is_included: F) -> Option<shape::Rectangle>
+    where
+        F: Fn(&chart::Chart) -> bool,
+    {
+        if !self.charts.iter().any(|chart| is_included(chart)) {
             return None;
         }
         let mut largest_data_frame =
             shape::Rectangle::with_boundaries(f64::MAX, f64::MIN, f64::MAX, f64::MIN);
-        for chart in &self.charts {
-            if chart.data_frame().left() < largest_data_frame.left() {
-                largest_data_frame.set_left(chart.data_frame().left());
+        // Error bars attached to a chart may extend beyond the data points of the chart
+        let chart_data_frames = self.charts
+            .iter()
+            .filter(|chart| is_included(chart))
+            .flat_map(|chart| {
+                Some(chart.data_frame())
+                    .into_iter()
+                    .chain(chart.error_bar_data_frame())
+            });
+        for chart_data_frame in chart_data_frames {
+            if chart_data_frame.left() < largest_data_frame.left() {
+                largest_data_frame.set_left(chart_data_frame.left());
             }
-            if chart.data_frame().right() > largest_data_frame.right() {
-                largest_data_frame.set_right(chart.data_frame().right());
+            if chart_data_frame.right() > largest_data_frame.right() {
+                largest_data_frame.set_right(chart_data_frame.right());
             }
-            if chart.data_frame().bottom() < largest_data_frame.bottom() {
-                largest_data_frame.set_bottom(chart.data_frame().bottom());
+            if chart_data_frame.bottom() < largest_data_frame.bottom() {
+                largest_data_frame.set_bottom(chart_data_frame.bottom());
             }
-            if chart.data_frame().top() > largest_data_frame.top() {
-                largest_data_frame.set_top(chart.data_frame().top());
+            if chart_data_frame.top() > largest_data_frame.top() {
+                largest_data_frame.set_top(chart_data_frame.top());
             }
         }
         Some(largest_data_frame)
     }
 
-    /// Compute the data frame used by this canvas.
+    /// Compute the data frame used by this canvas, that is, of the bottom and the left axis.
     ///
     /// First priority is user input. For the entries where there is no user input, the value
     /// should be the smallest value larger than or equal to all respective chart values. If there
-    /// are no chart values, the default is chosen.
+    /// are no chart values, the default is chosen. Charts drawn against the top or the right axis
+    /// are left out in that direction, unless all charts are.
     fn compute_data_frame(&self) -> shape::Rectangle {
-        let mut return_this_data_frame = match self.find_largest_chart_data_frame() {
-            Some(val) => val,
-            None => self.data_frame.clone(),
-        };
+        let all_charts_frame = self.find_largest_chart_data_frame(|_| true)
+            .unwrap_or_else(|| self.data_frame.clone());
+        let x_frame = self.find_largest_chart_data_frame(|chart| !chart.axis_pair().is_top())
+            .unwrap_or_else(|| all_charts_frame.clone());
+        let y_frame = self.find_largest_chart_data_frame(|chart| !chart.axis_pair().is_right())
+            .unwrap_or_else(|| all_charts_frame.clone());
+        let mut return_this_data_frame = shape::Rectangle::with_boundaries(
+            x_frame.left(),
+            x_frame.right(),
+            y_frame.bottom(),
+            y_frame.top(),
+        );
+
+        // Every category gets the same room on a categorical axis, also the outermost ones
+        if !self.x_categories.is_empty() {
+            return_this_data_frame.set_left(-0.5);
+            return_this_data_frame.set_right(self.x_categories.len() as f64 - 0.5);
+        }
+        if !self.y_categories.is_empty() {
+            return_this_data_frame.set_bottom(-0.5);
+            return_this_data_frame.set_top(self.y_categories.len() as f64 - 0.5);
+        }
 
         if self.user_data_frame.is_left_updated() {
             return_this_data_frame.set_left(self.user_data_frame.left());
@@ -450,9 +901,139 @@ impl Canvas {
         if self.user_data_frame.is_top_updated() {
             return_this_data_frame.set_top(self.user_data_frame.top());
         }
+
+        // Canvases that share an axis are drawn with the same data range along it
+        if let Some((x_min, x_max)) = self.shared_x_range {
+            return_this_data_frame.set_left(x_min);
+            return_this_data_frame.set_right(x_max);
+        }
+        if let Some((y_min, y_max)) = self.shared_y_range {
+            return_this_data_frame.set_bottom(y_min);
+            return_this_data_frame.set_top(y_max);
+        }
         return_this_data_frame
     }
 
+    /// Return the data frame of this canvas, as it would be without any shared data ranges. The
+    /// sides that are set by the user, or by categories, are marked as updated.
+    pub(crate) fn unshared_data_frame(&self) -> shape::Rectangle {
+        let mut canvas = self.clone();
+        canvas.shared_x_range = None;
+        canvas.shared_y_range = None;
+        canvas.update_categories();
+        canvas.compute_data_frame()
+    }
+
+    /// Share the horizontal data range with other canvases
+    pub(crate) fn set_shared_x_range(&mut self, x_min: f64, x_max: f64) {
+        self.shared_x_range = Some((x_min, x_max));
+    }
+
+    /// Share the vertical data range with other canvases
+    pub(crate) fn set_shared_y_range(&mut self, y_min: f64, y_max: f64) {
+        self.shared_y_range = Some((y_min, y_max));
+    }
+
+    /// Compute the data frame of the secondary axes, that is, of the top and the right axis, in the
+    /// same way as the data frame of the canvas. Only the charts drawn against these axes are
+    /// included. In a direction without such charts, the frame is that of the canvas.
+    fn compute_secondary_data_frame(&self, data_frame: &shape::Rectangle) -> shape::Rectangle {
+        let x_frame = self.find_largest_chart_data_frame(|chart| chart.axis_pair().is_top())
+            .unwrap_or_else(|| data_frame.clone());
+        let y_frame = self.find_largest_chart_data_frame(|chart| chart.axis_pair().is_right())
+            .unwrap_or_else(|| data_frame.clone());
+        let mut secondary_data_frame = shape::Rectangle::with_boundaries(
+            x_frame.left(),
+            x_frame.right(),
+            y_frame.bottom(),
+            y_frame.top(),
+        );
+
+        if self.user_secondary_data_frame.is_left_updated() {
+            secondary_data_frame.set_left(self.user_secondary_data_frame.left());
+        }
+        if self.user_secondary_data_frame.is_right_updated() {
+            secondary_data_frame.set_right(self.user_secondary_data_frame.right());
+        }
+        if self.user_secondary_data_frame.is_bottom_updated() {
+            secondary_data_frame.set_bottom(self.user_secondary_data_frame.bottom());
+        }
+        if self.user_secondary_data_frame.is_top_updated() {
+            secondary_data_frame.set_top(self.user_secondary_data_frame.top());
+        }
+        secondary_data_frame
+    }
+
+    /// Return the category marks of the first chart that has any, using `chart_marks` to get the
+    /// marks of a chart. If no chart has category marks, the list is empty.
+    fn category_marks<F>(&self, chart_marks: F) -> Vec<(f64, String)>
+    where
+        F: Fn(&chart::Chart) -> Vec<(f64, String)>,
+    {
+        self.charts
+            .iter()
+            .map(chart_marks)
+            .find(|marks| !marks.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Return the marks of a categorical axis, at the center of every category, or the category
+    /// marks of the charts if the axis is not categorical.
+    fn axis_category_marks<F>(&self, categories: &[String], chart_marks: F) -> Vec<(f64, String)>
+    where
+        F: Fn(&chart::Chart) -> Vec<(f64, String)>,
+    {
+        if categories.is_empty() {
+            return self.category_marks(chart_marks);
+        }
+        categories
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (index as f64, name.clone()))
+            .collect()
+    }
+
+    /// Collect the categories of all charts along each axis in the category order, and place the
+    /// charts at their categories.
+    fn update_categories(&mut self) {
+        let x_names: Vec<String> = self.charts
+            .iter()
+            .flat_map(|chart| chart.x_categories())
+            .collect();
+        let y_names: Vec<String> = self.charts
+            .iter()
+            .flat_map(|chart| chart.y_categories())
+            .collect();
+        self.x_categories = category::order_categories(&x_names, &self.category_order);
+        self.y_categories = category::order_categories(&y_names, &self.category_order);
+        for chart in &mut self.charts {
+            chart.set_category_positions(
+                &self.x_categories,
+                &self.y_categories,
+                self.category_padding,
+            );
+        }
+    }
+
+    /// Set the length, width and color of the ticks and minor ticks of an axis. The ticks point
+    /// out of the canvas, that is, in the positive direction on the secondary axes along the top
+    /// and the right side of the canvas.
+    fn set_tick_style(&self, axis: &mut axis::Axis, is_secondary: bool) {
+        let (tick_lengths, minor_tick_lengths) = if is_secondary {
+            ((self.tick_length, 0.0), (self.minor_tick_length, 0.0))
+        } else {
+            ((0.0, self.tick_length), (0.0, self.minor_tick_length))
+        };
+        axis.set_positive_tick_length(tick_lengths.0);
+        axis.set_negative_tick_length(tick_lengths.1);
+        axis.set_tick_width(self.tick_width);
+        axis.set_tick_color_internal(self.tick_color.as_srgba());
+        axis.set_minor_positive_tick_length(minor_tick_lengths.0);
+        axis.set_minor_negative_tick_length(minor_tick_lengths.1);
+        axis.set_minor_tick_width(self.minor_tick_width);
+        axis.set_minor_tick_color_internal(self.minor_tick_color.as_srgba());
+    }
+
     /// Sets a default horizontal and vertical axis. This is important in order to determine the
     /// data_frame of this canvas. The reason for this is that the data frame changes with these
     /// axes, because of nice tick labeling.
@@ -471,13 +1052,30 @@ impl Canvas {
             &coord::Coord::with_coordinates(1.0, 0.0),
         );
         hor_axis.set_data_range(data_frame.left(), data_frame.right());
+        hor_axis.set_fixed_range(self.hard_x_limits);
+        hor_axis.set_scale(&self.x_scale);
+        hor_axis.display_log_subdivisions(self.display_log_subdivisions);
+        hor_axis.set_time_mode(
+            self.charts
+                .iter()
+                .any(|chart| chart.is_x_time() && !chart.axis_pair().is_top()),
+        );
+        hor_axis.set_time_format(self.x_time_format.clone());
+        hor_axis.set_custom_marks(
+            &self.axis_category_marks(&self.x_categories, chart::Chart::x_category_marks),
+        );
+        hor_axis.set_locator(&self.x_locator);
+        hor_axis.set_minor_locator(self.x_minor_locator.clone());
+        hor_axis.set_formatter(&self.x_formatter);
+        hor_axis.set_inverted(self.invert_x);
         hor_axis.compute_marks()?;
 
-        hor_axis.set_positive_tick_length(0.0);
-        hor_axis.set_negative_tick_length(0.01);
+        self.set_tick_style(&mut hor_axis, false);
         hor_axis.set_tick_label_font_size(0.02);
         hor_axis.set_tick_label_frame_gaps(0.0, 0.0, 0.0, 0.0);
         hor_axis.set_tick_label_offset(-0.05);
+        hor_axis.set_tick_label_angle(self.x_tick_label_angle);
+        hor_axis.display_tick_labels(self.display_x_tick_labels);
 
         hor_axis.set_label(&self.default_x_axis_label);
 
@@ -486,26 +1084,104 @@ impl Canvas {
             &coord::Coord::with_coordinates(0.0, 1.0),
         );
         ver_axis.set_data_range(data_frame.bottom(), data_frame.top());
+        ver_axis.set_fixed_range(self.hard_y_limits);
+        ver_axis.set_scale(&self.y_scale);
+        ver_axis.display_log_subdivisions(self.display_log_subdivisions);
+        ver_axis.set_custom_marks(
+            &self.axis_category_marks(&self.y_categories, chart::Chart::y_category_marks),
+        );
+        ver_axis.set_locator(&self.y_locator);
+        ver_axis.set_minor_locator(self.y_minor_locator.clone());
+        ver_axis.set_formatter(&self.y_formatter);
+        ver_axis.set_inverted(self.invert_y);
         ver_axis.compute_marks()?;
 
-        ver_axis.set_positive_tick_length(0.0);
-        ver_axis.set_negative_tick_length(0.01);
+        self.set_tick_style(&mut ver_axis, false);
         ver_axis.set_tick_label_font_size(0.02);
         ver_axis.set_tick_label_frame_gaps(0.0, 0.0, 0.0, 0.0);
         ver_axis.set_tick_label_offset(-0.05);
+        ver_axis.set_tick_label_angle(self.y_tick_label_angle);
+        ver_axis.display_tick_labels(self.display_y_tick_labels);
 
         ver_axis.set_label(&self.default_y_axis_label);
 
         Ok((hor_axis, ver_axis))
     }
 
-    /// Fit this canvas to its plot
-    pub fn fit(
-        &mut self,
-        plot_frame: &shape::Rectangle,
-        plot_has_title: bool,
-    ) -> Result<(), Error> {
-        // TODO: This is a temporary hack that will be resolved with Issue #13
+    /// Sets the secondary horizontal axis along the top of the canvas, if any chart is drawn
+    /// against it, and likewise the secondary vertical axis along the right side of the canvas.
+    /// Their marks are computed from the secondary data frame, in the same way as for the default
+    /// axes, and their tick labels are outside of the canvas. Categories and minor marks are only
+    /// placed on the default axes.
+    fn set_secondary_axes(
+        &self,
+        secondary_data_frame: &shape::Rectangle,
+    ) -> Result<(Option<axis::Axis>, Option<axis::Axis>), Error> {
+        let mut top_axis = None;
+        if self.charts.iter().any(|chart| chart.axis_pair().is_top()) {
+            let mut axis = axis::Axis::with_boundaries(
+                &coord::Coord::with_coordinates(0.0, 1.0),
+                &coord::Coord::with_coordinates(1.0, 1.0),
+            );
+            axis.set_data_range(secondary_data_frame.left(), secondary_data_frame.right());
+            axis.set_fixed_range(self.hard_x_limits);
+            axis.set_scale(&self.secondary_x_scale);
+            axis.display_log_subdivisions(self.display_log_subdivisions);
+            axis.set_time_mode(
+                self.charts
+                    .iter()
+                    .any(|chart| chart.is_x_time() && chart.axis_pair().is_top()),
+            );
+            axis.set_time_format(self.x_time_format.clone());
+            axis.set_locator(&self.secondary_x_locator);
+            axis.set_formatter(&self.secondary_x_formatter);
+            axis.set_inverted(self.invert_x);
+            axis.compute_marks()?;
+
+            self.set_tick_style(&mut axis, true);
+            axis.set_tick_label_font_size(0.02);
+            axis.set_tick_label_frame_gaps(0.0, 0.0, 0.0, 0.0);
+            axis.set_tick_label_offset(0.05);
+            axis.set_tick_label_angle(self.x_tick_label_angle);
+
+            axis.set_label(&self.secondary_x_axis_label);
+            top_axis = Some(axis);
+        }
+
+        let mut right_axis = None;
+        if self.charts.iter().any(|chart| chart.axis_pair().is_right()) {
+            let mut axis = axis::Axis::with_boundaries(
+                &coord::Coord::with_coordinates(1.0, 0.0),
+                &coord::Coord::with_coordinates(1.0, 1.0),
+            );
+            axis.set_data_range(secondary_data_frame.bottom(), secondary_data_frame.top());
+            axis.set_fixed_range(self.hard_y_limits);
+            axis.set_scale(&self.secondary_y_scale);
+            axis.display_log_subdivisions(self.display_log_subdivisions);
+            axis.set_locator(&self.secondary_y_locator);
+            axis.set_formatter(&self.secondary_y_formatter);
+            axis.set_inverted(self.invert_y);
+            axis.compute_marks()?;
+
+            self.set_tick_style(&mut axis, true);
+            axis.set_tick_label_font_size(0.02);
+            axis.set_tick_label_frame_gaps(0.0, 0.0, 0.0, 0.0);
+            axis.set_tick_label_offset(0.05);
+            axis.set_tick_label_angle(self.y_tick_label_angle);
+
+            axis.set_label(&self.secondary_y_axis_label);
+            right_axis = Some(axis);
+        }
+
+        Ok((top_axis, right_axis))
+    }
+
+    /// Push the local frame to fixed fractions of the plot, to make room for the plot title, axis
+    /// labels, secondary axes, a colorbar and a legend outside of the canvas. A tight layout of the
+    /// figure measures the room that is needed in stead.
+    fn make_room(&mut self, plot_frame: &shape::Rectangle, plot_has_title: bool) {
+        let has_top_axis = self.charts.iter().any(|chart| chart.axis_pair().is_top());
+        let has_right_axis = self.charts.iter().any(|chart| chart.axis_pair().is_right());
         if plot_has_title {
             let new_top = self.local_frame.top().min(0.93);
             self.local_frame.set_top(new_top);
@@ -518,16 +1194,109 @@ impl Canvas {
             let new_left = self.local_frame.left().max(0.15);
             self.local_frame.set_left(new_left);
         }
+        if has_top_axis {
+            let max_top = if self.secondary_x_axis_label.content() != "" {
+                0.80
+            } else {
+                0.85
+            };
+            let new_top = self.local_frame.top().min(max_top);
+            self.local_frame.set_top(new_top);
+        }
+        if has_right_axis {
+            let max_right = if self.secondary_y_axis_label.content() != "" {
+                0.85
+            } else {
+                0.88
+            };
+            let new_right = self.local_frame.right().min(max_right);
+            self.local_frame.set_right(new_right);
+        }
+        // A legend outside of the canvas is given room to the right of it, within the plot
+        if let Some(ref legend) = self.legend {
+            if legend.is_outside() {
+                let canvas_diag_len = self.local_frame.relative_to(plot_frame).diag_len();
+                let legend_width = legend.width(&self.charts, canvas_diag_len);
+                if legend_width > 0.0 {
+                    let legend_room =
+                        legend_width + 2.0 * legend::Legend::margin(canvas_diag_len);
+                    let max_right = 1.0 - legend_room / plot_frame.width();
+                    let new_right = self.local_frame.right().min(max_right);
+                    self.local_frame.set_right(new_right);
+                }
+            }
+        }
+        // A colorbar is given room on its side of the canvas, which grows with the canvas
+        if let Some(ref colorbar) = self.colorbar {
+            let room = colorbar.room();
+            let (left, right) = (self.local_frame.left(), self.local_frame.right());
+            let (bottom, top) = (self.local_frame.bottom(), self.local_frame.top());
+            match colorbar.location() {
+                colorbar::ColorbarLocation::Right => {
+                    let max_right = (0.98 + room * left) / (1.0 + room);
+                    self.local_frame.set_right(right.min(max_right));
+                }
+                colorbar::ColorbarLocation::Left => {
+                    let min_left = (0.02 + room * right) / (1.0 + room);
+                    self.local_frame.set_left(left.max(min_left));
+                }
+                colorbar::ColorbarLocation::Top => {
+                    let top_limit = if plot_has_title { 0.93 } else { 0.98 };
+                    let max_top = (top_limit + room * bottom) / (1.0 + room);
+                    self.local_frame.set_top(top.min(max_top));
+                }
+                colorbar::ColorbarLocation::Bottom => {
+                    let min_bottom = (0.02 + room * top) / (1.0 + room);
+                    self.local_frame.set_bottom(bottom.max(min_bottom));
+                }
+            }
+        }
+    }
+
+    /// Fit this canvas to its plot
+    pub fn fit(
+        &mut self,
+        plot_frame: &shape::Rectangle,
+        plot_has_title: bool,
+    ) -> Result<(), Error> {
+        let has_top_axis = self.charts.iter().any(|chart| chart.axis_pair().is_top());
+        let has_right_axis = self.charts.iter().any(|chart| chart.axis_pair().is_right());
+        if !self.tight_layout {
+            self.make_room(plot_frame, plot_has_title);
+        }
         // First, we update the global_frame relative to the parent's global_frame.
         // After this is called, both local_frame and global_frame should not be altered.
-        self.local_frame.scale_size(plot_frame.diag_len()); //JIC we want to display the border
         self.global_frame = self.local_frame.relative_to(plot_frame);
+        let canvas_diag_len = self.global_frame.diag_len();
+        self.global_frame.scale_size(canvas_diag_len);
 
-        // Second, we update the data_frame
+        // Second, we update the data_frame, after the charts are placed at their categories
+        self.update_categories();
         let data_frame = self.compute_data_frame();
+        let secondary_data_frame = self.compute_secondary_data_frame(&data_frame);
+        Canvas::check_domain(&self.x_scale, data_frame.left(), data_frame.right(), "horizontal")?;
+        Canvas::check_domain(&self.y_scale, data_frame.bottom(), data_frame.top(), "vertical")?;
+        if has_top_axis {
+            Canvas::check_domain(
+                &self.secondary_x_scale,
+                secondary_data_frame.left(),
+                secondary_data_frame.right(),
+                "secondary horizontal",
+            )?;
+        }
+        if has_right_axis {
+            Canvas::check_domain(
+                &self.secondary_y_scale,
+                secondary_data_frame.bottom(),
+                secondary_data_frame.top(),
+                "secondary vertical",
+            )?;
+        }
 
-        // Then we compute one horizontal and one vertical axis.
+        // Then we compute one horizontal and one vertical axis, and the secondary axes that
+        // charts are drawn against.
         let (mut hor_axis, mut ver_axis) = self.set_default_axes(&data_frame)?;
+        let (mut top_axis, mut right_axis) = self.set_secondary_axes(&secondary_data_frame)?;
 
         // We can now define our updated data_frame.
         // TODO: Ord for f64 equivalent
@@ -541,30 +1310,246 @@ impl Canvas {
         let data_top = ver_axis.data_max();
         self.data_frame =
             shape::Rectangle::with_boundaries(data_left, data_right, data_bottom, data_top);
+        let (secondary_left, secondary_right) = match top_axis {
+            Some(ref axis) => (axis.data_min(), axis.data_max()),
+            None => (data_left, data_right),
+        };
+        let (secondary_bottom, secondary_top) = match right_axis {
+            Some(ref axis) => (axis.data_min(), axis.data_max()),
+            None => (data_bottom, data_top),
+        };
+        self.secondary_data_frame = shape::Rectangle::with_boundaries(
+            secondary_left,
+            secondary_right,
+            secondary_bottom,
+            secondary_top,
+        );
+
+        // The default axes are moved across the canvas to their positions, and the spines that
+        // are hidden keep their ticks and tick labels
+        let (y_first, y_last) = if self.invert_y {
+            (data_top, data_bottom)
+        } else {
+            (data_bottom, data_top)
+        };
+        let hor_offset = self.x_axis_position.local_offset(&self.y_scale, y_first, y_last);
+        hor_axis.shift(0.0, hor_offset);
+        if self.x_axis_position.moves_label() {
+            hor_axis.shift_label(0.0, hor_offset);
+        }
+        let (x_first, x_last) = if self.invert_x {
+            (data_right, data_left)
+        } else {
+            (data_left, data_right)
+        };
+        let ver_offset = self.y_axis_position.local_offset(&self.x_scale, x_first, x_last);
+        ver_axis.shift(ver_offset, 0.0);
+        if self.y_axis_position.moves_label() {
+            ver_axis.shift_label(ver_offset, 0.0);
+        }
+        hor_axis.display_line(self.display_bottom_spine);
+        ver_axis.display_line(self.display_left_spine);
+        if let Some(ref mut axis) = top_axis {
+            axis.display_line(self.display_top_spine.unwrap_or(true));
+        }
+        if let Some(ref mut axis) = right_axis {
+            axis.display_line(self.display_right_spine.unwrap_or(true));
+        }
 
         // Then, we update the axis, and charts based on this updated configuration
         ver_axis.fit(&self.global_frame);
         hor_axis.fit(&self.global_frame);
+        if let Some(ref mut axis) = top_axis {
+            axis.fit(&self.global_frame);
+        }
+        if let Some(ref mut axis) = right_axis {
+            axis.fit(&self.global_frame);
+        }
+
+        // Charts without a data coordinate system, like pie charts, hide the axes and the grid
+        let has_axes = self.charts.iter().all(|chart| chart.has_axes());
 
         // Set grid
-        self.compute_grid(&ver_axis, &hor_axis);
+        if has_axes {
+            self.compute_grid(&ver_axis, &hor_axis);
+        }
 
         let mut axes = Vec::<axis::Axis>::new();
-        if self.display_horizontal_axis {
+        if self.display_horizontal_axis && has_axes {
             axes.push(hor_axis);
         }
-        if self.display_vertical_axis {
+        if self.display_vertical_axis && has_axes {
             axes.push(ver_axis);
         }
+        if has_axes {
+            if top_axis.is_none() && self.display_top_spine.unwrap_or(false) {
+                axes.push(self.spine(0.0, 1.0, 1.0, 1.0));
+            }
+            if right_axis.is_none() && self.display_right_spine.unwrap_or(false) {
+                axes.push(self.spine(1.0, 0.0, 1.0, 1.0));
+            }
+            axes.extend(top_axis);
+            axes.extend(right_axis);
+        }
+        // The axes added by the user are placed in the updated data frame. They are kept as they
+        // were added, such that the canvas can be fitted again.
+        if has_axes {
+            let is_x_time = self.charts
+                .iter()
+                .any(|chart| chart.is_x_time() && !chart.axis_pair().is_top());
+            for user_axis in &self.user_axes {
+                let mut axis = user_axis.clone();
+                axis.set_canvas_data_frame(
+                    &self.data_frame,
+                    &self.x_scale,
+                    &self.y_scale,
+                    is_x_time,
+                )?;
+                axis.mirror(self.invert_x, self.invert_y);
+                axis.set_time_format(self.x_time_format.clone());
+                axis.compute_marks()?;
+                axis.fit(&self.global_frame);
+                axes.push(axis);
+            }
+        }
         self.axes = axes;
 
+        // Every chart is drawn in the data frame of the axes it is drawn against. On inverted
+        // axes, the charts are drawn in a mirrored canvas frame, such that the data locations
+        // decrease from its left or bottom side.
+        let mirrored_frame = shape::Rectangle::with_boundaries(
+            if self.invert_x { self.global_frame.right() } else { self.global_frame.left() },
+            if self.invert_x { self.global_frame.left() } else { self.global_frame.right() },
+            if self.invert_y { self.global_frame.top() } else { self.global_frame.bottom() },
+            if self.invert_y { self.global_frame.bottom() } else { self.global_frame.top() },
+        );
         for chart in &mut self.charts {
-            chart.fit(&self.global_frame, &self.data_frame);
+            let axis_pair = chart.axis_pair();
+            let (x_frame, x_scale) = if axis_pair.is_top() {
+                (&self.secondary_data_frame, &self.secondary_x_scale)
+            } else {
+                (&self.data_frame, &self.x_scale)
+            };
+            let (y_frame, y_scale) = if axis_pair.is_right() {
+                (&self.secondary_data_frame, &self.secondary_y_scale)
+            } else {
+                (&self.data_frame, &self.y_scale)
+            };
+            let chart_data_frame = shape::Rectangle::with_boundaries(
+                x_frame.left(),
+                x_frame.right(),
+                y_frame.bottom(),
+                y_frame.top(),
+            );
+            let chart_global_frame = if chart.has_axes() {
+                &mirrored_frame
+            } else {
+                &self.global_frame
+            };
+            chart.set_scales(x_scale, y_scale);
+            chart.fit(chart_global_frame, &chart_data_frame);
+        }
+
+        // The legend is placed last, clear of the fitted charts if possible
+        if let Some(ref mut legend) = self.legend {
+            legend.fit(&self.charts, &self.global_frame);
+        }
+        if let Some(ref mut colorbar) = self.colorbar {
+            colorbar.fit(&self.charts, &self.global_frame)?;
         }
 
         Ok(())
     }
 
+    /// Return a spine, that is, an axis without marks, from `(start_x, start_y)` to
+    /// `(end_x, end_y)` relative to the canvas
+    fn spine(&self, start_x: f64, start_y: f64, end_x: f64, end_y: f64) -> axis::Axis {
+        let mut spine = axis::Axis::with_boundaries(
+            &coord::Coord::with_coordinates(start_x, start_y),
+            &coord::Coord::with_coordinates(end_x, end_y),
+        );
+        spine.fit(&self.global_frame);
+        spine
+    }
+
+    /// Return an error if the data range of an axis is outside of the domain of its scale, or if
+    /// the scale itself is invalid
+    fn check_domain(
+        scale: &scale::Scale,
+        data_min: f64,
+        data_max: f64,
+        direction: &str,
+    ) -> Result<(), Error> {
+        if let scale::Scale::SymLog(linear_threshold) = *scale {
+            if !(linear_threshold > 0.0 && linear_threshold.is_finite()) {
+                return Err(err_msg(format!(
+                    "The linear threshold of the symmetric log scale of the {} axis must be \
+                     positive, but it is {}",
+                    direction, linear_threshold
+                )));
+            }
+        }
+        if scale.in_domain(data_min) && scale.in_domain(data_max) {
+            return Ok(());
+        }
+        Err(err_msg(format!(
+            "The {} data range [{}, {}] is outside of the domain of the axis scale, e.g. \
+             non-positive values on a logarithmic axis",
+            direction, data_min, data_max
+        )))
+    }
+
+    /// Move the axis labels of the default and secondary axes away from the canvas, as far as
+    /// they overlap the tick labels of their axes. The overlap is measured on a fitted copy of
+    /// this canvas.
+    pub(crate) fn clear_axis_labels(
+        &mut self,
+        fitted: &Canvas,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        let padding = layout::TIGHT_LAYOUT_PADDING;
+        let (height, width) = (fitted.global_frame.height(), fitted.global_frame.width());
+        for axis in &fitted.axes {
+            let clearance = axis.label_clearance(cr, fig_rel_height, fig_rel_width, padding);
+            if clearance <= 0.0 {
+                continue;
+            }
+            let (label, dx, dy) = match (axis.is_horizontal(), axis.tick_label_offset() < 0.0) {
+                (true, true) => (&mut self.default_x_axis_label, 0.0, -clearance / height),
+                (true, false) => (&mut self.secondary_x_axis_label, 0.0, clearance / height),
+                (false, true) => (&mut self.default_y_axis_label, -clearance / width, 0.0),
+                (false, false) => (&mut self.secondary_y_axis_label, clearance / width, 0.0),
+            };
+            let centroid = label.centroid();
+            label.set_centroid(centroid.x() + dx, centroid.y() + dy);
+        }
+    }
+
+    /// Return the frames that the axis labels, tick labels, a colorbar and a legend outside of the
+    /// canvas cover in the figure. The canvas must be fitted first.
+    pub(crate) fn text_extents(
+        &self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) -> Vec<shape::Rectangle> {
+        let mut extents = Vec::<shape::Rectangle>::new();
+        for axis in &self.axes {
+            extents.extend(axis.text_extents(cr, fig_rel_height, fig_rel_width));
+        }
+        if let Some(ref legend) = self.legend {
+            if legend.is_outside() {
+                extents.push(legend.frame());
+            }
+        }
+        if let Some(ref colorbar) = self.colorbar {
+            extents.extend(colorbar.extents(cr, fig_rel_height, fig_rel_width));
+        }
+        extents
+    }
+
     /// Draw the canvas
     pub fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
         // Background
@@ -591,13 +1576,43 @@ impl Canvas {
             axis.draw(cr, fig_rel_height, fig_rel_width);
         }
 
+        // Charts with several series (e.g. bar charts) draw one color per uncolored series
         let mut color_generator = color::ChartColorGenerator::new();
-        for chart in &mut self.charts.clone() {
-            if !chart.is_color_updated() {
+        let mut charts = self.charts.clone();
+        for chart in &mut charts {
+            while !chart.is_color_updated() {
                 let color = color_generator.next();
                 chart.set_color_internal(color.expect("Something wrong"));
             }
-            chart.draw(cr, fig_rel_height, fig_rel_width);
+            // Data outside of the data range is cut at the canvas boundary, in stead of being
+            // drawn over the axes and the neighbouring plots
+            if chart.clip_to_canvas() {
+                cr.save();
+                cr.rectangle(
+                    self.global_frame.left(),
+                    self.global_frame.bottom(),
+                    self.global_frame.width(),
+                    self.global_frame.height(),
+                );
+                cr.clip();
+                chart.draw(cr, fig_rel_height, fig_rel_width);
+                cr.restore();
+            } else {
+                chart.draw(cr, fig_rel_height, fig_rel_width);
+            }
+        }
+
+        // The box frame is drawn on top of the charts, such that data at the edges hides no part
+        // of it
+        self.global_frame.draw(cr, fig_rel_height, fig_rel_width);
+
+        // The legend shows the charts in the colors they were given above
+        if let Some(ref legend) = self.legend {
+            legend.draw(cr, &charts, fig_rel_height, fig_rel_width);
+        }
+
+        if let Some(ref colorbar) = self.colorbar {
+            colorbar.draw(cr, fig_rel_height, fig_rel_width);
         }
     }
 }
@@ -0,0 +1,66 @@
+//! Categories of categorical axes, and their ordering
+//!
+
+/// The order of the categories along a categorical axis.
+///
+/// | Variant      | Order                                                                       |
+/// | ------------ | --------------------------------------------------------------------------- |
+/// | Appearance   | The order in which the categories first appear in the charts (default).     |
+/// | Ascending    | Alphabetical order.                                                         |
+/// | Descending   | Reverse alphabetical order.                                                 |
+/// | Custom(..)   | The given order. Categories not in the list follow in order of appearance.  |
+#[derive(Clone, Debug)]
+pub enum CategoryOrder {
+    Appearance,
+    Ascending,
+    Descending,
+    Custom(Vec<String>),
+}
+
+impl CategoryOrder {
+    /// Create and return a custom order from a list of category names
+    pub fn custom(categories: &[&str]) -> CategoryOrder {
+        CategoryOrder::Custom(categories.iter().map(|name| String::from(*name)).collect())
+    }
+}
+
+/// Return the distinct categories in `names`, in the given order. The position of a category on
+/// the axis is its index in the returned list. Without any names, the axis is not categorical,
+/// and the list is empty.
+pub(crate) fn order_categories(names: &[String], order: &CategoryOrder) -> Vec<String> {
+    let mut categories = Vec::<String>::new();
+    if names.is_empty() {
+        return categories;
+    }
+    if let CategoryOrder::Custom(ref custom_order) = *order {
+        for name in custom_order {
+            if !categories.contains(name) {
+                categories.push(name.clone());
+            }
+        }
+    }
+    for name in names {
+        if !categories.contains(name) {
+            categories.push(name.clone());
+        }
+    }
+    match *order {
+        CategoryOrder::Ascending => categories.sort(),
+        CategoryOrder::Descending => categories.sort_by(|a, b| b.cmp(a)),
+        CategoryOrder::Appearance | CategoryOrder::Custom(_) => {}
+    }
+    categories
+}
+
+/// Return the position of every name along an axis with the given categories
+pub(crate) fn category_positions(names: &[String], categories: &[String]) -> Vec<f64> {
+    names
+        .iter()
+        .map(|name| {
+            categories
+                .iter()
+                .position(|category| category == name)
+                .map_or(f64::NAN, |index| index as f64)
+        })
+        .collect()
+}
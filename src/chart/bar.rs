@@ -0,0 +1,568 @@
+//! Module that defines the Bar struct
+//!
+
+use std::f64;
+
+use cairo::Context;
+use ndarray::AsArray;
+use palette::Srgba;
+
+use {category, chart, color, coord, scale, shape, utils};
+
+/// The direction in which the bars extend from the baseline.
+#[derive(Clone, Debug)]
+pub enum BarOrientation {
+    Vertical,
+    Horizontal,
+}
+
+/// How multiple series in the same bar chart are placed relative to each other.
+///
+/// Grouped series are placed side by side within the bar width, while stacked series are placed
+/// on top of each other. Stacked heights below the baseline are stacked downwards from it.
+#[derive(Clone, Debug)]
+pub enum BarLayout {
+    Grouped,
+    Stacked,
+}
+
+/// A single series of bar heights, sharing the positions of the chart it belongs to.
+#[derive(Clone, Debug)]
+struct BarSeries {
+    heights: Vec<f64>,
+    color: color::Color,
+    is_color_updated: bool,
+    bar_colors: Vec<Option<color::Color>>,
+}
+
+impl BarSeries {
+    fn new(heights: Vec<f64>) -> BarSeries {
+        let num_bars = heights.len();
+        BarSeries {
+            heights: heights,
+            color: color::Color::with_custom(&color::CustomColor::Blue),
+            is_color_updated: false,
+            bar_colors: vec![None; num_bars],
+        }
+    }
+
+    fn set_color_internal(&mut self, color: Srgba) {
+        self.color.set_color(color);
+        self.is_color_updated = true;
+    }
+
+    fn set_bar_color_internal(&mut self, index: usize, color: Srgba) {
+        if index < self.bar_colors.len() {
+            let mut bar_color = color::Color::new();
+            bar_color.set_color(color);
+            self.bar_colors[index] = Some(bar_color);
+        }
+    }
+
+    fn bar_color(&self, index: usize) -> Srgba {
+        match self.bar_colors.get(index) {
+            Some(&Some(ref bar_color)) => bar_color.as_srgba(),
+            _ => self.color.as_srgba(),
+        }
+    }
+}
+
+/// Bar chart
+///
+/// Displays data as rectangular bars extending from a baseline. Each bar is centered at a
+/// position, and its length is determined by its height. Multiple series can be added to the
+/// same chart, and they are either grouped side by side, or stacked on top of each other.
+///
+/// Setters that concern a series, such as `set_color()` and `set_bar_color()`, apply to the most
+/// recently added series.
+#[derive(Clone, Debug)]
+pub struct Bar {
+    positions: Vec<f64>,
+    categories: Vec<String>,
+    series: Vec<BarSeries>,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    orientation: BarOrientation,
+    layout: BarLayout,
+    bar_width: f64,
+    is_bar_width_updated: bool,
+    baseline: f64,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
+}
+
+impl Bar {
+    /// Create and return a new Bar chart. The bars are centered at `positions`, and extend from
+    /// the baseline to `heights`.
+    pub fn new<'a, I: AsArray<'a, f64>>(positions: I, heights: I) -> Bar {
+        let positions: Vec<f64> = positions.into().iter().cloned().collect();
+        let heights: Vec<f64> = heights.into().iter().cloned().collect();
+        let mut bar = Bar {
+            positions: positions,
+            categories: Vec::<String>::new(),
+            series: vec![BarSeries::new(heights)],
+            data_frame: shape::Rectangle::new(),
+            global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            orientation: BarOrientation::Vertical,
+            layout: BarLayout::Grouped,
+            bar_width: 0.8,
+            is_bar_width_updated: false,
+            baseline: 0.0,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
+        };
+        bar.update_data_frame();
+        bar
+    }
+
+    /// Create and return a new Bar chart with one bar per category, extending from the baseline to
+    /// `heights`. The category axis of the canvas places the bars, and labels them with the
+    /// category names.
+    pub fn with_categories<'a, I: AsArray<'a, f64>>(categories: &[&str], heights: I) -> Bar {
+        let categories: Vec<String> = categories.iter().map(|name| String::from(*name)).collect();
+        let ordered_categories =
+            category::order_categories(&categories, &category::CategoryOrder::Appearance);
+        let positions = category::category_positions(&categories, &ordered_categories);
+        let heights: Vec<f64> = heights.into().iter().cloned().collect();
+        let mut bar = Bar::new(&positions, &heights);
+        bar.categories = categories;
+        bar
+    }
+
+    /// Add a new series of bar heights, located at the same positions as the first series.
+    pub fn add_series<'a, I: AsArray<'a, f64>>(&mut self, heights: I) -> &mut Self {
+        let heights: Vec<f64> = heights.into().iter().cloned().collect();
+        self.series.push(BarSeries::new(heights));
+        self.update_data_frame();
+        self
+    }
+
+    /// Set the orientation of the bars. Vertical bars extend along the y axis, and horisontal bars
+    /// extend along the x axis.
+    pub fn set_orientation(&mut self, orientation: BarOrientation) -> &mut Self {
+        self.orientation = orientation;
+        self.update_data_frame();
+        self
+    }
+
+    /// Set how multiple series are placed relative to each other.
+    pub fn set_layout(&mut self, layout: BarLayout) -> &mut Self {
+        self.layout = layout;
+        self.update_data_frame();
+        self
+    }
+
+    /// Set the width of the bars, in data coordinates. When series are grouped, this is the width
+    /// of the whole group.
+    ///
+    /// On a categorical axis, the bars fill the distance between neighbouring categories, less the
+    /// category padding of the plot, unless a width is set here.
+    pub fn set_bar_width(&mut self, val: f64) -> &mut Self {
+        self.bar_width = val;
+        self.is_bar_width_updated = true;
+        self.update_data_frame();
+        self
+    }
+
+    /// Set the value the bars extend from.
+    pub fn set_baseline(&mut self, val: f64) -> &mut Self {
+        self.baseline = val;
+        self.update_data_frame();
+        self
+    }
+
+    /// Set the color of the most recently added series
+    pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.set_series_color(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the most recently added series
+    pub fn set_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_series_color(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the most recently added series
+    pub fn set_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_series_color(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the most recently added series
+    pub fn set_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_series_color(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the most recently added series
+    pub fn set_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_series_color(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the most recently added series
+    pub fn set_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.set_series_color(color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single bar in the most recently added series
+    pub fn set_bar_color(&mut self, index: usize, color: &color::CustomColor) -> &mut Self {
+        self.set_bar_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single bar in the most recently added series
+    pub fn set_bar_color_rgb(&mut self, index: usize, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_bar_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single bar in the most recently added series
+    pub fn set_bar_color_rgba(
+        &mut self,
+        index: usize,
+        red: f32,
+        green: f32,
+        blue: f32,
+        alpha: f32,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_bar_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single bar in the most recently added series
+    pub fn set_bar_color_rgb_u8(&mut self, index: usize, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_bar_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single bar in the most recently added series
+    pub fn set_bar_color_rgba_u8(
+        &mut self,
+        index: usize,
+        red: u8,
+        green: u8,
+        blue: u8,
+        alpha: u8,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_bar_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single bar in the most recently added series
+    pub fn set_bar_color_html(&mut self, index: usize, color: &color::HtmlColor) -> &mut Self {
+        self.set_bar_color_internal(index, color.as_srgba());
+        self
+    }
+
+    fn set_series_color(&mut self, color: Srgba) {
+        if let Some(series) = self.series.last_mut() {
+            series.set_color_internal(color);
+        }
+    }
+
+    fn set_bar_color_internal(&mut self, index: usize, color: Srgba) {
+        if let Some(series) = self.series.last_mut() {
+            series.set_bar_color_internal(index, color);
+        }
+    }
+
+    /// Return the extent of every bar as `(position_start, position_end, value_start,
+    /// value_end)`, together with the series index and the bar index. All values are in data
+    /// coordinates. Stacked bars above and below the baseline are stacked separately, away from
+    /// the baseline.
+    fn bar_extents(&self) -> Vec<(usize, usize, [f64; 4])> {
+        let num_series = self.series.len();
+        let mut positive_stack_tops = vec![self.baseline; self.positions.len()];
+        let mut negative_stack_tops = vec![self.baseline; self.positions.len()];
+        let mut extents = Vec::<(usize, usize, [f64; 4])>::new();
+        for (series_index, series) in self.series.iter().enumerate() {
+            for (bar_index, (position, height)) in
+                self.positions.iter().zip(series.heights.iter()).enumerate()
+            {
+                let extent = match self.layout {
+                    BarLayout::Grouped => {
+                        let sub_width = self.bar_width / num_series as f64;
+                        let start =
+                            position - self.bar_width / 2.0 + series_index as f64 * sub_width;
+                        [start, start + sub_width, self.baseline, *height]
+                    }
+                    BarLayout::Stacked => {
+                        let stack_tops = if *height >= self.baseline {
+                            &mut positive_stack_tops
+                        } else {
+                            &mut negative_stack_tops
+                        };
+                        let value_start = stack_tops[bar_index];
+                        let value_end = value_start + height - self.baseline;
+                        stack_tops[bar_index] = value_end;
+                        [
+                            position - self.bar_width / 2.0,
+                            position + self.bar_width / 2.0,
+                            value_start,
+                            value_end,
+                        ]
+                    }
+                };
+                extents.push((series_index, bar_index, extent));
+            }
+        }
+        extents
+    }
+
+    /// Update the data frame such that it encloses all bars, including the baseline.
+    fn update_data_frame(&mut self) {
+        let mut position_min = f64::MAX;
+        let mut position_max = f64::MIN;
+        let mut value_min = self.baseline;
+        let mut value_max = self.baseline;
+        for (_, _, extent) in self.bar_extents() {
+            position_min = position_min.min(extent[0]);
+            position_max = position_max.max(extent[1]);
+            value_min = value_min.min(extent[2].min(extent[3]));
+            value_max = value_max.max(extent[2].max(extent[3]));
+        }
+        if position_min > position_max {
+            position_min = self.baseline;
+            position_max = self.baseline;
+        }
+        self.data_frame = match self.orientation {
+            BarOrientation::Vertical => {
+                shape::Rectangle::with_boundaries(position_min, position_max, value_min, value_max)
+            }
+            BarOrientation::Horizontal => {
+                shape::Rectangle::with_boundaries(value_min, value_max, position_min, position_max)
+            }
+        };
+    }
+
+    /// Return the category of every bar along the horizontal axis, if the bars are vertical
+    pub(crate) fn x_categories(&self) -> Vec<String> {
+        match self.orientation {
+            BarOrientation::Vertical => self.categories.clone(),
+            BarOrientation::Horizontal => Vec::new(),
+        }
+    }
+
+    /// Return the category of every bar along the vertical axis, if the bars are horizontal
+    pub(crate) fn y_categories(&self) -> Vec<String> {
+        match self.orientation {
+            BarOrientation::Vertical => Vec::new(),
+            BarOrientation::Horizontal => self.categories.clone(),
+        }
+    }
+
+    /// Place the bars at their categories, given the ordered categories of the horizontal and
+    /// vertical axis. `padding` is the gap between neighbouring categories, in data coordinates.
+    pub(crate) fn set_category_positions(
+        &mut self,
+        x_categories: &[String],
+        y_categories: &[String],
+        padding: f64,
+    ) {
+        let categories = match self.orientation {
+            BarOrientation::Vertical => x_categories,
+            BarOrientation::Horizontal => y_categories,
+        };
+        if self.categories.is_empty() || categories.is_empty() {
+            return;
+        }
+        self.positions = category::category_positions(&self.categories, categories);
+        if !self.is_bar_width_updated {
+            self.bar_width = (1.0 - padding).max(0.0);
+        }
+        self.update_data_frame();
+    }
+
+    /// Whether or not to clip the bars at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the bars are clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the bars are drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the bars are drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the bar chart in the legend of the plot. All series of the chart share one
+    /// entry, with the color of the first series. Charts without a label are not listed in the
+    /// legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the bar chart in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+    }
+
+    /// Return the series index, the bar index and the boundaries `[x_start, x_end, y_start,
+    /// y_end]` of each bar, in canvas coordinates
+    fn canvas_extents(&self) -> Vec<(usize, usize, [f64; 4])> {
+        self.bar_extents()
+            .into_iter()
+            .map(|(series_index, bar_index, extent)| {
+                let (x_start, x_end, y_start, y_end) = match self.orientation {
+                    BarOrientation::Vertical => (extent[0], extent[1], extent[2], extent[3]),
+                    BarOrientation::Horizontal => (extent[2], extent[3], extent[0], extent[1]),
+                };
+                let canvas_x_start = self.x_scale.map_range(
+                    x_start,
+                    self.data_frame.left(),
+                    self.data_frame.right(),
+                    self.global_frame.left(),
+                    self.global_frame.right(),
+                );
+                let canvas_x_end = self.x_scale.map_range(
+                    x_end,
+                    self.data_frame.left(),
+                    self.data_frame.right(),
+                    self.global_frame.left(),
+                    self.global_frame.right(),
+                );
+                let canvas_y_start = self.y_scale.map_range(
+                    y_start,
+                    self.data_frame.bottom(),
+                    self.data_frame.top(),
+                    self.global_frame.bottom(),
+                    self.global_frame.top(),
+                );
+                let canvas_y_end = self.y_scale.map_range(
+                    y_end,
+                    self.data_frame.bottom(),
+                    self.data_frame.top(),
+                    self.global_frame.bottom(),
+                    self.global_frame.top(),
+                );
+                (
+                    series_index,
+                    bar_index,
+                    [canvas_x_start, canvas_x_end, canvas_y_start, canvas_y_end],
+                )
+            })
+            .collect()
+    }
+
+    /// Return locations spread over the bars, relative to the figure, such that the legend can
+    /// avoid them
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        let mut coords = Vec::<coord::Coord>::new();
+        for (_, _, extent) in self.canvas_extents() {
+            coords.extend(chart::area_coords(extent[0], extent[1], extent[2], extent[3]));
+        }
+        coords
+    }
+
+    /// Draw a patch with the color of the first series, within `sample_frame`
+    pub(crate) fn draw_legend_sample(&self, cr: &Context, sample_frame: &shape::Rectangle) {
+        if let Some(series) = self.series.first() {
+            chart::draw_patch_sample(cr, sample_frame, series.color.as_srgba());
+        }
+    }
+}
+
+impl utils::Drawable for Bar {
+    /// Colors the first series that has not been given a color yet.
+    fn set_color_internal(&mut self, color: Srgba) {
+        if let Some(series) = self.series.iter_mut().find(|s| !s.is_color_updated) {
+            series.set_color_internal(color);
+        }
+    }
+
+    /// Returns true when every series has been given a color.
+    fn is_color_updated(&self) -> bool {
+        self.series.iter().all(|s| s.is_color_updated)
+    }
+
+    fn scale_size(&mut self, _: f64) {}
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+    }
+
+    fn draw(&self, cr: &Context, _: f64, _: f64) {
+        for (series_index, bar_index, extent) in self.canvas_extents() {
+            let bar_color = self.series[series_index].bar_color(bar_index);
+            cr.set_source_rgba(
+                f64::from(bar_color.red),
+                f64::from(bar_color.green),
+                f64::from(bar_color.blue),
+                f64::from(bar_color.alpha),
+            );
+            cr.rectangle(
+                extent[0],
+                extent[2],
+                extent[1] - extent[0],
+                extent[3] - extent[2],
+            );
+            cr.fill();
+        }
+    }
+}
+
+impl utils::Plottable for Bar {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
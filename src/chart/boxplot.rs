@@ -0,0 +1,674 @@
+//! Module that defines the BoxPlot struct
+//!
+
+use std::f64;
+
+use cairo::Context;
+use ndarray::AsArray;
+use palette::Srgba;
+
+use {chart, color, coord, scale, shape, utils};
+
+/// Whether the boxes are standing up along the horizontal axis, or lying along the vertical axis.
+#[derive(Clone, Debug)]
+pub enum BoxOrientation {
+    Vertical,
+    Horizontal,
+}
+
+/// How the ends of the whiskers are determined. Samples outside of the whiskers are drawn as
+/// outliers.
+///
+/// | Variant                | Whisker ends                                                    |
+/// | ---------------------- | --------------------------------------------------------------- |
+/// | `Tukey(k)`             | The most extreme samples within `k` IQR of the box (`k = 1.5`)  |
+/// | `MinMax`               | The smallest and largest samples. There are no outliers         |
+/// | `Percentile(low, high)`| The `low`-th and `high`-th percentiles of the samples           |
+#[derive(Clone, Debug)]
+pub enum Whiskers {
+    Tukey(f64),
+    MinMax,
+    Percentile(f64, f64),
+}
+
+/// Summary statistics of one sample group
+#[derive(Clone, Debug)]
+struct BoxStats {
+    lower_quartile: f64,
+    median: f64,
+    upper_quartile: f64,
+    lower_whisker: f64,
+    upper_whisker: f64,
+    lower_notch: f64,
+    upper_notch: f64,
+    outliers: Vec<f64>,
+}
+
+impl BoxStats {
+    fn with_samples(sorted_samples: &[f64], whiskers: &Whiskers) -> BoxStats {
+        let lower_quartile = utils::percentile(sorted_samples, 25.0);
+        let median = utils::percentile(sorted_samples, 50.0);
+        let upper_quartile = utils::percentile(sorted_samples, 75.0);
+        let iqr = upper_quartile - lower_quartile;
+        let first = sorted_samples.first().cloned().unwrap_or(f64::NAN);
+        let last = sorted_samples.last().cloned().unwrap_or(f64::NAN);
+
+        let (lower_whisker, upper_whisker) = match *whiskers {
+            Whiskers::Tukey(factor) => {
+                let lower_fence = lower_quartile - factor * iqr;
+                let upper_fence = upper_quartile + factor * iqr;
+                (
+                    sorted_samples
+                        .iter()
+                        .cloned()
+                        .find(|&val| val >= lower_fence)
+                        .unwrap_or(lower_quartile),
+                    sorted_samples
+                        .iter()
+                        .cloned()
+                        .rev()
+                        .find(|&val| val <= upper_fence)
+                        .unwrap_or(upper_quartile),
+                )
+            }
+            Whiskers::MinMax => (first, last),
+            Whiskers::Percentile(low, high) => (
+                utils::percentile(sorted_samples, low),
+                utils::percentile(sorted_samples, high),
+            ),
+        };
+
+        // The notch spans an approximate 95 % confidence interval of the median
+        let notch_half_height = 1.57 * iqr / (sorted_samples.len().max(1) as f64).sqrt();
+
+        BoxStats {
+            lower_quartile: lower_quartile,
+            median: median,
+            upper_quartile: upper_quartile,
+            lower_whisker: lower_whisker,
+            upper_whisker: upper_whisker,
+            lower_notch: (median - notch_half_height).max(lower_quartile),
+            upper_notch: (median + notch_half_height).min(upper_quartile),
+            outliers: sorted_samples
+                .iter()
+                .cloned()
+                .filter(|&val| val < lower_whisker || val > upper_whisker)
+                .collect(),
+        }
+    }
+
+    /// Return the smallest and largest value drawn
+    fn value_range(&self) -> (f64, f64) {
+        let min_val = self.outliers
+            .iter()
+            .cloned()
+            .fold(self.lower_whisker.min(self.lower_quartile), f64::min);
+        let max_val = self.outliers
+            .iter()
+            .cloned()
+            .fold(self.upper_whisker.max(self.upper_quartile), f64::max);
+        (min_val, max_val)
+    }
+}
+
+/// Box plot chart
+///
+/// Summarizes one or more groups of samples. For each group, a box spans the lower to the upper
+/// quartile, with a line at the median, and whiskers extending from the box. Samples beyond the
+/// whiskers are drawn as outlier points.
+///
+/// Group `k` is placed at position `k` along the category axis, and the axis is labeled with the
+/// group labels, or with the group indices if no labels are set.
+#[derive(Clone, Debug)]
+pub struct BoxPlot {
+    groups: Vec<Vec<f64>>,
+    stats: Vec<BoxStats>,
+    group_labels: Vec<String>,
+    whiskers: Whiskers,
+    orientation: BoxOrientation,
+    is_notched: bool,
+    box_width: f64,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    color: color::Color,
+    is_color_updated: bool,
+    line_color: color::Color,
+    line_width: f64,
+    outlier_shape: chart::point::Shape,
+    outlier_size: f64,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
+}
+
+impl BoxPlot {
+    /// Create and return a new BoxPlot chart with a single group of samples. NaN values are
+    /// ignored.
+    pub fn new<'a, I: AsArray<'a, f64>>(samples: I) -> BoxPlot {
+        let mut box_plot = BoxPlot {
+            groups: Vec::<Vec<f64>>::new(),
+            stats: Vec::<BoxStats>::new(),
+            group_labels: Vec::<String>::new(),
+            whiskers: Whiskers::Tukey(1.5),
+            orientation: BoxOrientation::Vertical,
+            is_notched: false,
+            box_width: 0.5,
+            data_frame: shape::Rectangle::new(),
+            global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            color: color::Color::with_custom(&color::CustomColor::Blue),
+            is_color_updated: false,
+            line_color: color::Color::with_custom(&color::CustomColor::AxisLine),
+            line_width: 0.0025,
+            outlier_shape: chart::point::Shape::Circle,
+            outlier_size: 0.004,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
+        };
+        box_plot.add_group(samples);
+        box_plot
+    }
+
+    /// Add a group of samples. NaN values are ignored.
+    pub fn add_group<'a, I: AsArray<'a, f64>>(&mut self, samples: I) -> &mut Self {
+        let mut sorted_samples: Vec<_> = samples
+            .into()
+            .iter()
+            .filter_map(|v| utils::NonNan::new(*v))
+            .collect();
+        sorted_samples.sort();
+        self.groups
+            .push(sorted_samples.iter().map(|v| v.val()).collect());
+        self.update_stats();
+        self
+    }
+
+    /// Set the labels of the groups, in the order they were added. These are displayed on the
+    /// category axis.
+    pub fn set_group_labels(&mut self, labels: &[&str]) -> &mut Self {
+        self.group_labels = labels.iter().map(|label| String::from(*label)).collect();
+        self
+    }
+
+    /// Set how the whisker ends are determined. The default is `Whiskers::Tukey(1.5)`.
+    pub fn set_whiskers(&mut self, whiskers: Whiskers) -> &mut Self {
+        self.whiskers = whiskers;
+        self.update_stats();
+        self
+    }
+
+    /// Set the orientation of the boxes
+    pub fn set_orientation(&mut self, orientation: BoxOrientation) -> &mut Self {
+        self.orientation = orientation;
+        self.update_stats();
+        self
+    }
+
+    /// Whether or not to draw a notch around the median, indicating a confidence interval of the
+    /// median
+    pub fn set_notched(&mut self, val: bool) -> &mut Self {
+        self.is_notched = val;
+        self
+    }
+
+    /// Set the width of the boxes, relative to the distance between neighbouring groups
+    pub fn set_box_width(&mut self, val: f64) -> &mut Self {
+        self.box_width = val;
+        self
+    }
+
+    /// Set the line width of the box edges, medians and whiskers
+    pub fn set_line_width(&mut self, val: f64) -> &mut Self {
+        self.line_width = val;
+        self
+    }
+
+    /// Set the size of the outlier points
+    pub fn set_outlier_size(&mut self, val: f64) -> &mut Self {
+        self.outlier_size = val;
+        self
+    }
+
+    /// Set the shape of the outlier points. See the Scatter chart for valid inputs.
+    #[allow(unknown_lints)]
+    #[allow(match_same_arms)]
+    pub fn set_outlier_shape(&mut self, shape_id: &str) -> &mut Self {
+        self.outlier_shape = match shape_id {
+            "Circle" | "circle" | "c" | "o" => chart::point::Shape::Circle,
+            "Square" | "square" | "s" => chart::point::Shape::Square,
+            "Tick" | "tick" | "t" => chart::point::Shape::Tick,
+            _ => chart::point::Shape::Circle,
+        };
+        self
+    }
+
+    /// Set the box color
+    pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.color.set_color_custom(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the box color
+    pub fn set_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        self.color.set_color_rgb(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the box color
+    pub fn set_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        self.color.set_color_rgba(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the box color
+    pub fn set_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        self.color.set_color_rgb_u8(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the box color
+    pub fn set_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        self.color.set_color_rgba_u8(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the box color
+    pub fn set_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.color.set_color_html(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Return the category marks along the group positions, as `(data location, label)` pairs
+    fn category_marks(&self) -> Vec<(f64, String)> {
+        (0..self.groups.len())
+            .map(|k| match self.group_labels.get(k) {
+                Some(label) => (k as f64, label.clone()),
+                None => (k as f64, k.to_string()),
+            })
+            .collect()
+    }
+
+    /// Return the category marks of the horizontal axis, as `(data location, label)` pairs
+    pub(crate) fn x_category_marks(&self) -> Vec<(f64, String)> {
+        match self.orientation {
+            BoxOrientation::Vertical => self.category_marks(),
+            BoxOrientation::Horizontal => Vec::new(),
+        }
+    }
+
+    /// Return the category marks of the vertical axis, as `(data location, label)` pairs
+    pub(crate) fn y_category_marks(&self) -> Vec<(f64, String)> {
+        match self.orientation {
+            BoxOrientation::Vertical => Vec::new(),
+            BoxOrientation::Horizontal => self.category_marks(),
+        }
+    }
+
+    /// Recompute the statistics of every group, and the data frame
+    fn update_stats(&mut self) {
+        self.stats = self.groups
+            .iter()
+            .map(|group| BoxStats::with_samples(group, &self.whiskers))
+            .collect();
+
+        let mut value_min = f64::INFINITY;
+        let mut value_max = f64::NEG_INFINITY;
+        for stats in self.stats.iter().filter(|stats| !stats.median.is_nan()) {
+            let (group_min, group_max) = stats.value_range();
+            value_min = value_min.min(group_min);
+            value_max = value_max.max(group_max);
+        }
+        if value_min > value_max {
+            value_min = 0.0;
+            value_max = 1.0;
+        }
+        let position_min = -0.5;
+        let position_max = self.groups.len() as f64 - 0.5;
+        self.data_frame = match self.orientation {
+            BoxOrientation::Vertical => shape::Rectangle::with_boundaries(
+                position_min,
+                position_max,
+                value_min,
+                value_max,
+            ),
+            BoxOrientation::Horizontal => shape::Rectangle::with_boundaries(
+                value_min,
+                value_max,
+                position_min,
+                position_max,
+            ),
+        };
+    }
+
+    /// Map a (position, value) coordinate to the canvas
+    fn canvas_coord(&self, position: f64, value: f64) -> (f64, f64) {
+        let (x_coord, y_coord) = match self.orientation {
+            BoxOrientation::Vertical => (position, value),
+            BoxOrientation::Horizontal => (value, position),
+        };
+        let canvas_x = self.x_scale.map_range(
+            x_coord,
+            self.data_frame.left(),
+            self.data_frame.right(),
+            self.global_frame.left(),
+            self.global_frame.right(),
+        );
+        let canvas_y = self.y_scale.map_range(
+            y_coord,
+            self.data_frame.bottom(),
+            self.data_frame.top(),
+            self.global_frame.bottom(),
+            self.global_frame.top(),
+        );
+        (canvas_x, canvas_y)
+    }
+
+    /// Stroke a straight line between two (position, value) coordinates
+    fn stroke_line(
+        &self,
+        cr: &Context,
+        start: (f64, f64),
+        end: (f64, f64),
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        let (start_x, start_y) = self.canvas_coord(start.0, start.1);
+        let (end_x, end_y) = self.canvas_coord(end.0, end.1);
+        let is_vertical = (end_x - start_x).abs() < (end_y - start_y).abs();
+        let line_width = if is_vertical {
+            self.line_width * fig_rel_height
+        } else {
+            self.line_width * fig_rel_width
+        };
+        cr.set_line_width(line_width);
+        cr.move_to(start_x, start_y);
+        cr.line_to(end_x, end_y);
+        cr.stroke();
+    }
+
+    /// Return the outline of a box as (position, value) coordinates
+    fn box_outline(&self, position: f64, stats: &BoxStats) -> Vec<(f64, f64)> {
+        let left = position - self.box_width / 2.0;
+        let right = position + self.box_width / 2.0;
+        if self.is_notched {
+            let notch_left = position - self.box_width / 4.0;
+            let notch_right = position + self.box_width / 4.0;
+            vec![
+                (left, stats.lower_quartile),
+                (right, stats.lower_quartile),
+                (right, stats.lower_notch),
+                (notch_right, stats.median),
+                (right, stats.upper_notch),
+                (right, stats.upper_quartile),
+                (left, stats.upper_quartile),
+                (left, stats.upper_notch),
+                (notch_left, stats.median),
+                (left, stats.lower_notch),
+            ]
+        } else {
+            vec![
+                (left, stats.lower_quartile),
+                (right, stats.lower_quartile),
+                (right, stats.upper_quartile),
+                (left, stats.upper_quartile),
+            ]
+        }
+    }
+
+    /// Whether or not to clip the boxes at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the boxes are clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the boxes are drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the boxes are drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the box plot in the legend of the plot. Box plots without a label are not
+    /// listed in the legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the box plot in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+    }
+
+    /// Return locations spread over the boxes, along the whiskers and at the outliers, relative
+    /// to the figure, such that the legend can avoid them
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        let mut coords = Vec::<coord::Coord>::new();
+        for (k, stats) in self.stats.iter().enumerate() {
+            if stats.median.is_nan() {
+                continue;
+            }
+            let position = k as f64;
+            let (box_start_x, box_start_y) =
+                self.canvas_coord(position - self.box_width / 2.0, stats.lower_quartile);
+            let (box_end_x, box_end_y) =
+                self.canvas_coord(position + self.box_width / 2.0, stats.upper_quartile);
+            coords.extend(chart::area_coords(box_start_x, box_end_x, box_start_y, box_end_y));
+
+            let (whisker_start_x, whisker_start_y) =
+                self.canvas_coord(position, stats.lower_whisker);
+            let (whisker_end_x, whisker_end_y) = self.canvas_coord(position, stats.upper_whisker);
+            coords.extend(chart::area_coords(
+                whisker_start_x,
+                whisker_end_x,
+                whisker_start_y,
+                whisker_end_y,
+            ));
+
+            for &outlier in &stats.outliers {
+                let (canvas_x, canvas_y) = self.canvas_coord(position, outlier);
+                coords.push(coord::Coord::with_coordinates(canvas_x, canvas_y));
+            }
+        }
+        coords
+    }
+
+    /// Draw a patch with the color of the boxes within `sample_frame`, outlined with the line
+    /// color
+    pub(crate) fn draw_legend_sample(
+        &self,
+        cr: &Context,
+        sample_frame: &shape::Rectangle,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        chart::draw_patch_sample(cr, sample_frame, self.color.as_srgba());
+
+        let frame = chart::patch_frame(sample_frame);
+        let line_color = self.line_color.as_srgba();
+        cr.set_source_rgba(
+            f64::from(line_color.red),
+            f64::from(line_color.green),
+            f64::from(line_color.blue),
+            f64::from(line_color.alpha),
+        );
+        cr.set_line_width(self.line_width * fig_rel_width);
+        cr.move_to(frame.left(), frame.bottom());
+        cr.line_to(frame.right(), frame.bottom());
+        cr.move_to(frame.left(), frame.top());
+        cr.line_to(frame.right(), frame.top());
+        cr.stroke();
+        cr.set_line_width(self.line_width * fig_rel_height);
+        cr.move_to(frame.left(), frame.bottom());
+        cr.line_to(frame.left(), frame.top());
+        cr.move_to(frame.right(), frame.bottom());
+        cr.line_to(frame.right(), frame.top());
+        cr.stroke();
+    }
+}
+
+impl utils::Drawable for BoxPlot {
+    fn set_color_internal(&mut self, color: Srgba) {
+        self.color.set_color(color);
+        self.is_color_updated = true;
+    }
+
+    fn is_color_updated(&self) -> bool {
+        self.is_color_updated
+    }
+
+    fn scale_size(&mut self, factor: f64) {
+        self.line_width *= factor;
+        self.outlier_size *= factor;
+    }
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+        let scale_factor = self.global_frame.diag_len();
+        self.scale_size(scale_factor);
+    }
+
+    fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        let box_color = self.color.as_srgba();
+        let line_color = self.line_color.as_srgba();
+        for (k, stats) in self.stats.iter().enumerate() {
+            if stats.median.is_nan() {
+                continue;
+            }
+            let position = k as f64;
+
+            // Box
+            let outline = self.box_outline(position, stats);
+            cr.set_source_rgba(
+                f64::from(box_color.red),
+                f64::from(box_color.green),
+                f64::from(box_color.blue),
+                f64::from(box_color.alpha),
+            );
+            for (i, &(outline_position, outline_value)) in outline.iter().enumerate() {
+                let (canvas_x, canvas_y) = self.canvas_coord(outline_position, outline_value);
+                if i == 0 {
+                    cr.move_to(canvas_x, canvas_y);
+                } else {
+                    cr.line_to(canvas_x, canvas_y);
+                }
+            }
+            cr.close_path();
+            cr.fill();
+
+            cr.set_source_rgba(
+                f64::from(line_color.red),
+                f64::from(line_color.green),
+                f64::from(line_color.blue),
+                f64::from(line_color.alpha),
+            );
+            for (i, &start) in outline.iter().enumerate() {
+                let end = outline[(i + 1) % outline.len()];
+                self.stroke_line(cr, start, end, fig_rel_height, fig_rel_width);
+            }
+
+            // Median
+            let median_half_width = if self.is_notched {
+                self.box_width / 4.0
+            } else {
+                self.box_width / 2.0
+            };
+            self.stroke_line(
+                cr,
+                (position - median_half_width, stats.median),
+                (position + median_half_width, stats.median),
+                fig_rel_height,
+                fig_rel_width,
+            );
+
+            // Whiskers with caps
+            let cap_half_width = self.box_width / 4.0;
+            for &(box_end, whisker_end) in &[
+                (stats.lower_quartile, stats.lower_whisker),
+                (stats.upper_quartile, stats.upper_whisker),
+            ] {
+                self.stroke_line(
+                    cr,
+                    (position, box_end),
+                    (position, whisker_end),
+                    fig_rel_height,
+                    fig_rel_width,
+                );
+                self.stroke_line(
+                    cr,
+                    (position - cap_half_width, whisker_end),
+                    (position + cap_half_width, whisker_end),
+                    fig_rel_height,
+                    fig_rel_width,
+                );
+            }
+
+            // Outliers
+            for &outlier in &stats.outliers {
+                let (canvas_x, canvas_y) = self.canvas_coord(position, outlier);
+                let mut outlier_point = chart::point::Point::new(canvas_x, canvas_y);
+                outlier_point.set_shape(self.outlier_shape.clone());
+                outlier_point.set_size(self.outlier_size);
+                outlier_point.set_color_internal(box_color);
+                outlier_point.draw(cr, fig_rel_height, fig_rel_width);
+            }
+        }
+    }
+}
+
+impl utils::Plottable for BoxPlot {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
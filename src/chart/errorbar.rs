@@ -0,0 +1,444 @@
+//! Module that defines the ErrorBar struct
+//!
+
+use std::f64;
+
+use cairo::Context;
+use ndarray::AsArray;
+use palette::Srgba;
+
+use {chart, color, coord, scale, shape, utils};
+
+/// Error bars
+///
+/// Displays the uncertainty of data points as bars extending from each point, with a short cap at
+/// each end. Errors can be given for the horizontal and/or the vertical coordinate, and be either
+/// symmetric or asymmetric.
+///
+/// Error bars can be drawn as a chart on their own, or be attached to a `Scatter` or a `Line`
+/// chart, in which case they get the color of that chart unless another color is set.
+#[derive(Clone, Debug)]
+pub struct ErrorBar {
+    x_coords: Vec<f64>,
+    y_coords: Vec<f64>,
+    x_errors: Option<(Vec<f64>, Vec<f64>)>,
+    y_errors: Option<(Vec<f64>, Vec<f64>)>,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    color: color::Color,
+    is_color_updated: bool,
+    line_width: f64,
+    cap_width: f64,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
+}
+
+impl ErrorBar {
+    /// Create and return new error bars centered at the given coordinates. No bars are drawn
+    /// before horizontal or vertical errors are set.
+    pub fn new<'a, I: AsArray<'a, f64>>(x_data_coords: I, y_data_coords: I) -> ErrorBar {
+        let x_coords: Vec<f64> = x_data_coords.into().iter().cloned().collect();
+        let y_coords: Vec<f64> = y_data_coords.into().iter().cloned().collect();
+        let mut error_bar = ErrorBar {
+            x_coords: x_coords,
+            y_coords: y_coords,
+            x_errors: None,
+            y_errors: None,
+            data_frame: shape::Rectangle::new(),
+            global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            color: color::Color::with_custom(&color::CustomColor::Blue),
+            is_color_updated: false,
+            line_width: 0.002,
+            cap_width: 0.01,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
+        };
+        error_bar.update_data_frame();
+        error_bar
+    }
+
+    /// Set symmetric errors of the horizontal coordinates. Each bar extends `error` to both sides
+    /// of its point.
+    pub fn set_x_errors<'a, I: AsArray<'a, f64>>(&mut self, errors: I) -> &mut Self {
+        let errors: Vec<f64> = errors.into().iter().map(|err| err.abs()).collect();
+        self.x_errors = Some((errors.clone(), errors));
+        self.update_data_frame();
+        self
+    }
+
+    /// Set asymmetric errors of the horizontal coordinates. Each bar extends `lower` to the left
+    /// and `upper` to the right of its point.
+    pub fn set_x_errors_asymmetric<'a, I: AsArray<'a, f64>>(
+        &mut self,
+        lower_errors: I,
+        upper_errors: I,
+    ) -> &mut Self {
+        let lower_errors = lower_errors.into().iter().map(|err| err.abs()).collect();
+        let upper_errors = upper_errors.into().iter().map(|err| err.abs()).collect();
+        self.x_errors = Some((lower_errors, upper_errors));
+        self.update_data_frame();
+        self
+    }
+
+    /// Set symmetric errors of the vertical coordinates. Each bar extends `error` above and below
+    /// its point.
+    pub fn set_y_errors<'a, I: AsArray<'a, f64>>(&mut self, errors: I) -> &mut Self {
+        let errors: Vec<f64> = errors.into().iter().map(|err| err.abs()).collect();
+        self.y_errors = Some((errors.clone(), errors));
+        self.update_data_frame();
+        self
+    }
+
+    /// Set asymmetric errors of the vertical coordinates. Each bar extends `lower` below and
+    /// `upper` above its point.
+    pub fn set_y_errors_asymmetric<'a, I: AsArray<'a, f64>>(
+        &mut self,
+        lower_errors: I,
+        upper_errors: I,
+    ) -> &mut Self {
+        let lower_errors = lower_errors.into().iter().map(|err| err.abs()).collect();
+        let upper_errors = upper_errors.into().iter().map(|err| err.abs()).collect();
+        self.y_errors = Some((lower_errors, upper_errors));
+        self.update_data_frame();
+        self
+    }
+
+    /// Set the error bar color
+    pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.color.set_color_custom(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the error bar color
+    pub fn set_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        self.color.set_color_rgb(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the error bar color
+    pub fn set_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        self.color.set_color_rgba(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the error bar color
+    pub fn set_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        self.color.set_color_rgb_u8(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the error bar color
+    pub fn set_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        self.color.set_color_rgba_u8(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the error bar color
+    pub fn set_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.color.set_color_html(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the line width of the bars and caps
+    pub fn set_line_width(&mut self, val: f64) -> &mut Self {
+        self.line_width = val;
+        self
+    }
+
+    /// Set the width of the caps at the end of the bars. A width of zero draws no caps.
+    pub fn set_cap_width(&mut self, val: f64) -> &mut Self {
+        self.cap_width = val;
+        self
+    }
+
+    /// Return the (lower, upper) extent of every bar along one dimension, or None where the
+    /// coordinate or the errors are not finite.
+    fn extents(coords: &[f64], errors: &Option<(Vec<f64>, Vec<f64>)>) -> Vec<Option<(f64, f64)>> {
+        match *errors {
+            Some((ref lower_errors, ref upper_errors)) => coords
+                .iter()
+                .zip(lower_errors.iter().zip(upper_errors.iter()))
+                .map(|(&coord, (&lower, &upper))| {
+                    if coord.is_finite() && lower.is_finite() && upper.is_finite() {
+                        Some((coord - lower, coord + upper))
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Compute the data frame, which includes both the points and the full extent of the bars
+    fn update_data_frame(&mut self) {
+        let mut x_min = f64::INFINITY;
+        let mut x_max = f64::NEG_INFINITY;
+        let mut y_min = f64::INFINITY;
+        let mut y_max = f64::NEG_INFINITY;
+        for (&x, &y) in self.x_coords.iter().zip(self.y_coords.iter()) {
+            if x.is_finite() && y.is_finite() {
+                x_min = x_min.min(x);
+                x_max = x_max.max(x);
+                y_min = y_min.min(y);
+                y_max = y_max.max(y);
+            }
+        }
+        for &(lower, upper) in ErrorBar::extents(&self.x_coords, &self.x_errors)
+            .iter()
+            .flat_map(|extent| extent.iter())
+        {
+            x_min = x_min.min(lower);
+            x_max = x_max.max(upper);
+        }
+        for &(lower, upper) in ErrorBar::extents(&self.y_coords, &self.y_errors)
+            .iter()
+            .flat_map(|extent| extent.iter())
+        {
+            y_min = y_min.min(lower);
+            y_max = y_max.max(upper);
+        }
+        self.data_frame = shape::Rectangle::with_boundaries(x_min, x_max, y_min, y_max);
+    }
+
+    /// Map a data coordinate to the canvas
+    fn canvas_coord(&self, x_coord: f64, y_coord: f64) -> (f64, f64) {
+        let canvas_x = self.x_scale.map_range(
+            x_coord,
+            self.data_frame.left(),
+            self.data_frame.right(),
+            self.global_frame.left(),
+            self.global_frame.right(),
+        );
+        let canvas_y = self.y_scale.map_range(
+            y_coord,
+            self.data_frame.bottom(),
+            self.data_frame.top(),
+            self.global_frame.bottom(),
+            self.global_frame.top(),
+        );
+        (canvas_x, canvas_y)
+    }
+
+    /// Stroke a straight line between two canvas coordinates
+    fn stroke_line(&self, cr: &Context, start: (f64, f64), end: (f64, f64), line_width: f64) {
+        cr.set_line_width(line_width);
+        cr.move_to(start.0, start.1);
+        cr.line_to(end.0, end.1);
+        cr.stroke();
+    }
+
+    /// Whether or not to clip the error bars at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the error bars are clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the error bars are drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the error bars are drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the error bars in the legend of the plot. Error bars without a label are
+    /// not listed in the legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the error bars in the legend, if they have one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+    }
+
+    /// Return locations along the error bars, relative to the figure, such that the legend can
+    /// avoid them
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        let mut coords = Vec::<coord::Coord>::new();
+        let x_extents = ErrorBar::extents(&self.x_coords, &self.x_errors);
+        for (extent, &y) in x_extents.iter().zip(self.y_coords.iter()) {
+            if let Some((lower, upper)) = *extent {
+                let (start_x, start_y) = self.canvas_coord(lower, y);
+                let (end_x, end_y) = self.canvas_coord(upper, y);
+                coords.extend(chart::area_coords(start_x, end_x, start_y, end_y));
+            }
+        }
+        let y_extents = ErrorBar::extents(&self.y_coords, &self.y_errors);
+        for (extent, &x) in y_extents.iter().zip(self.x_coords.iter()) {
+            if let Some((lower, upper)) = *extent {
+                let (start_x, start_y) = self.canvas_coord(x, lower);
+                let (end_x, end_y) = self.canvas_coord(x, upper);
+                coords.extend(chart::area_coords(start_x, end_x, start_y, end_y));
+            }
+        }
+        coords
+    }
+
+    /// Draw a horizontal error bar with caps across the middle of `sample_frame`, with the color
+    /// and the line width of the error bars
+    pub(crate) fn draw_legend_sample(
+        &self,
+        cr: &Context,
+        sample_frame: &shape::Rectangle,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        let bar_color = self.color.as_srgba();
+        cr.set_source_rgba(
+            f64::from(bar_color.red),
+            f64::from(bar_color.green),
+            f64::from(bar_color.blue),
+            f64::from(bar_color.alpha),
+        );
+        let cap_half_height =
+            (self.cap_width / (2.0 * fig_rel_height)).min(0.5 * sample_frame.height());
+        let middle = 0.5 * (sample_frame.bottom() + sample_frame.top());
+        let start = (sample_frame.left(), middle);
+        let end = (sample_frame.right(), middle);
+        self.stroke_line(cr, start, end, self.line_width * fig_rel_width);
+        for &(cap_x, cap_y) in &[start, end] {
+            self.stroke_line(
+                cr,
+                (cap_x, cap_y - cap_half_height),
+                (cap_x, cap_y + cap_half_height),
+                self.line_width * fig_rel_height,
+            );
+        }
+    }
+}
+
+impl utils::Drawable for ErrorBar {
+    fn set_color_internal(&mut self, color: Srgba) {
+        self.color.set_color(color);
+        self.is_color_updated = true;
+    }
+
+    fn is_color_updated(&self) -> bool {
+        self.is_color_updated
+    }
+
+    fn scale_size(&mut self, factor: f64) {
+        self.line_width *= factor;
+        self.cap_width *= factor;
+    }
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+        let scale_factor = self.global_frame.diag_len();
+        self.scale_size(scale_factor);
+    }
+
+    fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        let bar_color = self.color.as_srgba();
+        cr.set_source_rgba(
+            f64::from(bar_color.red),
+            f64::from(bar_color.green),
+            f64::from(bar_color.blue),
+            f64::from(bar_color.alpha),
+        );
+        // The caps have the same length in both directions, regardless of the figure shape
+        let cap_half_width = self.cap_width / (2.0 * fig_rel_width);
+        let cap_half_height = self.cap_width / (2.0 * fig_rel_height);
+
+        let x_extents = ErrorBar::extents(&self.x_coords, &self.x_errors);
+        for (extent, &y) in x_extents.iter().zip(self.y_coords.iter()) {
+            if let Some((lower, upper)) = *extent {
+                if !y.is_finite() {
+                    continue;
+                }
+                let start = self.canvas_coord(lower, y);
+                let end = self.canvas_coord(upper, y);
+                self.stroke_line(cr, start, end, self.line_width * fig_rel_width);
+                for &(cap_x, cap_y) in &[start, end] {
+                    self.stroke_line(
+                        cr,
+                        (cap_x, cap_y - cap_half_height),
+                        (cap_x, cap_y + cap_half_height),
+                        self.line_width * fig_rel_height,
+                    );
+                }
+            }
+        }
+
+        let y_extents = ErrorBar::extents(&self.y_coords, &self.y_errors);
+        for (extent, &x) in y_extents.iter().zip(self.x_coords.iter()) {
+            if let Some((lower, upper)) = *extent {
+                if !x.is_finite() {
+                    continue;
+                }
+                let start = self.canvas_coord(x, lower);
+                let end = self.canvas_coord(x, upper);
+                self.stroke_line(cr, start, end, self.line_width * fig_rel_height);
+                for &(cap_x, cap_y) in &[start, end] {
+                    self.stroke_line(
+                        cr,
+                        (cap_x - cap_half_width, cap_y),
+                        (cap_x + cap_half_width, cap_y),
+                        self.line_width * fig_rel_width,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl utils::Plottable for ErrorBar {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
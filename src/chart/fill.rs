@@ -0,0 +1,357 @@
+//! Module that defines the FillBetween struct
+//!
+
+use std::f64;
+
+use cairo::Context;
+use ndarray::AsArray;
+use palette::Srgba;
+
+use {chart, color, coord, scale, shape, utils};
+
+/// Filled area chart
+///
+/// Fills the region between a lower and an upper curve, which share the same horizontal
+/// coordinates. This is typically used to display confidence bands around a line. The curves can
+/// be stepped with the same line styles as the `Line` chart, such that a band lines up with a stair
+/// line drawn from the same coordinates.
+///
+/// The region is filled with a translucent color. The opacity is multiplied with the alpha of the
+/// color, such that the band does not hide what is drawn below it.
+#[derive(Clone, Debug)]
+pub struct FillBetween {
+    x_coords: Vec<f64>,
+    y_lower_coords: Vec<f64>,
+    y_upper_coords: Vec<f64>,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    color: color::Color,
+    is_color_updated: bool,
+    opacity: f64,
+    line_style: chart::LineStyle,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
+}
+
+impl FillBetween {
+    /// Create and return a new FillBetween chart. Coordinates where either of the inputs is NaN
+    /// are ignored.
+    pub fn new<'a, I: AsArray<'a, f64>>(
+        x_data_coords: I,
+        y_lower_data_coords: I,
+        y_upper_data_coords: I,
+    ) -> FillBetween {
+        let x_view = x_data_coords.into();
+        let y_lower_view = y_lower_data_coords.into();
+        let y_upper_view = y_upper_data_coords.into();
+
+        let mut x_coords = Vec::<f64>::new();
+        let mut y_lower_coords = Vec::<f64>::new();
+        let mut y_upper_coords = Vec::<f64>::new();
+        let coords = x_view
+            .iter()
+            .zip(y_lower_view.iter())
+            .zip(y_upper_view.iter());
+        for ((x, y_lower), y_upper) in coords {
+            if x.is_nan() || y_lower.is_nan() || y_upper.is_nan() {
+                continue;
+            }
+            x_coords.push(*x);
+            y_lower_coords.push(*y_lower);
+            y_upper_coords.push(*y_upper);
+        }
+
+        let x_data_min = x_coords.iter().cloned().fold(f64::INFINITY, f64::min);
+        let x_data_max = x_coords.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let y_data_min = y_lower_coords
+            .iter()
+            .chain(y_upper_coords.iter())
+            .cloned()
+            .fold(f64::INFINITY, f64::min);
+        let y_data_max = y_lower_coords
+            .iter()
+            .chain(y_upper_coords.iter())
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        FillBetween {
+            x_coords: x_coords,
+            y_lower_coords: y_lower_coords,
+            y_upper_coords: y_upper_coords,
+            data_frame: shape::Rectangle::with_boundaries(
+                x_data_min,
+                x_data_max,
+                y_data_min,
+                y_data_max,
+            ),
+            global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            color: color::Color::with_custom(&color::CustomColor::Blue),
+            is_color_updated: false,
+            opacity: 0.3,
+            line_style: chart::LineStyle::Plain,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
+        }
+    }
+
+    /// Set the fill color
+    pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.color.set_color_custom(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the fill color
+    pub fn set_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        self.color.set_color_rgb(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the fill color
+    pub fn set_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        self.color.set_color_rgba(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the fill color
+    pub fn set_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        self.color.set_color_rgb_u8(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the fill color
+    pub fn set_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        self.color.set_color_rgba_u8(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the fill color
+    pub fn set_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.color.set_color_html(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the opacity of the fill, in `[0.0, 1.0]`. The default is 0.3.
+    pub fn set_opacity(&mut self, val: f64) -> &mut Self {
+        self.opacity = val.max(0.0).min(1.0);
+        self
+    }
+
+    /// Set the style of the curves bounding the region. Plain, left stair, or right stair. See the
+    /// `Line` chart for how the stairs are drawn.
+    pub fn set_line_style(&mut self, style: chart::LineStyle) -> &mut Self {
+        self.line_style = style;
+        self
+    }
+
+    /// Return the vertices of a curve with the current line style, in data coordinates.
+    fn curve_vertices(&self, y_coords: &[f64]) -> Vec<(f64, f64)> {
+        let mut vertices = Vec::<(f64, f64)>::new();
+        for (k, (&x, &y)) in self.x_coords.iter().zip(y_coords.iter()).enumerate() {
+            if k > 0 {
+                // The "angle point" of the stair
+                match self.line_style {
+                    chart::LineStyle::Plain => {}
+                    chart::LineStyle::LeftStair => vertices.push((self.x_coords[k - 1], y)),
+                    chart::LineStyle::RightStair => vertices.push((x, y_coords[k - 1])),
+                }
+            }
+            vertices.push((x, y));
+        }
+        vertices
+    }
+
+    /// Whether or not to clip the filled area at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the filled area is clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the filled area is drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the filled area is drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the filled area in the legend of the plot. Areas without a label are not
+    /// listed in the legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the filled area in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+    }
+
+    /// Map a vertex in data coordinates to the canvas
+    fn canvas_coord(&self, x_coord: f64, y_coord: f64) -> (f64, f64) {
+        let canvas_x = self.x_scale.map_range(
+            x_coord,
+            self.data_frame.left(),
+            self.data_frame.right(),
+            self.global_frame.left(),
+            self.global_frame.right(),
+        );
+        let canvas_y = self.y_scale.map_range(
+            y_coord,
+            self.data_frame.bottom(),
+            self.data_frame.top(),
+            self.global_frame.bottom(),
+            self.global_frame.top(),
+        );
+        (canvas_x, canvas_y)
+    }
+
+    /// Return locations spread over the filled area, relative to the figure, such that the legend
+    /// can avoid them. The upper and lower curves share their horizontal coordinates, so the area
+    /// is covered by columns between them.
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        let upper_vertices: Vec<(f64, f64)> = self.curve_vertices(&self.y_upper_coords)
+            .iter()
+            .map(|&(x, y)| self.canvas_coord(x, y))
+            .collect();
+        let lower_vertices: Vec<(f64, f64)> = self.curve_vertices(&self.y_lower_coords)
+            .iter()
+            .map(|&(x, y)| self.canvas_coord(x, y))
+            .collect();
+        let mut coords = Vec::<coord::Coord>::new();
+        for k in 1..upper_vertices.len() {
+            let (x_start, upper_start) = upper_vertices[k - 1];
+            let (x_end, upper_end) = upper_vertices[k];
+            let lower_start = lower_vertices[k - 1].1;
+            let lower_end = lower_vertices[k].1;
+            let num_steps = ((x_end - x_start).abs() / chart::LEGEND_AREA_STEP)
+                .ceil()
+                .max(1.0) as usize;
+            for i in 0..num_steps {
+                let t = i as f64 / num_steps as f64;
+                let x = x_start + t * (x_end - x_start);
+                let lower = lower_start + t * (lower_end - lower_start);
+                let upper = upper_start + t * (upper_end - upper_start);
+                coords.extend(chart::area_coords(x, x, lower, upper));
+            }
+        }
+        if let (Some(&(x, upper)), Some(&(_, lower))) =
+            (upper_vertices.last(), lower_vertices.last())
+        {
+            coords.extend(chart::area_coords(x, x, lower, upper));
+        }
+        coords
+    }
+
+    /// Draw a patch with the color and the opacity of the filled area, within `sample_frame`
+    pub(crate) fn draw_legend_sample(&self, cr: &Context, sample_frame: &shape::Rectangle) {
+        let mut fill_color = self.color.as_srgba();
+        fill_color.alpha *= self.opacity as f32;
+        chart::draw_patch_sample(cr, sample_frame, fill_color);
+    }
+}
+
+impl utils::Drawable for FillBetween {
+    fn set_color_internal(&mut self, color: Srgba) {
+        self.color.set_color(color);
+        self.is_color_updated = true;
+    }
+
+    fn is_color_updated(&self) -> bool {
+        self.is_color_updated
+    }
+
+    fn scale_size(&mut self, _: f64) {}
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+    }
+
+    fn draw(&self, cr: &Context, _: f64, _: f64) {
+        if self.x_coords.is_empty() {
+            return;
+        }
+
+        // The region is bounded by the upper curve from left to right, and the lower curve back
+        // from right to left.
+        let mut vertices = self.curve_vertices(&self.y_upper_coords);
+        let mut lower_vertices = self.curve_vertices(&self.y_lower_coords);
+        lower_vertices.reverse();
+        vertices.append(&mut lower_vertices);
+
+        let fill_color = self.color.as_srgba();
+        cr.set_source_rgba(
+            f64::from(fill_color.red),
+            f64::from(fill_color.green),
+            f64::from(fill_color.blue),
+            f64::from(fill_color.alpha) * self.opacity,
+        );
+        for (k, &(x, y)) in vertices.iter().enumerate() {
+            let (canvas_x, canvas_y) = self.canvas_coord(x, y);
+            if k == 0 {
+                cr.move_to(canvas_x, canvas_y);
+            } else {
+                cr.line_to(canvas_x, canvas_y);
+            }
+        }
+        cr.close_path();
+        cr.fill();
+    }
+}
+
+impl utils::Plottable for FillBetween {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
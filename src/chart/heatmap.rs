@@ -0,0 +1,380 @@
+//! Module that defines the Heatmap struct
+//!
+
+use std::f64;
+
+use cairo::Context;
+use ndarray::{Array2, AsArray, Ix2};
+use palette::Srgba;
+
+use {chart, color, label, scale, shape, utils};
+use scale::AxisTransform;
+
+/// Number of bands that the colormap gradient of the legend sample is drawn with
+const NUM_LEGEND_BANDS: usize = 16;
+
+/// Heatmap chart
+///
+/// Displays a matrix as a grid of discrete cells, e.g. a confusion matrix or a correlation table.
+/// Each cell is colored by its value through a colormap, and can optionally be annotated with the
+/// value itself.
+///
+/// The cell in row `i` and column `j` is centered at `(j, rows - 1 - i)` in data coordinates, such
+/// that the first row is at the top, as when the matrix is written out. The default axes are
+/// labeled with the row and column labels, or with the row and column indices if no labels are
+/// set.
+#[derive(Clone, Debug)]
+pub struct Heatmap {
+    values: Array2<f64>,
+    colormap: color::Colormap,
+    value_min: Option<f64>,
+    value_max: Option<f64>,
+    value_scale: scale::Scale,
+    row_labels: Vec<String>,
+    column_labels: Vec<String>,
+    display_annotations: bool,
+    annotation_precision: usize,
+    annotation_font_size: f64,
+    annotations: Vec<label::Label>,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
+}
+
+impl Heatmap {
+    /// Create and return a new Heatmap chart from a `(rows, cols)` matrix. The values are mapped to
+    /// colors with a colormap, which by default is `Colormap::Viridis`.
+    pub fn new<'a, I: AsArray<'a, f64, Ix2>>(data: I) -> Heatmap {
+        let values = data.into().to_owned();
+        let (num_rows, num_cols) = values.dim();
+        Heatmap {
+            values: values,
+            colormap: color::Colormap::Viridis,
+            value_min: None,
+            value_max: None,
+            value_scale: scale::Scale::Linear,
+            row_labels: Vec::<String>::new(),
+            column_labels: Vec::<String>::new(),
+            display_annotations: false,
+            annotation_precision: 2,
+            annotation_font_size: 0.025,
+            annotations: Vec::<label::Label>::new(),
+            data_frame: shape::Rectangle::with_boundaries(
+                -0.5,
+                num_cols as f64 - 0.5,
+                -0.5,
+                num_rows as f64 - 0.5,
+            ),
+            global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
+        }
+    }
+
+    /// Set the colormap used to color the cells
+    pub fn set_colormap(&mut self, colormap: color::Colormap) -> &mut Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Set the values mapped to the lower and upper end of the colormap. Values outside of this
+    /// range are clamped. By default, the smallest and largest values of the matrix are used.
+    pub fn set_value_range(&mut self, min: f64, max: f64) -> &mut Self {
+        self.value_min = Some(min);
+        self.value_max = Some(max);
+        self
+    }
+
+    /// Set the scale that values are mapped to the colormap with, e.g. `Scale::Log10` for a
+    /// logarithmic color normalization. Values outside of the domain of the scale are not drawn.
+    /// Default is `Scale::Linear`.
+    pub fn set_value_scale(&mut self, scale: scale::Scale) -> &mut Self {
+        self.value_scale = scale;
+        self
+    }
+
+    /// Set the category labels of the rows, from top to bottom. These are displayed on the
+    /// vertical axis.
+    pub fn set_row_labels(&mut self, labels: &[&str]) -> &mut Self {
+        self.row_labels = labels.iter().map(|label| String::from(*label)).collect();
+        self
+    }
+
+    /// Set the category labels of the columns, from left to right. These are displayed on the
+    /// horizontal axis.
+    pub fn set_column_labels(&mut self, labels: &[&str]) -> &mut Self {
+        self.column_labels = labels.iter().map(|label| String::from(*label)).collect();
+        self
+    }
+
+    /// Whether or not to write the value of each cell in the cell
+    pub fn display_annotations(&mut self, val: bool) -> &mut Self {
+        self.display_annotations = val;
+        self
+    }
+
+    /// Set the number of decimals in the cell annotations
+    pub fn set_annotation_precision(&mut self, val: usize) -> &mut Self {
+        self.annotation_precision = val;
+        self
+    }
+
+    /// Set the font size of the cell annotations
+    pub fn set_annotation_font_size(&mut self, val: f64) -> &mut Self {
+        self.annotation_font_size = val;
+        self
+    }
+
+    /// Return the category marks of the horizontal axis, as `(data location, label)` pairs. Columns
+    /// without a label are labeled by their index.
+    pub(crate) fn x_category_marks(&self) -> Vec<(f64, String)> {
+        (0..self.values.cols())
+            .map(|col| match self.column_labels.get(col) {
+                Some(label) => (col as f64, label.clone()),
+                None => (col as f64, col.to_string()),
+            })
+            .collect()
+    }
+
+    /// Return the category marks of the vertical axis, as `(data location, label)` pairs. Rows
+    /// without a label are labeled by their index.
+    pub(crate) fn y_category_marks(&self) -> Vec<(f64, String)> {
+        let num_rows = self.values.rows();
+        (0..num_rows)
+            .map(|row| match self.row_labels.get(row) {
+                Some(label) => ((num_rows - 1 - row) as f64, label.clone()),
+                None => ((num_rows - 1 - row) as f64, row.to_string()),
+            })
+            .collect()
+    }
+
+    /// Return the range of values mapped to the colormap
+    fn value_range(&self) -> (f64, f64) {
+        let finite_values = self.values
+            .iter()
+            .cloned()
+            .filter(|val| val.is_finite() && self.value_scale.in_domain(*val));
+        let data_min = finite_values.clone().fold(f64::INFINITY, f64::min);
+        let data_max = finite_values.fold(f64::NEG_INFINITY, f64::max);
+        (
+            self.value_min.unwrap_or(data_min),
+            self.value_max.unwrap_or(data_max),
+        )
+    }
+
+    /// Return the colormap, the range of values that is mapped to it, and the scale of the mapping
+    pub(crate) fn color_mapping(&self) -> Option<(color::Colormap, (f64, f64), scale::Scale)> {
+        Some((self.colormap.clone(), self.value_range(), self.value_scale.clone()))
+    }
+
+    /// Return the color of a cell, or None if the value can not be mapped to the colormap
+    fn cell_color(&self, val: f64, value_range: (f64, f64)) -> Option<Srgba> {
+        if !(val.is_finite() && self.value_scale.in_domain(val)) {
+            return None;
+        }
+        let (value_min, value_max) = value_range;
+        let normalized_val = if value_max > value_min {
+            self.value_scale.map_range(val, value_min, value_max, 0.0, 1.0)
+        } else {
+            0.5
+        };
+        Some(self.colormap.as_srgba(normalized_val))
+    }
+
+    /// Map a data coordinate to the canvas
+    fn canvas_coord(&self, x_coord: f64, y_coord: f64) -> (f64, f64) {
+        let canvas_x = self.x_scale.map_range(
+            x_coord,
+            self.data_frame.left(),
+            self.data_frame.right(),
+            self.global_frame.left(),
+            self.global_frame.right(),
+        );
+        let canvas_y = self.y_scale.map_range(
+            y_coord,
+            self.data_frame.bottom(),
+            self.data_frame.top(),
+            self.global_frame.bottom(),
+            self.global_frame.top(),
+        );
+        (canvas_x, canvas_y)
+    }
+
+    /// Whether or not to clip the heatmap cells at the canvas boundary. By default, nothing is
+    /// drawn outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the heatmap cells are clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the heatmap cells are drawn against. See the AxisPair enum for
+    /// reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the heatmap cells are drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the heatmap in the legend of the plot. Heatmaps without a label are not
+    /// listed in the legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the heatmap in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+    }
+
+    /// Draw the colormap as a horizontal gradient within `sample_frame`
+    pub(crate) fn draw_legend_sample(&self, cr: &Context, sample_frame: &shape::Rectangle) {
+        let frame = chart::patch_frame(sample_frame);
+        let band_len = 1.0 / NUM_LEGEND_BANDS as f64;
+        for band in 0..NUM_LEGEND_BANDS {
+            let band_start = band as f64 * band_len;
+            let color = self.colormap.as_srgba(band_start + 0.5 * band_len);
+            cr.set_source_rgba(
+                f64::from(color.red),
+                f64::from(color.green),
+                f64::from(color.blue),
+                f64::from(color.alpha),
+            );
+            cr.rectangle(
+                frame.left() + band_start * frame.width(),
+                frame.bottom(),
+                band_len * frame.width(),
+                frame.height(),
+            );
+            cr.fill();
+        }
+    }
+}
+
+impl utils::Drawable for Heatmap {
+    fn set_color_internal(&mut self, _: Srgba) {}
+
+    fn is_color_updated(&self) -> bool {
+        // Cell colors are given by the colormap
+        true
+    }
+
+    fn scale_size(&mut self, _: f64) {}
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+
+        self.annotations.clear();
+        if !self.display_annotations {
+            return;
+        }
+        let value_range = self.value_range();
+        let num_rows = self.values.rows();
+        for ((row, col), &val) in self.values.indexed_iter() {
+            let cell_color = match self.cell_color(val, value_range) {
+                Some(cell_color) => cell_color,
+                None => continue,
+            };
+            // The label centroid is relative to the canvas frame
+            let centroid_x = self.x_scale.map_range(
+                col as f64,
+                self.data_frame.left(),
+                self.data_frame.right(),
+                0.0,
+                1.0,
+            );
+            let centroid_y = self.y_scale.map_range(
+                (num_rows - 1 - row) as f64,
+                self.data_frame.bottom(),
+                self.data_frame.top(),
+                0.0,
+                1.0,
+            );
+            let mut annotation = label::Label::with_centroid(centroid_x, centroid_y);
+            annotation.set_content(&format!("{:.*}", self.annotation_precision, val));
+            annotation.set_font_size(self.annotation_font_size);
+            annotation.set_color_internal(color::contrast_color(cell_color));
+            annotation.fit(canvas_global_frame);
+            self.annotations.push(annotation);
+        }
+    }
+
+    fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        let value_range = self.value_range();
+        let num_rows = self.values.rows();
+        for ((row, col), &val) in self.values.indexed_iter() {
+            let cell_color = match self.cell_color(val, value_range) {
+                Some(cell_color) => cell_color,
+                None => continue,
+            };
+            let cell_y = (num_rows - 1 - row) as f64;
+            let (left, bottom) = self.canvas_coord(col as f64 - 0.5, cell_y - 0.5);
+            let (right, top) = self.canvas_coord(col as f64 + 0.5, cell_y + 0.5);
+            cr.set_source_rgba(
+                f64::from(cell_color.red),
+                f64::from(cell_color.green),
+                f64::from(cell_color.blue),
+                f64::from(cell_color.alpha),
+            );
+            cr.rectangle(left, bottom, right - left, top - bottom);
+            cr.fill();
+        }
+
+        for annotation in &self.annotations {
+            annotation.draw(cr, fig_rel_height, fig_rel_width);
+        }
+    }
+}
+
+impl utils::Plottable for Heatmap {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
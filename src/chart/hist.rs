@@ -0,0 +1,539 @@
+//! Module that defines the Histogram struct
+//!
+
+use std::f64;
+
+use cairo::Context;
+use ndarray::AsArray;
+use palette::Srgba;
+
+use {chart, color, coord, scale, shape, utils};
+
+/// The largest number of bins of a histogram, e.g. with a tiny bin width
+const MAX_NUM_BINS: usize = 10_000;
+
+/// Rule used to determine the bin edges of a histogram.
+///
+/// | Variant            | Bins                                                           |
+/// | ------------------ | -------------------------------------------------------------- |
+/// | `Count(n)`         | `n` bins of equal width, spanning the data range               |
+/// | `Width(w)`         | Bins of width `w`, aligned with integer multiples of `w`        |
+/// | `Sturges`          | `ceil(log2(n) + 1)` bins of equal width, for `n` samples        |
+/// | `FreedmanDiaconis` | Bins of width `2 IQR / n^(1/3)`, for `n` samples               |
+/// | `Edges(edges)`     | Explicit, finite and strictly increasing bin edges             |
+///
+/// There are at most 10 000 bins. A width giving more bins is widened to span the data range with
+/// that many bins, and a width that is not positive and finite falls back to `Sturges`. Fewer than
+/// two edges, or edges that are not finite and strictly increasing, fall back to a single bin.
+#[derive(Clone, Debug)]
+pub enum Bins {
+    Count(usize),
+    Width(f64),
+    Sturges,
+    FreedmanDiaconis,
+    Edges(Vec<f64>),
+}
+
+/// How the bins of a histogram are drawn. Either as filled bars, or as the outline of the bars.
+#[derive(Clone, Debug)]
+pub enum HistogramStyle {
+    Filled,
+    Step,
+}
+
+/// Histogram chart
+///
+/// Displays the distribution of a set of samples. The samples are counted in bins, which are
+/// determined by a binning rule, and the count of each bin is displayed as a bar spanning the
+/// bin. The counts can be normalized to a density, and accumulated.
+#[derive(Clone, Debug)]
+pub struct Histogram {
+    samples: Vec<f64>,
+    bins: Bins,
+    edges: Vec<f64>,
+    values: Vec<f64>,
+    is_density: bool,
+    is_cumulative: bool,
+    baseline: f64,
+    style: HistogramStyle,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    color: color::Color,
+    is_color_updated: bool,
+    line_width: f64,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
+}
+
+impl Histogram {
+    /// Create and return a new Histogram chart from raw samples. NaN and infinite values are
+    /// ignored.
+    pub fn new<'a, I: AsArray<'a, f64>>(samples: I) -> Histogram {
+        let mut sorted_samples: Vec<_> = samples
+            .into()
+            .iter()
+            .filter(|v| v.is_finite())
+            .filter_map(|v| utils::NonNan::new(*v))
+            .collect();
+        sorted_samples.sort();
+
+        let mut histogram = Histogram {
+            samples: sorted_samples.iter().map(|v| v.val()).collect(),
+            bins: Bins::Sturges,
+            edges: Vec::<f64>::new(),
+            values: Vec::<f64>::new(),
+            is_density: false,
+            is_cumulative: false,
+            baseline: 0.0,
+            style: HistogramStyle::Filled,
+            data_frame: shape::Rectangle::new(),
+            global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            color: color::Color::with_custom(&color::CustomColor::Blue),
+            is_color_updated: false,
+            line_width: 0.0035,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
+        };
+        histogram.update_bins();
+        histogram
+    }
+
+    /// Set the rule used to determine the bins. The default is `Bins::Sturges`.
+    pub fn set_bins(&mut self, bins: Bins) -> &mut Self {
+        self.bins = bins;
+        self.update_bins();
+        self
+    }
+
+    /// Whether or not to normalize the bin values such that the total area of the bars is one.
+    pub fn set_density(&mut self, val: bool) -> &mut Self {
+        self.is_density = val;
+        self.update_bins();
+        self
+    }
+
+    /// Whether or not to let each bin value be the sum of the values in all bins up to and
+    /// including itself.
+    pub fn set_cumulative(&mut self, val: bool) -> &mut Self {
+        self.is_cumulative = val;
+        self.update_bins();
+        self
+    }
+
+    /// Set the value the bins extend from. The default is 0, which is outside of a logarithmic
+    /// value axis, so bins on such an axis must extend from a positive baseline, e.g. 0.5 for
+    /// counts.
+    pub fn set_baseline(&mut self, val: f64) -> &mut Self {
+        self.baseline = val;
+        self.update_data_frame();
+        self
+    }
+
+    /// Set how the bins are drawn. Filled bars, or the outline of the bars.
+    pub fn set_style(&mut self, style: HistogramStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the line width used by the step style
+    pub fn set_line_width(&mut self, val: f64) -> &mut Self {
+        self.line_width = val;
+        self
+    }
+
+    /// Set the histogram color
+    pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.color.set_color_custom(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the histogram color
+    pub fn set_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        self.color.set_color_rgb(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the histogram color
+    pub fn set_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        self.color.set_color_rgba(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the histogram color
+    pub fn set_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        self.color.set_color_rgb_u8(red, green, blue);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the histogram color
+    pub fn set_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        self.color.set_color_rgba_u8(red, green, blue, alpha);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Set the histogram color
+    pub fn set_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.color.set_color_html(color);
+        self.is_color_updated = true;
+        self
+    }
+
+    /// Return the bin edges. There is one more edge than there are bins.
+    pub fn edges(&self) -> Vec<f64> {
+        self.edges.clone()
+    }
+
+    /// Return the value of each bin, after normalization and accumulation.
+    pub fn values(&self) -> Vec<f64> {
+        self.values.clone()
+    }
+
+    /// Compute bin edges of equal width spanning the data range.
+    fn equal_width_edges(&self, num_bins: usize) -> Vec<f64> {
+        let num_bins = num_bins.max(1).min(MAX_NUM_BINS);
+        let (data_min, data_max) = self.sample_range();
+        let bin_width = (data_max - data_min) / num_bins as f64;
+        (0..num_bins + 1)
+            .map(|k| data_min + k as f64 * bin_width)
+            .collect()
+    }
+
+    /// Return the smallest and largest sample. If all samples are equal, or if there are no
+    /// samples, the range is widened to have unit length.
+    fn sample_range(&self) -> (f64, f64) {
+        match (self.samples.first(), self.samples.last()) {
+            (Some(&first), Some(&last)) if last > first => (first, last),
+            (Some(&first), Some(_)) => (first - 0.5, first + 0.5),
+            _ => (0.0, 1.0),
+        }
+    }
+
+    /// Compute the bin edges given by the binning rule.
+    fn compute_edges(&self) -> Vec<f64> {
+        let num_samples = self.samples.len().max(1) as f64;
+        match self.bins {
+            Bins::Count(num_bins) => self.equal_width_edges(num_bins),
+            Bins::Width(bin_width) if bin_width > 0.0 && bin_width.is_finite() => {
+                let (data_min, data_max) = self.sample_range();
+                let first_edge = (data_min / bin_width).floor() * bin_width;
+                let num_bins = ((data_max - first_edge) / bin_width).floor() + 1.0;
+                if num_bins > MAX_NUM_BINS as f64 {
+                    return self.equal_width_edges(MAX_NUM_BINS);
+                }
+                (0..num_bins as usize + 1)
+                    .map(|k| first_edge + k as f64 * bin_width)
+                    .collect()
+            }
+            Bins::Width(_) | Bins::Sturges => {
+                self.equal_width_edges((num_samples.log2() + 1.0).ceil() as usize)
+            }
+            Bins::FreedmanDiaconis => {
+                let iqr = utils::percentile(&self.samples, 75.0)
+                    - utils::percentile(&self.samples, 25.0);
+                let bin_width = 2.0 * iqr / num_samples.cbrt();
+                let (data_min, data_max) = self.sample_range();
+                if bin_width > 0.0 {
+                    let num_bins = ((data_max - data_min) / bin_width).ceil();
+                    self.equal_width_edges(num_bins.min(MAX_NUM_BINS as f64) as usize)
+                } else {
+                    self.equal_width_edges((num_samples.log2() + 1.0).ceil() as usize)
+                }
+            }
+            Bins::Edges(ref edges)
+                if edges.len() > 1 && edges.iter().all(|edge| edge.is_finite())
+                    && edges.windows(2).all(|pair| pair[0] < pair[1]) =>
+            {
+                edges.clone()
+            }
+            Bins::Edges(_) => self.equal_width_edges(1),
+        }
+    }
+
+    /// Recompute the bin edges, the bin values, and the data frame.
+    fn update_bins(&mut self) {
+        self.edges = self.compute_edges();
+        let num_bins = self.edges.len() - 1;
+        let first_edge = self.edges[0];
+        let last_edge = self.edges[num_bins];
+
+        // Every bin is half open, [left, right), except the last, which also includes its right
+        // edge. Samples outside of the edges are not counted. The samples are sorted, so the bin
+        // index only moves forward.
+        let mut counts = vec![0.0; num_bins];
+        let mut bin_index = 0;
+        for &sample in &self.samples {
+            if sample < first_edge || sample > last_edge {
+                continue;
+            }
+            while bin_index < num_bins - 1 && sample >= self.edges[bin_index + 1] {
+                bin_index += 1;
+            }
+            counts[bin_index] += 1.0;
+        }
+
+        let total_count: f64 = counts.iter().sum();
+        let mut values: Vec<f64> = if self.is_density && total_count > 0.0 {
+            counts
+                .iter()
+                .enumerate()
+                .map(|(i, count)| count / (total_count * (self.edges[i + 1] - self.edges[i])))
+                .collect()
+        } else {
+            counts
+        };
+
+        if self.is_cumulative {
+            let mut accumulated = 0.0;
+            for (i, value) in values.iter_mut().enumerate() {
+                // With density, the area of each bin is accumulated, such that the final value is 1.
+                accumulated += if self.is_density {
+                    *value * (self.edges[i + 1] - self.edges[i])
+                } else {
+                    *value
+                };
+                *value = accumulated;
+            }
+        }
+
+        self.values = values;
+        self.update_data_frame();
+    }
+
+    /// Update the data frame such that it encloses all bins that are not empty, and the baseline.
+    fn update_data_frame(&mut self) {
+        let (value_min, value_max) = self.values
+            .iter()
+            .filter(|value| **value > 0.0)
+            .fold((self.baseline, self.baseline), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+        self.data_frame = shape::Rectangle::with_boundaries(
+            self.edges[0],
+            self.edges[self.edges.len() - 1],
+            value_min,
+            value_max,
+        );
+    }
+
+    /// Map a data coordinate to the canvas
+    fn canvas_coord(&self, x_coord: f64, y_coord: f64) -> (f64, f64) {
+        let canvas_x = self.x_scale.map_range(
+            x_coord,
+            self.data_frame.left(),
+            self.data_frame.right(),
+            self.global_frame.left(),
+            self.global_frame.right(),
+        );
+        let canvas_y = self.y_scale.map_range(
+            y_coord,
+            self.data_frame.bottom(),
+            self.data_frame.top(),
+            self.global_frame.bottom(),
+            self.global_frame.top(),
+        );
+        (canvas_x, canvas_y)
+    }
+
+    /// Whether or not to clip the bins at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the bins are clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the bins are drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the bins are drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the histogram in the legend of the plot. Histograms without a label are
+    /// not listed in the legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the histogram in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+    }
+
+    /// Return locations spread over the bins that are not empty, relative to the figure, such that
+    /// the legend can avoid them
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        let mut coords = Vec::<coord::Coord>::new();
+        for (i, value) in self.values.iter().enumerate() {
+            if *value <= 0.0 {
+                continue;
+            }
+            let (left, bottom) = self.canvas_coord(self.edges[i], self.baseline);
+            let (right, top) = self.canvas_coord(self.edges[i + 1], *value);
+            coords.extend(chart::area_coords(left, right, bottom, top));
+        }
+        coords
+    }
+
+    /// Draw a patch with the color of the histogram within `sample_frame`, or the outline of a
+    /// single bin for the step style
+    pub(crate) fn draw_legend_sample(
+        &self,
+        cr: &Context,
+        sample_frame: &shape::Rectangle,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        let hist_color = self.color.as_srgba();
+        match self.style {
+            HistogramStyle::Filled => chart::draw_patch_sample(cr, sample_frame, hist_color),
+            HistogramStyle::Step => {
+                let frame = chart::patch_frame(sample_frame);
+                cr.set_source_rgba(
+                    f64::from(hist_color.red),
+                    f64::from(hist_color.green),
+                    f64::from(hist_color.blue),
+                    f64::from(hist_color.alpha),
+                );
+                cr.set_line_width(self.line_width * fig_rel_height);
+                cr.move_to(frame.left(), frame.bottom());
+                cr.line_to(frame.left(), frame.top());
+                cr.stroke();
+                cr.move_to(frame.right(), frame.top());
+                cr.line_to(frame.right(), frame.bottom());
+                cr.stroke();
+                cr.set_line_width(self.line_width * fig_rel_width);
+                cr.move_to(frame.left(), frame.top());
+                cr.line_to(frame.right(), frame.top());
+                cr.stroke();
+            }
+        }
+    }
+}
+
+impl utils::Drawable for Histogram {
+    fn set_color_internal(&mut self, color: Srgba) {
+        self.color.set_color(color);
+        self.is_color_updated = true;
+    }
+
+    fn is_color_updated(&self) -> bool {
+        self.is_color_updated
+    }
+
+    fn scale_size(&mut self, factor: f64) {
+        self.line_width *= factor;
+    }
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+        let scale_factor = self.global_frame.diag_len();
+        self.scale_size(scale_factor);
+    }
+
+    fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        let hist_color = self.color.as_srgba();
+        cr.set_source_rgba(
+            f64::from(hist_color.red),
+            f64::from(hist_color.green),
+            f64::from(hist_color.blue),
+            f64::from(hist_color.alpha),
+        );
+        match self.style {
+            // Empty bins are not drawn, as zero is outside of a logarithmic value axis
+            HistogramStyle::Filled => for (i, value) in self.values.iter().enumerate() {
+                if *value <= 0.0 {
+                    continue;
+                }
+                let (left, bottom) = self.canvas_coord(self.edges[i], self.baseline);
+                let (right, top) = self.canvas_coord(self.edges[i + 1], *value);
+                cr.rectangle(left, bottom, right - left, top - bottom);
+                cr.fill();
+            },
+            HistogramStyle::Step => {
+                // The outline goes from the baseline, along the top of every bin, and back to the
+                // baseline. Empty bins are outlined along the baseline.
+                let num_bins = self.values.len();
+                let mut outline = vec![self.canvas_coord(self.edges[0], self.baseline)];
+                for (i, value) in self.values.iter().enumerate() {
+                    let bin_top = if *value > 0.0 { *value } else { self.baseline };
+                    outline.push(self.canvas_coord(self.edges[i], bin_top));
+                    outline.push(self.canvas_coord(self.edges[i + 1], bin_top));
+                }
+                outline.push(self.canvas_coord(self.edges[num_bins], self.baseline));
+
+                for segment in outline.windows(2) {
+                    let (start_x, start_y) = segment[0];
+                    let (end_x, end_y) = segment[1];
+                    let is_vertical = (end_x - start_x).abs() < (end_y - start_y).abs();
+                    let line_width = if is_vertical {
+                        self.line_width * fig_rel_height
+                    } else {
+                        self.line_width * fig_rel_width
+                    };
+                    cr.set_line_width(line_width);
+                    cr.move_to(start_x, start_y);
+                    cr.line_to(end_x, end_y);
+                    cr.stroke();
+                }
+            }
+        }
+    }
+}
+
+impl utils::Plottable for Histogram {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
@@ -0,0 +1,404 @@
+//! Module that defines the Image struct
+//!
+
+use std::f64;
+
+use cairo::{Antialias, Context};
+use ndarray::{Array3, AsArray, Ix2, Ix3};
+use palette::Srgba;
+
+use {chart, color, scale, shape, utils};
+use scale::AxisTransform;
+
+/// Number of cells along each dimension that a bilinearly interpolated image is resampled to, if
+/// it is smaller.
+const BILINEAR_RESOLUTION: usize = 256;
+
+/// How the image is interpolated when it is drawn.
+///
+/// With `Nearest`, every pixel is drawn as a rectangle of uniform color. With `Bilinear`, the
+/// image is resampled to a finer grid, where the values are linearly interpolated between the
+/// pixel centres.
+#[derive(Clone, Debug)]
+pub enum ImageInterpolation {
+    Nearest,
+    Bilinear,
+}
+
+/// Where the first row of the image is placed. `Upper` places it at the top of the extent, as is
+/// common for images, and `Lower` places it at the bottom, as is common for matrices of data.
+#[derive(Clone, Debug)]
+pub enum ImageOrigin {
+    Upper,
+    Lower,
+}
+
+/// Image chart
+///
+/// Displays a 2D array as a grid of pixels. A `(rows, cols)` array of scalars is mapped to colors
+/// through a colormap, and a `(rows, cols, channels)` array is interpreted as gray (1 channel),
+/// gray and alpha (2 channels), RGB (3 channels), or RGBA (4 channels).
+///
+/// By default, the image covers `[0, cols] x [0, rows]` in data coordinates, such that each pixel
+/// has unit size, but this can be changed with `set_extent()`.
+#[derive(Clone, Debug)]
+pub struct Image {
+    pixels: Array3<f64>,
+    is_scalar: bool,
+    colormap: color::Colormap,
+    value_min: Option<f64>,
+    value_max: Option<f64>,
+    value_scale: scale::Scale,
+    interpolation: ImageInterpolation,
+    origin: ImageOrigin,
+    extent: shape::Rectangle,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+}
+
+impl Image {
+    /// Create and return a new Image chart from a `(rows, cols)` array of scalar values. The values
+    /// are mapped to colors with a colormap, which by default is `Colormap::Viridis`.
+    pub fn new<'a, I: AsArray<'a, f64, Ix2>>(data: I) -> Image {
+        let data_view = data.into();
+        let (num_rows, num_cols) = data_view.dim();
+        let pixels = Array3::from_shape_fn((num_rows, num_cols, 1), |(row, col, _)| {
+            data_view[[row, col]]
+        });
+        Image::with_pixels(pixels, true)
+    }
+
+    /// Create and return a new Image chart from a `(rows, cols, channels)` array of color channel
+    /// intensities in `[0.0, 1.0]`.
+    pub fn with_rgb<'a, I: AsArray<'a, f64, Ix3>>(data: I) -> Image {
+        let data_view = data.into();
+        let (num_rows, num_cols, num_channels) = data_view.dim();
+        let pixels = Array3::from_shape_fn((num_rows, num_cols, 4), |(row, col, channel)| {
+            let is_alpha = channel == 3;
+            match num_channels {
+                0 => if is_alpha { 1.0 } else { 0.0 },
+                1 => if is_alpha { 1.0 } else { data_view[[row, col, 0]] },
+                2 => if is_alpha { data_view[[row, col, 1]] } else { data_view[[row, col, 0]] },
+                3 => if is_alpha { 1.0 } else { data_view[[row, col, channel]] },
+                _ => data_view[[row, col, channel]],
+            }
+        });
+        Image::with_pixels(pixels, false)
+    }
+
+    /// Create and return a new Image chart from a `(rows, cols, channels)` array of color channel
+    /// intensities in `[0, 255]`.
+    pub fn with_rgb_u8<'a, I: AsArray<'a, u8, Ix3>>(data: I) -> Image {
+        let data_view = data.into();
+        Image::with_rgb(&data_view.mapv(|val| f64::from(val) / 255.0))
+    }
+
+    fn with_pixels(pixels: Array3<f64>, is_scalar: bool) -> Image {
+        let (num_rows, num_cols, _) = pixels.dim();
+        let extent = shape::Rectangle::with_boundaries(0.0, num_cols as f64, 0.0, num_rows as f64);
+        Image {
+            pixels: pixels,
+            is_scalar: is_scalar,
+            colormap: color::Colormap::Viridis,
+            value_min: None,
+            value_max: None,
+            value_scale: scale::Scale::Linear,
+            interpolation: ImageInterpolation::Nearest,
+            origin: ImageOrigin::Upper,
+            extent: extent.clone(),
+            data_frame: extent,
+            global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+        }
+    }
+
+    /// Set the colormap used for scalar images. Has no effect on color images.
+    pub fn set_colormap(&mut self, colormap: color::Colormap) -> &mut Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Set the values mapped to the lower and upper end of the colormap. Values outside of this
+    /// range are clamped. By default, the smallest and largest values of the image are used. Has no
+    /// effect on color images.
+    pub fn set_value_range(&mut self, min: f64, max: f64) -> &mut Self {
+        self.value_min = Some(min);
+        self.value_max = Some(max);
+        self
+    }
+
+    /// Set the scale that values are mapped to the colormap with, e.g. `Scale::Log10` for a
+    /// logarithmic color normalization. Values outside of the domain of the scale are not drawn.
+    /// Default is `Scale::Linear`. Has no effect on color images.
+    pub fn set_value_scale(&mut self, scale: scale::Scale) -> &mut Self {
+        self.value_scale = scale;
+        self
+    }
+
+    /// Set how the image is interpolated when it is drawn.
+    pub fn set_interpolation(&mut self, interpolation: ImageInterpolation) -> &mut Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Set whether the first row of the image is placed at the top or the bottom of the extent.
+    pub fn set_origin(&mut self, origin: ImageOrigin) -> &mut Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Set the area in data coordinates that the image covers.
+    pub fn set_extent(&mut self, left: f64, right: f64, bottom: f64, top: f64) -> &mut Self {
+        self.extent = shape::Rectangle::with_boundaries(left, right, bottom, top);
+        self.data_frame = self.extent.clone();
+        self
+    }
+
+    /// Return the range of scalar values mapped to the colormap.
+    fn value_range(&self) -> (f64, f64) {
+        let finite_values = self.pixels
+            .iter()
+            .cloned()
+            .filter(|val| val.is_finite() && self.value_scale.in_domain(*val));
+        let data_min = finite_values.clone().fold(f64::INFINITY, f64::min);
+        let data_max = finite_values.fold(f64::NEG_INFINITY, f64::max);
+        (
+            self.value_min.unwrap_or(data_min),
+            self.value_max.unwrap_or(data_max),
+        )
+    }
+
+    /// Return the colormap, the range of values that is mapped to it, and the scale of the mapping,
+    /// or None for color images
+    pub(crate) fn color_mapping(&self) -> Option<(color::Colormap, (f64, f64), scale::Scale)> {
+        if self.is_scalar {
+            Some((self.colormap.clone(), self.value_range(), self.value_scale.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Resample the pixels to (at least) `BILINEAR_RESOLUTION` cells along each dimension, by
+    /// linear interpolation between the pixel centres.
+    fn bilinear_resampled(&self) -> Array3<f64> {
+        let (num_rows, num_cols, num_channels) = self.pixels.dim();
+        if num_rows == 0 || num_cols == 0 {
+            return self.pixels.clone();
+        }
+        let new_num_rows = num_rows.max(BILINEAR_RESOLUTION);
+        let new_num_cols = num_cols.max(BILINEAR_RESOLUTION);
+
+        // Position of the centre of a new cell, in units of old pixels from the first pixel centre.
+        let source_coord = |index: usize, new_len: usize, old_len: usize| -> (usize, usize, f64) {
+            let coord = ((index as f64 + 0.5) * old_len as f64 / new_len as f64 - 0.5)
+                .max(0.0)
+                .min((old_len - 1) as f64);
+            let lower = coord.floor() as usize;
+            let upper = (lower + 1).min(old_len - 1);
+            (lower, upper, coord - lower as f64)
+        };
+
+        Array3::from_shape_fn(
+            (new_num_rows, new_num_cols, num_channels),
+            |(row, col, channel)| {
+                let (top, bottom, row_weight) = source_coord(row, new_num_rows, num_rows);
+                let (left, right, col_weight) = source_coord(col, new_num_cols, num_cols);
+                let upper_val = self.pixels[[top, left, channel]] * (1.0 - col_weight)
+                    + self.pixels[[top, right, channel]] * col_weight;
+                let lower_val = self.pixels[[bottom, left, channel]] * (1.0 - col_weight)
+                    + self.pixels[[bottom, right, channel]] * col_weight;
+                upper_val * (1.0 - row_weight) + lower_val * row_weight
+            },
+        )
+    }
+
+    /// Return the color of a pixel, or None if the pixel should not be drawn.
+    fn pixel_color(
+        &self,
+        pixels: &Array3<f64>,
+        row: usize,
+        col: usize,
+        value_range: (f64, f64),
+    ) -> Option<Srgba> {
+        if self.is_scalar {
+            let val = pixels[[row, col, 0]];
+            if !(val.is_finite() && self.value_scale.in_domain(val)) {
+                return None;
+            }
+            let (value_min, value_max) = value_range;
+            let normalized_val = if value_max > value_min {
+                self.value_scale.map_range(val, value_min, value_max, 0.0, 1.0)
+            } else {
+                0.5
+            };
+            Some(self.colormap.as_srgba(normalized_val))
+        } else {
+            Some(Srgba::new(
+                pixels[[row, col, 0]] as f32,
+                pixels[[row, col, 1]] as f32,
+                pixels[[row, col, 2]] as f32,
+                pixels[[row, col, 3]] as f32,
+            ))
+        }
+    }
+
+    /// Whether or not to clip the image at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the image is clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the image is drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the image is drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+    }
+}
+
+impl utils::Drawable for Image {
+    fn set_color_internal(&mut self, _: Srgba) {}
+
+    fn is_color_updated(&self) -> bool {
+        // Images carry their own colors
+        true
+    }
+
+    fn scale_size(&mut self, _: f64) {}
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+    }
+
+    fn draw(&self, cr: &Context, _: f64, _: f64) {
+        let pixels = match self.interpolation {
+            ImageInterpolation::Nearest => self.pixels.clone(),
+            ImageInterpolation::Bilinear => self.bilinear_resampled(),
+        };
+        let (num_rows, num_cols, _) = pixels.dim();
+        if num_rows == 0 || num_cols == 0 {
+            return;
+        }
+        let value_range = self.value_range();
+        let cell_width = (self.extent.right() - self.extent.left()) / num_cols as f64;
+        let cell_height = (self.extent.top() - self.extent.bottom()) / num_rows as f64;
+
+        // Antialiasing would leave visible seams between neighbouring cells
+        cr.save();
+        cr.set_antialias(Antialias::None);
+        for row in 0..num_rows {
+            let (cell_bottom, cell_top) = match self.origin {
+                ImageOrigin::Upper => (
+                    self.extent.top() - (row + 1) as f64 * cell_height,
+                    self.extent.top() - row as f64 * cell_height,
+                ),
+                ImageOrigin::Lower => (
+                    self.extent.bottom() + row as f64 * cell_height,
+                    self.extent.bottom() + (row + 1) as f64 * cell_height,
+                ),
+            };
+            let canvas_bottom = self.y_scale.map_range(
+                cell_bottom,
+                self.data_frame.bottom(),
+                self.data_frame.top(),
+                self.global_frame.bottom(),
+                self.global_frame.top(),
+            );
+            let canvas_top = self.y_scale.map_range(
+                cell_top,
+                self.data_frame.bottom(),
+                self.data_frame.top(),
+                self.global_frame.bottom(),
+                self.global_frame.top(),
+            );
+            for col in 0..num_cols {
+                let pixel_color = match self.pixel_color(&pixels, row, col, value_range) {
+                    Some(pixel_color) => pixel_color,
+                    None => continue,
+                };
+                let canvas_left = self.x_scale.map_range(
+                    self.extent.left() + col as f64 * cell_width,
+                    self.data_frame.left(),
+                    self.data_frame.right(),
+                    self.global_frame.left(),
+                    self.global_frame.right(),
+                );
+                let canvas_right = self.x_scale.map_range(
+                    self.extent.left() + (col + 1) as f64 * cell_width,
+                    self.data_frame.left(),
+                    self.data_frame.right(),
+                    self.global_frame.left(),
+                    self.global_frame.right(),
+                );
+                cr.set_source_rgba(
+                    f64::from(pixel_color.red),
+                    f64::from(pixel_color.green),
+                    f64::from(pixel_color.blue),
+                    f64::from(pixel_color.alpha),
+                );
+                cr.rectangle(
+                    canvas_left,
+                    canvas_bottom,
+                    canvas_right - canvas_left,
+                    canvas_top - canvas_bottom,
+                );
+                cr.fill();
+            }
+        }
+        cr.restore();
+    }
+}
+
+impl utils::Plottable for Image {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
@@ -5,7 +5,12 @@ use cairo::{Context, LineCap};
 use palette::Srgba;
 use ndarray::AsArray;
 
-use {chart, color, coord, shape, utils};
+use {chart, color, coord, datetime, scale, shape, utils};
+use utils::Plottable;
+
+/// The number of locations along every line segment that the legend avoids, in addition to the
+/// data points
+const LEGEND_STEPS_PER_SEGMENT: usize = 4;
 
 #[derive(Clone, Debug)]
 pub enum LineStyle {
@@ -101,12 +106,19 @@ pub struct Line {
     data_points: Vec<chart::point::Point>,
     data_frame: shape::Rectangle,
     global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
     color: color::Color,
     is_color_updated: bool,
     line_width: f64,
     line_style: LineStyle,
     stroke_style: StrokeStyle,
     dash_pattern: DashPattern,
+    error_bars: Option<chart::errorbar::ErrorBar>,
+    is_x_time: bool,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
 }
 
 impl Line {
@@ -145,15 +157,36 @@ impl Line {
                 y_data_max.val(),
             ),
             global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
             color: color::Color::with_custom(&color::CustomColor::Blue),
             is_color_updated: false,
             line_width: 0.0035,
             line_style: LineStyle::Plain,
             stroke_style: stroke_style,
             dash_pattern: dash_pattern,
+            error_bars: None,
+            is_x_time: false,
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
         }
     }
 
+    /// Create and return a new Line chart with times as horizontal coordinates, e.g.
+    /// `chrono::DateTime`, `chrono::NaiveDateTime`, or `chrono::NaiveDate`. The horizontal axis of
+    /// the canvas becomes a time axis, with marks at calendar units.
+    pub fn with_time<'a, T: datetime::TimeCoord, I: AsArray<'a, f64>>(
+        x_times: &[T],
+        y_data_coords: I,
+    ) -> Line {
+        let x_coords: Vec<f64> = x_times.iter().map(|time| time.epoch_seconds()).collect();
+        let y_coords: Vec<f64> = y_data_coords.into().iter().cloned().collect();
+        let mut chart = Line::new(&x_coords, &y_coords);
+        chart.is_x_time = true;
+        chart
+    }
+
     /// Set the line color
     pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
         self.color.set_color_custom(color);
@@ -238,6 +271,140 @@ impl Line {
         self.dash_pattern.set_line_cap(cap);
         self
     }
+
+    /// Attach error bars to the chart. The error bars are drawn in the line color, unless they
+    /// have a color of their own.
+    pub fn set_error_bars(&mut self, error_bars: &chart::ErrorBar) -> &mut Self {
+        self.error_bars = Some(error_bars.clone());
+        self
+    }
+
+    /// Whether or not the horizontal coordinates are times
+    pub(crate) fn is_x_time(&self) -> bool {
+        self.is_x_time
+    }
+
+    /// Return the data frame of the attached error bars, if any
+    pub(crate) fn error_bar_data_frame(&self) -> Option<shape::Rectangle> {
+        self.error_bars.as_ref().map(|error_bars| error_bars.data_frame())
+    }
+
+    /// Whether or not to clip the line at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the line is clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the line is drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the line is drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the line in the legend of the plot. Lines without a label are not listed
+    /// in the legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the line in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+        if let Some(ref mut error_bars) = self.error_bars {
+            error_bars.set_scales(x_scale, y_scale);
+        }
+    }
+
+    /// Return the location of a data point, relative to the figure
+    fn canvas_coord(&self, data_point: &chart::point::Point) -> coord::Coord {
+        coord::Coord::with_coordinates(
+            self.x_scale.map_range(
+                data_point.x_coord(),
+                self.data_frame.left(),
+                self.data_frame.right(),
+                self.global_frame.left(),
+                self.global_frame.right(),
+            ),
+            self.y_scale.map_range(
+                data_point.y_coord(),
+                self.data_frame.bottom(),
+                self.data_frame.top(),
+                self.global_frame.bottom(),
+                self.global_frame.top(),
+            ),
+        )
+    }
+
+    /// Return the locations of the data points, relative to the figure, with a few locations
+    /// along the line between every two neighbouring points
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        let coords: Vec<coord::Coord> = self.data_points
+            .iter()
+            .map(|data_point| self.canvas_coord(data_point))
+            .collect();
+        let mut line_coords = Vec::<coord::Coord>::new();
+        for pair in coords.windows(2) {
+            for k in 0..LEGEND_STEPS_PER_SEGMENT {
+                let t = k as f64 / LEGEND_STEPS_PER_SEGMENT as f64;
+                line_coords.push(coord::Coord::with_coordinates(
+                    pair[0].x() + t * (pair[1].x() - pair[0].x()),
+                    pair[0].y() + t * (pair[1].y() - pair[0].y()),
+                ));
+            }
+        }
+        line_coords.extend(coords.last().cloned());
+        line_coords
+    }
+
+    /// Draw a short line with the color, width and dash pattern of the line, across the middle of
+    /// `sample_frame`
+    pub(crate) fn draw_legend_sample(
+        &self,
+        cr: &Context,
+        sample_frame: &shape::Rectangle,
+        _: f64,
+        fig_rel_width: f64,
+    ) {
+        let line_color = self.color.as_srgba();
+        cr.set_source_rgba(
+            f64::from(line_color.red),
+            f64::from(line_color.green),
+            f64::from(line_color.blue),
+            f64::from(line_color.alpha),
+        );
+        cr.set_dash(
+            &[
+                self.dash_pattern.on_length(),
+                self.dash_pattern.off_length(),
+            ],
+            self.dash_pattern.offset(),
+        );
+        cr.set_line_cap(self.dash_pattern.line_cap());
+        cr.set_line_width(self.line_width * fig_rel_width);
+        let middle = 0.5 * (sample_frame.bottom() + sample_frame.top());
+        cr.move_to(sample_frame.left(), middle);
+        cr.line_to(sample_frame.right(), middle);
+        cr.stroke();
+        cr.set_dash(&[], 0.0);
+    }
 }
 
 impl utils::Drawable for Line {
@@ -268,9 +435,22 @@ impl utils::Drawable for Line {
         for data_point in &mut self.data_points {
             data_point.fit(canvas_global_frame, canvas_data_frame);
         }
+
+        if let Some(ref mut error_bars) = self.error_bars {
+            error_bars.fit(canvas_global_frame, canvas_data_frame);
+        }
     }
 
     fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        // The error bars are drawn below the line
+        if let Some(ref error_bars) = self.error_bars {
+            let mut error_bars = error_bars.clone();
+            if !error_bars.is_color_updated() {
+                error_bars.set_color_internal(self.color.as_srgba());
+            }
+            error_bars.draw(cr, fig_rel_height, fig_rel_width);
+        }
+
         let mut first_point = true;
         cr.set_dash(
             &[
@@ -284,14 +464,14 @@ impl utils::Drawable for Line {
         let line_color = self.color.as_srgba();
         match self.line_style {
             LineStyle::Plain => for data_point in &self.data_points {
-                let canvas_x = utils::map_range(
+                let canvas_x = self.x_scale.map_range(
                     data_point.x_coord(),
                     self.data_frame.left(),
                     self.data_frame.right(),
                     self.global_frame.left(),
                     self.global_frame.right(),
                 );
-                let canvas_y = utils::map_range(
+                let canvas_y = self.y_scale.map_range(
                     data_point.y_coord(),
                     self.data_frame.bottom(),
                     self.data_frame.top(),
@@ -326,14 +506,14 @@ impl utils::Drawable for Line {
             LineStyle::LeftStair => {
                 let mut prev_canvas_x = 0.0;
                 for data_point in &self.data_points {
-                    let canvas_x = utils::map_range(
+                    let canvas_x = self.x_scale.map_range(
                         data_point.x_coord(),
                         self.data_frame.left(),
                         self.data_frame.right(),
                         self.global_frame.left(),
                         self.global_frame.right(),
                     );
-                    let canvas_y = utils::map_range(
+                    let canvas_y = self.y_scale.map_range(
                         data_point.y_coord(),
                         self.data_frame.bottom(),
                         self.data_frame.top(),
@@ -392,14 +572,14 @@ impl utils::Drawable for Line {
             LineStyle::RightStair => {
                 let mut prev_canvas_y = 0.0;
                 for data_point in &self.data_points {
-                    let canvas_x = utils::map_range(
+                    let canvas_x = self.x_scale.map_range(
                         data_point.x_coord(),
                         self.data_frame.left(),
                         self.data_frame.right(),
                         self.global_frame.left(),
                         self.global_frame.right(),
                     );
-                    let canvas_y = utils::map_range(
+                    let canvas_y = self.y_scale.map_range(
                         data_point.y_coord(),
                         self.data_frame.bottom(),
                         self.data_frame.top(),
@@ -2,21 +2,88 @@
 
 pub use self::scatter::Scatter;
 pub use self::line::{Line, LineStyle, StrokeStyle};
+pub use self::bar::{Bar, BarLayout, BarOrientation};
+pub use self::hist::{Bins, Histogram, HistogramStyle};
+pub use self::image::{Image, ImageInterpolation, ImageOrigin};
+pub use self::heatmap::Heatmap;
+pub use self::fill::FillBetween;
+pub use self::boxplot::{BoxOrientation, BoxPlot, Whiskers};
+pub use self::pie::{Pie, PieDirection};
+pub use self::errorbar::ErrorBar;
 
 mod scatter;
 mod line;
 mod point;
+mod bar;
+mod hist;
+mod image;
+mod heatmap;
+mod fill;
+mod boxplot;
+mod pie;
+mod errorbar;
 
 use cairo::Context;
 use palette::Srgba;
 
-use {chart, shape, utils};
+use {chart, color, coord, scale, shape, utils};
+
+/// Distance between the locations that fill the area covered by a chart, relative to the figure.
+/// The legend avoids these locations in the same way as the data points of lines and scatter
+/// charts.
+const LEGEND_AREA_STEP: f64 = 0.01;
 
 /// A graphical representation of data.
 #[derive(Clone, Debug)]
 pub enum Chart {
     Scatter(chart::scatter::Scatter),
     Line(chart::line::Line),
+    Bar(chart::bar::Bar),
+    Histogram(chart::hist::Histogram),
+    Image(chart::image::Image),
+    Heatmap(chart::heatmap::Heatmap),
+    FillBetween(chart::fill::FillBetween),
+    BoxPlot(chart::boxplot::BoxPlot),
+    Pie(chart::pie::Pie),
+    ErrorBar(chart::errorbar::ErrorBar),
+}
+
+/// The pair of axes a chart is drawn against, given as its horizontal and vertical axis.
+///
+/// By default, charts are drawn against the bottom and the left axis of the canvas. Charts drawn
+/// against the top or the right axis give the canvas a secondary horizontal or vertical axis,
+/// with a data range, marks and label of its own. This way, quantities with different units can
+/// share a canvas, e.g. temperature on the left axis and load on the right axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AxisPair {
+    BottomLeft,
+    BottomRight,
+    TopLeft,
+    TopRight,
+}
+
+impl Default for AxisPair {
+    fn default() -> Self {
+        AxisPair::BottomLeft
+    }
+}
+
+impl AxisPair {
+    /// Whether or not the horizontal axis of the pair is the secondary, top axis
+    pub(crate) fn is_top(&self) -> bool {
+        match *self {
+            AxisPair::TopLeft | AxisPair::TopRight => true,
+            _ => false,
+        }
+    }
+
+    /// Whether or not the vertical axis of the pair is the secondary, right axis
+    pub(crate) fn is_right(&self) -> bool {
+        match *self {
+            AxisPair::BottomRight | AxisPair::TopRight => true,
+            _ => false,
+        }
+    }
 }
 
 /*
@@ -31,11 +98,222 @@ impl Chart {
 }
 */
 
+impl Chart {
+    /// Return the category marks this chart wants on the horizontal axis, as
+    /// `(data location, label)` pairs. Most charts have none.
+    pub(crate) fn x_category_marks(&self) -> Vec<(f64, String)> {
+        match *self {
+            Chart::Heatmap(ref h) => h.x_category_marks(),
+            Chart::BoxPlot(ref b) => b.x_category_marks(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Return the category marks this chart wants on the vertical axis, as
+    /// `(data location, label)` pairs. Most charts have none.
+    pub(crate) fn y_category_marks(&self) -> Vec<(f64, String)> {
+        match *self {
+            Chart::Heatmap(ref h) => h.y_category_marks(),
+            Chart::BoxPlot(ref b) => b.y_category_marks(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Return the category of every data point along the horizontal axis, for charts that take
+    /// categories in stead of numbers. Most charts have none.
+    pub(crate) fn x_categories(&self) -> Vec<String> {
+        match *self {
+            Chart::Scatter(ref s) => s.x_categories(),
+            Chart::Bar(ref b) => b.x_categories(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Return the category of every data point along the vertical axis, for charts that take
+    /// categories in stead of numbers. Most charts have none.
+    pub(crate) fn y_categories(&self) -> Vec<String> {
+        match *self {
+            Chart::Bar(ref b) => b.y_categories(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Place the data points of this chart at their categories, given the ordered categories of
+    /// the horizontal and vertical axis, and the padding between neighbouring categories.
+    pub(crate) fn set_category_positions(
+        &mut self,
+        x_categories: &[String],
+        y_categories: &[String],
+        padding: f64,
+    ) {
+        match *self {
+            Chart::Scatter(ref mut s) => s.set_x_category_positions(x_categories),
+            Chart::Bar(ref mut b) => b.set_category_positions(x_categories, y_categories, padding),
+            _ => {}
+        }
+    }
+
+    /// Return the data frame of the error bars attached to this chart, if any
+    pub(crate) fn error_bar_data_frame(&self) -> Option<shape::Rectangle> {
+        match *self {
+            Chart::Scatter(ref s) => s.error_bar_data_frame(),
+            Chart::Line(ref l) => l.error_bar_data_frame(),
+            _ => None,
+        }
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        match *self {
+            Chart::Scatter(ref mut s) => s.set_scales(x_scale, y_scale),
+            Chart::Line(ref mut l) => l.set_scales(x_scale, y_scale),
+            Chart::Bar(ref mut b) => b.set_scales(x_scale, y_scale),
+            Chart::Histogram(ref mut h) => h.set_scales(x_scale, y_scale),
+            Chart::Image(ref mut i) => i.set_scales(x_scale, y_scale),
+            Chart::Heatmap(ref mut h) => h.set_scales(x_scale, y_scale),
+            Chart::FillBetween(ref mut f) => f.set_scales(x_scale, y_scale),
+            Chart::BoxPlot(ref mut b) => b.set_scales(x_scale, y_scale),
+            Chart::Pie(_) => {}
+            Chart::ErrorBar(ref mut e) => e.set_scales(x_scale, y_scale),
+        }
+    }
+
+    /// Whether or not the horizontal coordinates of this chart are times
+    pub(crate) fn is_x_time(&self) -> bool {
+        match *self {
+            Chart::Scatter(ref s) => s.is_x_time(),
+            Chart::Line(ref l) => l.is_x_time(),
+            _ => false,
+        }
+    }
+
+    /// Whether or not this chart is clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        match *self {
+            Chart::Scatter(ref s) => s.clip_to_canvas(),
+            Chart::Line(ref l) => l.clip_to_canvas(),
+            Chart::Bar(ref b) => b.clip_to_canvas(),
+            Chart::Histogram(ref h) => h.clip_to_canvas(),
+            Chart::Image(ref i) => i.clip_to_canvas(),
+            Chart::Heatmap(ref h) => h.clip_to_canvas(),
+            Chart::FillBetween(ref f) => f.clip_to_canvas(),
+            Chart::BoxPlot(ref b) => b.clip_to_canvas(),
+            Chart::Pie(ref p) => p.clip_to_canvas(),
+            Chart::ErrorBar(ref e) => e.clip_to_canvas(),
+        }
+    }
+
+    /// Return the pair of axes this chart is drawn against
+    pub(crate) fn axis_pair(&self) -> AxisPair {
+        match *self {
+            Chart::Scatter(ref s) => s.axis_pair(),
+            Chart::Line(ref l) => l.axis_pair(),
+            Chart::Bar(ref b) => b.axis_pair(),
+            Chart::Histogram(ref h) => h.axis_pair(),
+            Chart::Image(ref i) => i.axis_pair(),
+            Chart::Heatmap(ref h) => h.axis_pair(),
+            Chart::FillBetween(ref f) => f.axis_pair(),
+            Chart::BoxPlot(ref b) => b.axis_pair(),
+            Chart::Pie(_) => AxisPair::default(),
+            Chart::ErrorBar(ref e) => e.axis_pair(),
+        }
+    }
+
+    /// Return the colormap of this chart, the range of values that is mapped to it, and the scale
+    /// of the mapping, if the chart is colored with a colormap
+    pub(crate) fn color_mapping(&self) -> Option<(color::Colormap, (f64, f64), scale::Scale)> {
+        match *self {
+            Chart::Image(ref i) => i.color_mapping(),
+            Chart::Heatmap(ref h) => h.color_mapping(),
+            _ => None,
+        }
+    }
+
+    /// Return the label of this chart in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        match *self {
+            Chart::Scatter(ref s) => s.legend_label(),
+            Chart::Line(ref l) => l.legend_label(),
+            Chart::Bar(ref b) => b.legend_label(),
+            Chart::Histogram(ref h) => h.legend_label(),
+            Chart::Heatmap(ref h) => h.legend_label(),
+            Chart::FillBetween(ref f) => f.legend_label(),
+            Chart::BoxPlot(ref b) => b.legend_label(),
+            Chart::ErrorBar(ref e) => e.legend_label(),
+            Chart::Image(_) | Chart::Pie(_) => None,
+        }
+    }
+
+    /// Draw a sample of this chart in the legend, within `sample_frame`, which is relative to the
+    /// figure. Charts that cover an area are represented by a filled patch.
+    pub(crate) fn draw_legend_sample(
+        &self,
+        cr: &Context,
+        sample_frame: &shape::Rectangle,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        match *self {
+            Chart::Scatter(ref s) => {
+                s.draw_legend_sample(cr, sample_frame, fig_rel_height, fig_rel_width)
+            }
+            Chart::Line(ref l) => {
+                l.draw_legend_sample(cr, sample_frame, fig_rel_height, fig_rel_width)
+            }
+            Chart::Bar(ref b) => b.draw_legend_sample(cr, sample_frame),
+            Chart::Histogram(ref h) => {
+                h.draw_legend_sample(cr, sample_frame, fig_rel_height, fig_rel_width)
+            }
+            Chart::Heatmap(ref h) => h.draw_legend_sample(cr, sample_frame),
+            Chart::FillBetween(ref f) => f.draw_legend_sample(cr, sample_frame),
+            Chart::BoxPlot(ref b) => {
+                b.draw_legend_sample(cr, sample_frame, fig_rel_height, fig_rel_width)
+            }
+            Chart::ErrorBar(ref e) => {
+                e.draw_legend_sample(cr, sample_frame, fig_rel_height, fig_rel_width)
+            }
+            Chart::Image(_) | Chart::Pie(_) => {}
+        }
+    }
+
+    /// Return the coordinates of the data points of this chart, relative to the figure, such that
+    /// the legend can avoid them. Bars, bins, boxes and filled regions are covered by locations
+    /// spread over their area. Images, heatmaps and pie charts have none.
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        match *self {
+            Chart::Scatter(ref s) => s.canvas_coords(),
+            Chart::Line(ref l) => l.canvas_coords(),
+            Chart::Bar(ref b) => b.canvas_coords(),
+            Chart::Histogram(ref h) => h.canvas_coords(),
+            Chart::FillBetween(ref f) => f.canvas_coords(),
+            Chart::BoxPlot(ref b) => b.canvas_coords(),
+            Chart::ErrorBar(ref e) => e.canvas_coords(),
+            Chart::Image(_) | Chart::Heatmap(_) | Chart::Pie(_) => Vec::new(),
+        }
+    }
+
+    /// Whether or not the canvas should display axes and grid lines for this chart
+    pub(crate) fn has_axes(&self) -> bool {
+        match *self {
+            Chart::Pie(_) => false,
+            _ => true,
+        }
+    }
+}
+
 impl utils::Drawable for Chart {
     fn set_color_internal(&mut self, color: Srgba) {
         match *self {
             Chart::Scatter(ref mut s) => s.set_color_internal(color),
             Chart::Line(ref mut l) => l.set_color_internal(color),
+            Chart::Bar(ref mut b) => b.set_color_internal(color),
+            Chart::Histogram(ref mut h) => h.set_color_internal(color),
+            Chart::Image(ref mut i) => i.set_color_internal(color),
+            Chart::Heatmap(ref mut h) => h.set_color_internal(color),
+            Chart::FillBetween(ref mut f) => f.set_color_internal(color),
+            Chart::BoxPlot(ref mut b) => b.set_color_internal(color),
+            Chart::Pie(ref mut p) => p.set_color_internal(color),
+            Chart::ErrorBar(ref mut e) => e.set_color_internal(color),
         }
     }
 
@@ -43,6 +321,14 @@ impl utils::Drawable for Chart {
         match *self {
             Chart::Scatter(ref s) => s.is_color_updated(),
             Chart::Line(ref l) => l.is_color_updated(),
+            Chart::Bar(ref b) => b.is_color_updated(),
+            Chart::Histogram(ref h) => h.is_color_updated(),
+            Chart::Image(ref i) => i.is_color_updated(),
+            Chart::Heatmap(ref h) => h.is_color_updated(),
+            Chart::FillBetween(ref f) => f.is_color_updated(),
+            Chart::BoxPlot(ref b) => b.is_color_updated(),
+            Chart::Pie(ref p) => p.is_color_updated(),
+            Chart::ErrorBar(ref e) => e.is_color_updated(),
         }
     }
 
@@ -50,6 +336,14 @@ impl utils::Drawable for Chart {
         match *self {
             Chart::Scatter(ref mut s) => s.scale_size(factor),
             Chart::Line(ref mut l) => l.scale_size(factor),
+            Chart::Bar(ref mut b) => b.scale_size(factor),
+            Chart::Histogram(ref mut h) => h.scale_size(factor),
+            Chart::Image(ref mut i) => i.scale_size(factor),
+            Chart::Heatmap(ref mut h) => h.scale_size(factor),
+            Chart::FillBetween(ref mut f) => f.scale_size(factor),
+            Chart::BoxPlot(ref mut b) => b.scale_size(factor),
+            Chart::Pie(ref mut p) => p.scale_size(factor),
+            Chart::ErrorBar(ref mut e) => e.scale_size(factor),
         }
     }
 
@@ -57,6 +351,14 @@ impl utils::Drawable for Chart {
         match *self {
             Chart::Scatter(ref mut s) => s.fit(global_frame, data_frame),
             Chart::Line(ref mut l) => l.fit(global_frame, data_frame),
+            Chart::Bar(ref mut b) => b.fit(global_frame, data_frame),
+            Chart::Histogram(ref mut h) => h.fit(global_frame, data_frame),
+            Chart::Image(ref mut i) => i.fit(global_frame, data_frame),
+            Chart::Heatmap(ref mut h) => h.fit(global_frame, data_frame),
+            Chart::FillBetween(ref mut f) => f.fit(global_frame, data_frame),
+            Chart::BoxPlot(ref mut b) => b.fit(global_frame, data_frame),
+            Chart::Pie(ref mut p) => p.fit(global_frame, data_frame),
+            Chart::ErrorBar(ref mut e) => e.fit(global_frame, data_frame),
         }
     }
 
@@ -64,6 +366,14 @@ impl utils::Drawable for Chart {
         match *self {
             Chart::Scatter(ref s) => s.draw(cr, fig_rel_height, fig_rel_width),
             Chart::Line(ref l) => l.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::Bar(ref b) => b.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::Histogram(ref h) => h.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::Image(ref i) => i.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::Heatmap(ref h) => h.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::FillBetween(ref f) => f.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::BoxPlot(ref b) => b.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::Pie(ref p) => p.draw(cr, fig_rel_height, fig_rel_width),
+            Chart::ErrorBar(ref e) => e.draw(cr, fig_rel_height, fig_rel_width),
         }
     }
 }
@@ -73,6 +383,14 @@ impl utils::Plottable for Chart {
         match *self {
             Chart::Scatter(ref s) => s.data_frame(),
             Chart::Line(ref l) => l.data_frame(),
+            Chart::Bar(ref b) => b.data_frame(),
+            Chart::Histogram(ref h) => h.data_frame(),
+            Chart::Image(ref i) => i.data_frame(),
+            Chart::Heatmap(ref h) => h.data_frame(),
+            Chart::FillBetween(ref f) => f.data_frame(),
+            Chart::BoxPlot(ref b) => b.data_frame(),
+            Chart::Pie(ref p) => p.data_frame(),
+            Chart::ErrorBar(ref e) => e.data_frame(),
         }
     }
 
@@ -80,6 +398,14 @@ impl utils::Plottable for Chart {
         match *self {
             Chart::Scatter(ref s) => s.data_x_min(),
             Chart::Line(ref l) => l.data_x_min(),
+            Chart::Bar(ref b) => b.data_x_min(),
+            Chart::Histogram(ref h) => h.data_x_min(),
+            Chart::Image(ref i) => i.data_x_min(),
+            Chart::Heatmap(ref h) => h.data_x_min(),
+            Chart::FillBetween(ref f) => f.data_x_min(),
+            Chart::BoxPlot(ref b) => b.data_x_min(),
+            Chart::Pie(ref p) => p.data_x_min(),
+            Chart::ErrorBar(ref e) => e.data_x_min(),
         }
     }
 
@@ -87,6 +413,14 @@ impl utils::Plottable for Chart {
         match *self {
             Chart::Scatter(ref s) => s.data_x_max(),
             Chart::Line(ref l) => l.data_x_max(),
+            Chart::Bar(ref b) => b.data_x_max(),
+            Chart::Histogram(ref h) => h.data_x_max(),
+            Chart::Image(ref i) => i.data_x_max(),
+            Chart::Heatmap(ref h) => h.data_x_max(),
+            Chart::FillBetween(ref f) => f.data_x_max(),
+            Chart::BoxPlot(ref b) => b.data_x_max(),
+            Chart::Pie(ref p) => p.data_x_max(),
+            Chart::ErrorBar(ref e) => e.data_x_max(),
         }
     }
 
@@ -94,6 +428,14 @@ impl utils::Plottable for Chart {
         match *self {
             Chart::Scatter(ref s) => s.data_y_min(),
             Chart::Line(ref l) => l.data_y_min(),
+            Chart::Bar(ref b) => b.data_y_min(),
+            Chart::Histogram(ref h) => h.data_y_min(),
+            Chart::Image(ref i) => i.data_y_min(),
+            Chart::Heatmap(ref h) => h.data_y_min(),
+            Chart::FillBetween(ref f) => f.data_y_min(),
+            Chart::BoxPlot(ref b) => b.data_y_min(),
+            Chart::Pie(ref p) => p.data_y_min(),
+            Chart::ErrorBar(ref e) => e.data_y_min(),
         }
     }
 
@@ -101,6 +443,14 @@ impl utils::Plottable for Chart {
         match *self {
             Chart::Scatter(ref s) => s.data_y_max(),
             Chart::Line(ref l) => l.data_y_max(),
+            Chart::Bar(ref b) => b.data_y_max(),
+            Chart::Histogram(ref h) => h.data_y_max(),
+            Chart::Image(ref i) => i.data_y_max(),
+            Chart::Heatmap(ref h) => h.data_y_max(),
+            Chart::FillBetween(ref f) => f.data_y_max(),
+            Chart::BoxPlot(ref b) => b.data_y_max(),
+            Chart::Pie(ref p) => p.data_y_max(),
+            Chart::ErrorBar(ref e) => e.data_y_max(),
         }
     }
 
@@ -108,6 +458,58 @@ impl utils::Plottable for Chart {
         match *self {
             Chart::Scatter(ref mut s) => s.set_data_frame(new_data_frame),
             Chart::Line(ref mut l) => l.set_data_frame(new_data_frame),
+            Chart::Bar(ref mut b) => b.set_data_frame(new_data_frame),
+            Chart::Histogram(ref mut h) => h.set_data_frame(new_data_frame),
+            Chart::Image(ref mut i) => i.set_data_frame(new_data_frame),
+            Chart::Heatmap(ref mut h) => h.set_data_frame(new_data_frame),
+            Chart::FillBetween(ref mut f) => f.set_data_frame(new_data_frame),
+            Chart::BoxPlot(ref mut b) => b.set_data_frame(new_data_frame),
+            Chart::Pie(ref mut p) => p.set_data_frame(new_data_frame),
+            Chart::ErrorBar(ref mut e) => e.set_data_frame(new_data_frame),
+        }
+    }
+}
+
+/// Return locations spread over the rectangle between the canvas coordinates `(x_start, y_start)`
+/// and `(x_end, y_end)`, including its edges, such that the legend can avoid the area
+pub(crate) fn area_coords(x_start: f64, x_end: f64, y_start: f64, y_end: f64) -> Vec<coord::Coord> {
+    if !(x_start.is_finite() && x_end.is_finite() && y_start.is_finite() && y_end.is_finite()) {
+        return Vec::new();
+    }
+    let num_x_steps = ((x_end - x_start).abs() / LEGEND_AREA_STEP).ceil().max(1.0) as usize;
+    let num_y_steps = ((y_end - y_start).abs() / LEGEND_AREA_STEP).ceil().max(1.0) as usize;
+    let mut coords = Vec::<coord::Coord>::new();
+    for i in 0..num_x_steps + 1 {
+        let x_coord = x_start + (x_end - x_start) * i as f64 / num_x_steps as f64;
+        for j in 0..num_y_steps + 1 {
+            let y_coord = y_start + (y_end - y_start) * j as f64 / num_y_steps as f64;
+            coords.push(coord::Coord::with_coordinates(x_coord, y_coord));
         }
     }
+    coords
+}
+
+/// Return the part of a legend sample frame that a patch is drawn in, leaving a gap to the
+/// neighbouring entries above and below
+pub(crate) fn patch_frame(sample_frame: &shape::Rectangle) -> shape::Rectangle {
+    let inset = 0.2 * sample_frame.height();
+    shape::Rectangle::with_boundaries(
+        sample_frame.left(),
+        sample_frame.right(),
+        sample_frame.bottom() + inset,
+        sample_frame.top() - inset,
+    )
+}
+
+/// Draw a patch filled with `color` in the legend, as the sample of a chart that covers an area
+pub(crate) fn draw_patch_sample(cr: &Context, sample_frame: &shape::Rectangle, color: Srgba) {
+    let frame = patch_frame(sample_frame);
+    cr.set_source_rgba(
+        f64::from(color.red),
+        f64::from(color.green),
+        f64::from(color.blue),
+        f64::from(color.alpha),
+    );
+    cr.rectangle(frame.left(), frame.bottom(), frame.width(), frame.height());
+    cr.fill();
 }
@@ -0,0 +1,426 @@
+//! Module that defines the Pie struct
+//!
+
+use std::f64;
+use std::f64::consts::PI;
+
+use cairo::Context;
+use ndarray::AsArray;
+use palette::Srgba;
+
+use {color, label, shape, utils};
+
+/// The direction in which consecutive wedges are placed around the pie.
+#[derive(Clone, Debug)]
+pub enum PieDirection {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// A single wedge of the pie
+#[derive(Clone, Debug)]
+struct Wedge {
+    value: f64,
+    label: String,
+    color: color::Color,
+    is_color_updated: bool,
+    explode: f64,
+}
+
+/// Pie chart
+///
+/// Displays the fraction each value makes of the total as a wedge of a circle. Wedges are colored
+/// individually, and can be labeled, pulled out from the centre, and annotated with their
+/// percentage of the total. With a non-zero inner radius, the pie becomes a donut.
+///
+/// A pie has no use for axes, so a canvas with a pie chart does not display its axes and grid
+/// lines. The pie is kept circular regardless of the shape of the canvas.
+#[derive(Clone, Debug)]
+pub struct Pie {
+    wedges: Vec<Wedge>,
+    start_angle: f64,
+    direction: PieDirection,
+    radius: f64,
+    inner_radius: f64,
+    display_percentages: bool,
+    percentage_precision: usize,
+    label_font_size: f64,
+    edge_color: color::Color,
+    edge_width: f64,
+    data_frame: shape::Rectangle,
+    global_frame: shape::Rectangle,
+    clip_to_canvas: bool,
+}
+
+impl Pie {
+    /// Create and return a new Pie chart. Each value is one wedge. Negative and NaN values are
+    /// drawn as empty wedges.
+    pub fn new<'a, I: AsArray<'a, f64>>(values: I) -> Pie {
+        let wedges = values
+            .into()
+            .iter()
+            .map(|&value| Wedge {
+                value: if value > 0.0 { value } else { 0.0 },
+                label: String::new(),
+                color: color::Color::with_custom(&color::CustomColor::Blue),
+                is_color_updated: false,
+                explode: 0.0,
+            })
+            .collect();
+        Pie {
+            wedges: wedges,
+            start_angle: 0.0,
+            direction: PieDirection::CounterClockwise,
+            radius: 0.8,
+            inner_radius: 0.0,
+            display_percentages: true,
+            percentage_precision: 1,
+            label_font_size: 0.025,
+            edge_color: color::Color::with_custom(&color::CustomColor::White),
+            edge_width: 0.003,
+            data_frame: shape::Rectangle::with_boundaries(0.0, 1.0, 0.0, 1.0),
+            global_frame: shape::Rectangle::new(),
+            clip_to_canvas: true,
+        }
+    }
+
+    /// Set the labels of the wedges, in the order of the values. The labels are placed outside of
+    /// the pie.
+    pub fn set_labels(&mut self, labels: &[&str]) -> &mut Self {
+        for (wedge, label) in self.wedges.iter_mut().zip(labels.iter()) {
+            wedge.label = String::from(*label);
+        }
+        self
+    }
+
+    /// Set the angle, in radians, where the first wedge starts. Zero is at the positive horizontal
+    /// direction, and the angle increases counterclockwise.
+    pub fn set_start_angle(&mut self, val: f64) -> &mut Self {
+        self.start_angle = val;
+        self
+    }
+
+    /// Set the direction in which the wedges are placed around the pie
+    pub fn set_direction(&mut self, direction: PieDirection) -> &mut Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Pull a wedge out from the centre. The offset is relative to the pie radius.
+    pub fn set_explode(&mut self, index: usize, val: f64) -> &mut Self {
+        if let Some(wedge) = self.wedges.get_mut(index) {
+            wedge.explode = val;
+        }
+        self
+    }
+
+    /// Set the radius of the pie, relative to the largest circle that fits in the canvas
+    pub fn set_radius(&mut self, val: f64) -> &mut Self {
+        self.radius = val;
+        self
+    }
+
+    /// Set the radius of the hole in the middle, relative to the pie radius. A non-zero inner
+    /// radius makes a donut chart.
+    pub fn set_inner_radius(&mut self, val: f64) -> &mut Self {
+        self.inner_radius = val.max(0.0).min(1.0);
+        self
+    }
+
+    /// Whether or not to display the percentage of the total in each wedge
+    pub fn display_percentages(&mut self, val: bool) -> &mut Self {
+        self.display_percentages = val;
+        self
+    }
+
+    /// Set the number of decimals in the percentage labels
+    pub fn set_percentage_precision(&mut self, val: usize) -> &mut Self {
+        self.percentage_precision = val;
+        self
+    }
+
+    /// Set the font size of the wedge labels and the percentage labels
+    pub fn set_label_font_size(&mut self, val: f64) -> &mut Self {
+        self.label_font_size = val;
+        self
+    }
+
+    /// Set the width of the edges between the wedges
+    pub fn set_edge_width(&mut self, val: f64) -> &mut Self {
+        self.edge_width = val;
+        self
+    }
+
+    /// Whether or not to clip the pie at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the pie is clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    fn set_wedge_color_internal(&mut self, index: usize, color: Srgba) {
+        if let Some(wedge) = self.wedges.get_mut(index) {
+            wedge.color.set_color(color);
+            wedge.is_color_updated = true;
+        }
+    }
+
+    /// Set the color of a single wedge
+    pub fn set_wedge_color(&mut self, index: usize, color: &color::CustomColor) -> &mut Self {
+        self.set_wedge_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single wedge
+    pub fn set_wedge_color_rgb(&mut self, index: usize, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_wedge_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single wedge
+    pub fn set_wedge_color_rgba(
+        &mut self,
+        index: usize,
+        red: f32,
+        green: f32,
+        blue: f32,
+        alpha: f32,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_wedge_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single wedge
+    pub fn set_wedge_color_rgb_u8(&mut self, index: usize, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_wedge_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single wedge
+    pub fn set_wedge_color_rgba_u8(
+        &mut self,
+        index: usize,
+        red: u8,
+        green: u8,
+        blue: u8,
+        alpha: u8,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_wedge_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Set the color of a single wedge
+    pub fn set_wedge_color_html(&mut self, index: usize, color: &color::HtmlColor) -> &mut Self {
+        self.set_wedge_color_internal(index, color.as_srgba());
+        self
+    }
+
+    /// Return the start and end angle of every wedge
+    fn wedge_angles(&self) -> Vec<(f64, f64)> {
+        let total: f64 = self.wedges.iter().map(|wedge| wedge.value).sum();
+        let mut angles = Vec::<(f64, f64)>::new();
+        let mut start_angle = self.start_angle;
+        for wedge in &self.wedges {
+            let sweep = if total > 0.0 {
+                2.0 * PI * wedge.value / total
+            } else {
+                0.0
+            };
+            let end_angle = match self.direction {
+                PieDirection::CounterClockwise => start_angle + sweep,
+                PieDirection::Clockwise => start_angle - sweep,
+            };
+            angles.push((start_angle, end_angle));
+            start_angle = end_angle;
+        }
+        angles
+    }
+
+    /// Draw a label centered at a point given relative to the pie centre.
+    ///
+    /// The offset is in units of the largest figure dimension, and is converted to the figure frame
+    /// by the relative figure dimensions, such that the pie stays circular.
+    fn draw_label(
+        &self,
+        cr: &Context,
+        content: &str,
+        color: Srgba,
+        offset: (f64, f64),
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        let centroid_x = 0.5 + offset.0 / (fig_rel_width * self.global_frame.width());
+        let centroid_y = 0.5 + offset.1 / (fig_rel_height * self.global_frame.height());
+        let mut label = label::Label::with_centroid(centroid_x, centroid_y);
+        label.set_content(content);
+        label.set_font_size(self.label_font_size);
+        label.set_color_internal(color);
+        label.fit(&self.global_frame);
+        label.draw(cr, fig_rel_height, fig_rel_width);
+    }
+}
+
+impl utils::Drawable for Pie {
+    fn set_color_internal(&mut self, color: Srgba) {
+        if let Some(wedge) = self.wedges.iter_mut().find(|w| !w.is_color_updated) {
+            wedge.color.set_color(color);
+            wedge.is_color_updated = true;
+        }
+    }
+
+    /// Returns true when every wedge has been given a color.
+    fn is_color_updated(&self) -> bool {
+        self.wedges.iter().all(|w| w.is_color_updated)
+    }
+
+    fn scale_size(&mut self, factor: f64) {
+        self.edge_width *= factor;
+    }
+
+    fn fit(
+        &mut self,
+        canvas_global_frame: &shape::Rectangle,
+        canvas_data_frame: &shape::Rectangle,
+    ) {
+        self.global_frame = canvas_global_frame.clone();
+        self.data_frame = canvas_data_frame.clone();
+        let scale_factor = self.global_frame.diag_len();
+        self.scale_size(scale_factor);
+    }
+
+    fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        // All lengths below are in units of the largest figure dimension, such that the pie is
+        // circular also in non-square figures and canvases.
+        let canvas_width = self.global_frame.width() * fig_rel_width;
+        let canvas_height = self.global_frame.height() * fig_rel_height;
+        let radius = 0.5 * self.radius * canvas_width.min(canvas_height);
+        let inner_radius = self.inner_radius * radius;
+        let center_x = (self.global_frame.left() + self.global_frame.right()) / 2.0;
+        let center_y = (self.global_frame.bottom() + self.global_frame.top()) / 2.0;
+        let total: f64 = self.wedges.iter().map(|wedge| wedge.value).sum();
+        let edge_color = self.edge_color.as_srgba();
+
+        for (wedge, &(start_angle, end_angle)) in self.wedges.iter().zip(self.wedge_angles().iter())
+        {
+            if wedge.value <= 0.0 {
+                continue;
+            }
+            let mid_angle = (start_angle + end_angle) / 2.0;
+            let explode_offset = wedge.explode * radius;
+
+            cr.save();
+            cr.translate(
+                center_x + explode_offset * mid_angle.cos() / fig_rel_width,
+                center_y + explode_offset * mid_angle.sin() / fig_rel_height,
+            );
+            cr.scale(1.0 / fig_rel_width, 1.0 / fig_rel_height);
+            cr.new_path();
+            let is_counter_clockwise = end_angle > start_angle;
+            if inner_radius > 0.0 {
+                if is_counter_clockwise {
+                    cr.arc(0.0, 0.0, radius, start_angle, end_angle);
+                    cr.arc_negative(0.0, 0.0, inner_radius, end_angle, start_angle);
+                } else {
+                    cr.arc_negative(0.0, 0.0, radius, start_angle, end_angle);
+                    cr.arc(0.0, 0.0, inner_radius, end_angle, start_angle);
+                }
+            } else {
+                cr.move_to(0.0, 0.0);
+                if is_counter_clockwise {
+                    cr.arc(0.0, 0.0, radius, start_angle, end_angle);
+                } else {
+                    cr.arc_negative(0.0, 0.0, radius, start_angle, end_angle);
+                }
+            }
+            cr.close_path();
+
+            let wedge_color = wedge.color.as_srgba();
+            cr.set_source_rgba(
+                f64::from(wedge_color.red),
+                f64::from(wedge_color.green),
+                f64::from(wedge_color.blue),
+                f64::from(wedge_color.alpha),
+            );
+            cr.fill_preserve();
+            cr.set_source_rgba(
+                f64::from(edge_color.red),
+                f64::from(edge_color.green),
+                f64::from(edge_color.blue),
+                f64::from(edge_color.alpha),
+            );
+            cr.set_line_width(self.edge_width);
+            cr.stroke();
+            cr.restore();
+
+            if !wedge.label.is_empty() {
+                let label_distance = 1.15 * radius + explode_offset;
+                self.draw_label(
+                    cr,
+                    &wedge.label,
+                    color::CustomColor::TickLabel.as_srgba(),
+                    (
+                        label_distance * mid_angle.cos(),
+                        label_distance * mid_angle.sin(),
+                    ),
+                    fig_rel_height,
+                    fig_rel_width,
+                );
+            }
+
+            if self.display_percentages {
+                let percentage_distance = if inner_radius > 0.0 {
+                    (inner_radius + radius) / 2.0 + explode_offset
+                } else {
+                    0.6 * radius + explode_offset
+                };
+                let percentage = 100.0 * wedge.value / total;
+                self.draw_label(
+                    cr,
+                    &format!("{:.*} %", self.percentage_precision, percentage),
+                    color::contrast_color(wedge_color),
+                    (
+                        percentage_distance * mid_angle.cos(),
+                        percentage_distance * mid_angle.sin(),
+                    ),
+                    fig_rel_height,
+                    fig_rel_width,
+                );
+            }
+        }
+    }
+}
+
+impl utils::Plottable for Pie {
+    fn data_frame(&self) -> shape::Rectangle {
+        self.data_frame.clone()
+    }
+
+    fn data_x_min(&self) -> f64 {
+        self.data_frame.left()
+    }
+
+    fn data_x_max(&self) -> f64 {
+        self.data_frame.right()
+    }
+
+    fn data_y_min(&self) -> f64 {
+        self.data_frame.bottom()
+    }
+
+    fn data_y_max(&self) -> f64 {
+        self.data_frame.top()
+    }
+
+    fn set_data_frame(&mut self, new_data_frame: shape::Rectangle) {
+        self.data_frame = new_data_frame;
+    }
+}
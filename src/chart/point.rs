@@ -72,25 +72,6 @@ impl Point {
     pub fn y_coord(&self) -> f64 {
         self.coord.y()
     }
-
-    #[allow(dead_code)]
-    pub fn map_range(&mut self, old_frame: &shape::Rectangle, new_frame: &shape::Rectangle) {
-        let new_x = utils::map_range(
-            self.x_coord(),
-            old_frame.left(),
-            old_frame.right(),
-            new_frame.right(),
-            new_frame.right(),
-        );
-        let new_y = utils::map_range(
-            self.y_coord(),
-            old_frame.bottom(),
-            old_frame.top(),
-            new_frame.bottom(),
-            new_frame.top(),
-        );
-        self.set_coord(new_x, new_y);
-    }
 }
 
 impl utils::Drawable for Point {
@@ -7,8 +7,8 @@ use cairo::Context;
 use ndarray::AsArray;
 use palette::Srgba;
 
-use {chart, color, shape, utils};
-use utils::Drawable;
+use {category, chart, color, coord, datetime, scale, shape, utils};
+use utils::{Drawable, Plottable};
 
 /// Scatter chart
 ///
@@ -18,11 +18,19 @@ use utils::Drawable;
 pub struct Scatter {
     data_points: Vec<chart::point::Point>,
     global_frame: shape::Rectangle,
+    x_scale: scale::Scale,
+    y_scale: scale::Scale,
     data_frame: shape::Rectangle,
     color: color::Color,
     is_color_updated: bool,
     shape: chart::point::Shape,
     point_size: f64,
+    error_bars: Option<chart::errorbar::ErrorBar>,
+    is_x_time: bool,
+    x_categories: Vec<String>,
+    clip_to_canvas: bool,
+    axis_pair: chart::AxisPair,
+    label: Option<String>,
 }
 
 impl Scatter {
@@ -57,6 +65,8 @@ impl Scatter {
         Scatter {
             data_points: data_points,
             global_frame: shape::Rectangle::new(),
+            x_scale: scale::Scale::Linear,
+            y_scale: scale::Scale::Linear,
             data_frame: shape::Rectangle::with_boundaries(
                 x_data_min.val(),
                 x_data_max.val(),
@@ -67,9 +77,49 @@ impl Scatter {
             is_color_updated: false,
             shape: shape,
             point_size: point_size,
+            error_bars: None,
+            is_x_time: false,
+            x_categories: Vec::<String>::new(),
+            clip_to_canvas: true,
+            axis_pair: chart::AxisPair::default(),
+            label: None,
         }
     }
 
+    /// Create and return a new Scatter chart with times as horizontal coordinates, e.g.
+    /// `chrono::DateTime`, `chrono::NaiveDateTime`, or `chrono::NaiveDate`. The horizontal axis of
+    /// the canvas becomes a time axis, with marks at calendar units.
+    pub fn with_time<'a, T: datetime::TimeCoord, I: AsArray<'a, f64>>(
+        x_times: &[T],
+        y_data_coords: I,
+    ) -> Scatter {
+        let x_coords: Vec<f64> = x_times.iter().map(|time| time.epoch_seconds()).collect();
+        let y_coords: Vec<f64> = y_data_coords.into().iter().cloned().collect();
+        let mut chart = Scatter::new(&x_coords, &y_coords);
+        chart.is_x_time = true;
+        chart
+    }
+
+    /// Create and return a new Scatter chart with categories as horizontal coordinates, e.g. to
+    /// show samples by group. The horizontal axis of the canvas becomes a categorical axis, and
+    /// every point is placed at its category.
+    pub fn with_categories<'a, I: AsArray<'a, f64>>(
+        x_categories: &[&str],
+        y_data_coords: I,
+    ) -> Scatter {
+        let x_categories: Vec<String> = x_categories
+            .iter()
+            .map(|name| String::from(*name))
+            .collect();
+        let ordered_categories =
+            category::order_categories(&x_categories, &category::CategoryOrder::Appearance);
+        let x_coords = category::category_positions(&x_categories, &ordered_categories);
+        let y_coords: Vec<f64> = y_data_coords.into().iter().cloned().collect();
+        let mut chart = Scatter::new(&x_coords, &y_coords);
+        chart.x_categories = x_categories;
+        chart
+    }
+
     /// Set the point color
     pub fn set_color(&mut self, color: &color::CustomColor) -> &mut Self {
         self.color.set_color_custom(color);
@@ -140,6 +190,132 @@ impl Scatter {
         };
         self
     }
+
+    /// Attach error bars to the chart. The error bars are drawn in the point color, unless they
+    /// have a color of their own.
+    pub fn set_error_bars(&mut self, error_bars: &chart::ErrorBar) -> &mut Self {
+        self.error_bars = Some(error_bars.clone());
+        self
+    }
+
+    /// Whether or not the horizontal coordinates are times
+    pub(crate) fn is_x_time(&self) -> bool {
+        self.is_x_time
+    }
+
+    /// Return the category of every point along the horizontal axis, if any
+    pub(crate) fn x_categories(&self) -> Vec<String> {
+        self.x_categories.clone()
+    }
+
+    /// Place the points at their categories, given the ordered categories of the horizontal axis
+    pub(crate) fn set_x_category_positions(&mut self, categories: &[String]) {
+        if self.x_categories.is_empty() || categories.is_empty() {
+            return;
+        }
+        let x_coords = category::category_positions(&self.x_categories, categories);
+        for (data_point, &x_coord) in self.data_points.iter_mut().zip(x_coords.iter()) {
+            data_point.set_x_coord(x_coord);
+        }
+        let x_min = x_coords.iter().cloned().fold(f64::INFINITY, f64::min);
+        let x_max = x_coords.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        self.data_frame.set_left(x_min);
+        self.data_frame.set_right(x_max);
+    }
+
+    /// Return the data frame of the attached error bars, if any
+    pub(crate) fn error_bar_data_frame(&self) -> Option<shape::Rectangle> {
+        self.error_bars.as_ref().map(|error_bars| error_bars.data_frame())
+    }
+
+    /// Whether or not to clip the points at the canvas boundary. By default, nothing is drawn
+    /// outside of the canvas.
+    pub fn set_clip_to_canvas(&mut self, val: bool) -> &mut Self {
+        self.clip_to_canvas = val;
+        self
+    }
+
+    /// Whether or not the points are clipped at the canvas boundary
+    pub(crate) fn clip_to_canvas(&self) -> bool {
+        self.clip_to_canvas
+    }
+
+    /// Set the pair of axes the points are drawn against. See the AxisPair enum for reference.
+    pub fn set_axis_pair(&mut self, axis_pair: chart::AxisPair) -> &mut Self {
+        self.axis_pair = axis_pair;
+        self
+    }
+
+    /// Return the pair of axes the points are drawn against
+    pub(crate) fn axis_pair(&self) -> chart::AxisPair {
+        self.axis_pair
+    }
+
+    /// Set the label of the scatter chart in the legend of the plot. Charts without a label are
+    /// not listed in the legend.
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(String::from(label));
+        self
+    }
+
+    /// Return the label of the scatter chart in the legend, if it has one
+    pub(crate) fn legend_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    /// Return the locations of the data points, relative to the figure
+    pub(crate) fn canvas_coords(&self) -> Vec<coord::Coord> {
+        self.data_points
+            .iter()
+            .map(|data_point| {
+                coord::Coord::with_coordinates(
+                    self.x_scale.map_range(
+                        data_point.x_coord(),
+                        self.data_frame.left(),
+                        self.data_frame.right(),
+                        self.global_frame.left(),
+                        self.global_frame.right(),
+                    ),
+                    self.y_scale.map_range(
+                        data_point.y_coord(),
+                        self.data_frame.bottom(),
+                        self.data_frame.top(),
+                        self.global_frame.bottom(),
+                        self.global_frame.top(),
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Draw a single point with the color, shape and size of the points, in the middle of
+    /// `sample_frame`
+    pub(crate) fn draw_legend_sample(
+        &self,
+        cr: &Context,
+        sample_frame: &shape::Rectangle,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        let mut point = chart::point::Point::new(
+            0.5 * (sample_frame.left() + sample_frame.right()),
+            0.5 * (sample_frame.bottom() + sample_frame.top()),
+        );
+        point.set_color_internal(self.color.as_srgba());
+        point.set_shape(self.shape.clone());
+        point.set_size(self.point_size);
+        point.fit(&self.global_frame, &self.data_frame);
+        point.draw(cr, fig_rel_height, fig_rel_width);
+    }
+
+    /// Set the scales of the canvas axes, used to map data coordinates to the canvas
+    pub(crate) fn set_scales(&mut self, x_scale: &scale::Scale, y_scale: &scale::Scale) {
+        self.x_scale = x_scale.clone();
+        self.y_scale = y_scale.clone();
+        if let Some(ref mut error_bars) = self.error_bars {
+            error_bars.set_scales(x_scale, y_scale);
+        }
+    }
 }
 
 impl utils::Drawable for Scatter {
@@ -170,18 +346,31 @@ impl utils::Drawable for Scatter {
             data_point.set_size(self.point_size);
             data_point.fit(canvas_global_frame, canvas_data_frame);
         }
+
+        if let Some(ref mut error_bars) = self.error_bars {
+            error_bars.fit(canvas_global_frame, canvas_data_frame);
+        }
     }
 
     fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        // The error bars are drawn below the points
+        if let Some(ref error_bars) = self.error_bars {
+            let mut error_bars = error_bars.clone();
+            if !error_bars.is_color_updated() {
+                error_bars.set_color_internal(self.color.as_srgba());
+            }
+            error_bars.draw(cr, fig_rel_height, fig_rel_width);
+        }
+
         for data_point in &self.data_points {
-            let canvas_x = utils::map_range(
+            let canvas_x = self.x_scale.map_range(
                 data_point.x_coord(),
                 self.data_frame.left(),
                 self.data_frame.right(),
                 self.global_frame.left(),
                 self.global_frame.right(),
             );
-            let canvas_y = utils::map_range(
+            let canvas_y = self.y_scale.map_range(
                 data_point.y_coord(),
                 self.data_frame.bottom(),
                 self.data_frame.top(),
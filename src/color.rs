@@ -585,3 +585,114 @@ impl Color {
         self.color
     }
 }
+
+/// Return a text color that is readable on top of `background`. Dark text is used on light
+/// backgrounds, and light text on dark backgrounds.
+pub(crate) fn contrast_color(background: Srgba) -> Srgba {
+    let luminance = 0.2126 * background.red + 0.7152 * background.green + 0.0722 * background.blue;
+    if luminance > 0.5 {
+        CustomColor::Black.as_srgba()
+    } else {
+        CustomColor::White.as_srgba()
+    }
+}
+
+/// Colormaps used to map scalar values to colors.
+///
+/// The perceptually uniform maps `Viridis`, `Magma`, `Inferno`, and `Plasma` are approximations of
+/// the [matplotlib colormaps](https://bids.github.io/colormap/) with the same names. `Custom`
+/// takes a list of `(red, green, blue)` colors that are evenly spread over the value range.
+#[derive(Clone, Debug)]
+pub enum Colormap {
+    Gray,
+    Viridis,
+    Magma,
+    Inferno,
+    Plasma,
+    Hot,
+    Coolwarm,
+    Custom(Vec<(u8, u8, u8)>),
+}
+
+impl Colormap {
+    /// Colors evenly spread over `[0.0, 1.0]`, between which the colormap is interpolated.
+    fn anchors(&self) -> Vec<(u8, u8, u8)> {
+        match *self {
+            Colormap::Gray => vec![(0, 0, 0), (255, 255, 255)],
+            Colormap::Viridis => vec![
+                (68, 1, 84),
+                (71, 44, 122),
+                (59, 81, 139),
+                (44, 113, 142),
+                (33, 144, 141),
+                (39, 173, 129),
+                (92, 200, 99),
+                (170, 220, 50),
+                (253, 231, 37),
+            ],
+            Colormap::Magma => vec![
+                (0, 0, 4),
+                (28, 16, 68),
+                (79, 18, 123),
+                (129, 37, 129),
+                (181, 54, 122),
+                (229, 80, 100),
+                (251, 135, 97),
+                (254, 194, 135),
+                (252, 253, 191),
+            ],
+            Colormap::Inferno => vec![
+                (0, 0, 4),
+                (31, 12, 72),
+                (85, 15, 109),
+                (136, 34, 106),
+                (186, 54, 85),
+                (227, 89, 51),
+                (249, 140, 10),
+                (249, 201, 50),
+                (252, 255, 164),
+            ],
+            Colormap::Plasma => vec![
+                (13, 8, 135),
+                (76, 2, 161),
+                (126, 3, 168),
+                (169, 35, 149),
+                (204, 71, 120),
+                (229, 107, 93),
+                (248, 148, 65),
+                (253, 195, 40),
+                (240, 249, 33),
+            ],
+            Colormap::Hot => vec![(10, 0, 0), (255, 0, 0), (255, 255, 0), (255, 255, 255)],
+            Colormap::Coolwarm => vec![(59, 76, 192), (221, 221, 221), (180, 4, 38)],
+            Colormap::Custom(ref colors) => if colors.is_empty() {
+                vec![(0, 0, 0), (255, 255, 255)]
+            } else {
+                colors.clone()
+            },
+        }
+    }
+
+    /// Return the color of `val`, where `val` is a normalized value in `[0.0, 1.0]`. Values outside
+    /// of this range are clamped.
+    pub fn as_srgba(&self, val: f64) -> Srgba {
+        let anchors = self.anchors();
+        let val = if val.is_nan() { 0.0 } else { val.max(0.0).min(1.0) };
+        let position = val * (anchors.len() - 1) as f64;
+        let lower_index = (position.floor() as usize).min(anchors.len() - 1);
+        let upper_index = (lower_index + 1).min(anchors.len() - 1);
+        let weight = (position - lower_index as f64) as f32;
+
+        let interpolate = |lower: u8, upper: u8| -> f32 {
+            (f32::from(lower) * (1.0 - weight) + f32::from(upper) * weight) / 255.0
+        };
+        let (lower_red, lower_green, lower_blue) = anchors[lower_index];
+        let (upper_red, upper_green, upper_blue) = anchors[upper_index];
+        Srgba::new(
+            interpolate(lower_red, upper_red),
+            interpolate(lower_green, upper_green),
+            interpolate(lower_blue, upper_blue),
+            1.0,
+        )
+    }
+}
@@ -0,0 +1,322 @@
+//! Definition of the Colorbar struct
+//!
+
+use std::f64;
+use failure::Error;
+
+use cairo::{Antialias, Context, FontSlant};
+
+use {axis, chart, color, coord, formatter, label, locator, shape};
+
+/// Number of bands that the colormap gradient is drawn with
+const NUM_GRADIENT_BANDS: usize = 256;
+
+/// Determines where the colorbar is placed, relative to the canvas.
+///
+/// | Variant | Placement                                                                   |
+/// | ------- | --------------------------------------------------------------------------- |
+/// | Right   | To the right of the canvas, along its whole height (default).               |
+/// | Left    | To the left of the canvas, outside of the vertical axis label.              |
+/// | Top     | Above the canvas, along its whole width.                                    |
+/// | Bottom  | Below the canvas, outside of the horizontal axis label.                     |
+///
+/// The canvas is made smaller to give room for the colorbar, within the plot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorbarLocation {
+    Right,
+    Left,
+    Top,
+    Bottom,
+}
+
+impl Default for ColorbarLocation {
+    fn default() -> Self {
+        ColorbarLocation::Right
+    }
+}
+
+impl ColorbarLocation {
+    fn is_vertical(&self) -> bool {
+        *self == ColorbarLocation::Right || *self == ColorbarLocation::Left
+    }
+
+    /// Whether the colorbar is on the side of the secondary axes, where the tick labels are
+    /// placed in the positive direction
+    fn is_positive(&self) -> bool {
+        *self == ColorbarLocation::Right || *self == ColorbarLocation::Top
+    }
+}
+
+/// ## Colorbar
+///
+/// A colorbar is a strip next to the canvas, showing the colormap of an image or a heatmap, with
+/// an axis of the values that are mapped to the colors. It shares the value range and value scale
+/// of its chart, e.g. from `Heatmap::set_value_range()` and `Heatmap::set_value_scale()`, and
+/// belongs to the first chart of the canvas that is colored by a colormap.
+///
+/// Sizes are relative to the canvas, as for the axes.
+#[derive(Clone, Debug)]
+pub struct Colorbar {
+    location: ColorbarLocation,
+    width: f64,
+    gap: Option<f64>,
+    display_frame: bool,
+    locator: locator::Locator,
+    formatter: formatter::Formatter,
+    label: label::Label,
+    colormap: color::Colormap,
+    global_frame: shape::Rectangle,
+    axis: Option<axis::Axis>,
+}
+
+impl Default for Colorbar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Colorbar {
+    /// Create and return a new colorbar
+    pub fn new() -> Colorbar {
+        let mut label = label::Label::new();
+        label.set_font_size(0.025);
+        label.set_color_internal(color::CustomColor::AxisLabel.as_srgba());
+        label.set_font_slant(FontSlant::Italic);
+        Colorbar {
+            location: ColorbarLocation::default(),
+            width: 0.04,
+            gap: None,
+            display_frame: true,
+            locator: locator::Locator::default(),
+            formatter: formatter::Formatter::default(),
+            label: label,
+            colormap: color::Colormap::Gray,
+            global_frame: shape::Rectangle::new(),
+            axis: None,
+        }
+    }
+
+    // ----------------- PLACEMENT ----------------------------------------- //
+
+    /// Set where the colorbar is placed. See the ColorbarLocation enum for reference.
+    pub fn set_location(&mut self, location: ColorbarLocation) -> &mut Self {
+        self.location = location;
+        self
+    }
+
+    /// Set the width of the colorbar strip. The default is 0.04.
+    pub fn set_width(&mut self, val: f64) -> &mut Self {
+        self.width = val;
+        self
+    }
+
+    /// Set the gap between the canvas and the colorbar strip. By default, the gap is 0.04 to the
+    /// right of and above the canvas, and clears the axis labels to the left of and below it.
+    pub fn set_gap(&mut self, val: f64) -> &mut Self {
+        self.gap = Some(val);
+        self
+    }
+
+    /// Whether or not to draw a frame around the colorbar strip
+    pub fn display_frame(&mut self, val: bool) -> &mut Self {
+        self.display_frame = val;
+        self
+    }
+
+    // ----------------- AXIS ---------------------------------------------- //
+
+    /// Set the label of the colorbar
+    pub fn set_label(&mut self, content: &str) -> &mut Self {
+        self.label.set_content(content);
+        self
+    }
+
+    /// Set the font size of the colorbar label
+    pub fn set_label_font_size(&mut self, val: f64) -> &mut Self {
+        self.label.set_font_size(val);
+        self
+    }
+
+    /// Set the locator that places the marks along the colorbar. See the Locator enum for
+    /// reference.
+    pub fn set_locator(&mut self, locator: locator::Locator) -> &mut Self {
+        self.locator = locator;
+        self
+    }
+
+    /// Set the formatter of the tick labels along the colorbar. See the Formatter enum for
+    /// reference.
+    pub fn set_formatter(&mut self, formatter: formatter::Formatter) -> &mut Self {
+        self.formatter = formatter;
+        self
+    }
+
+    // ----------------- PLACEMENT INTERNALS ------------------------------- //
+
+    /// Return the distances from the canvas to the near and the far side of the strip
+    fn strip_offsets(&self) -> (f64, f64) {
+        let gap = self.gap.unwrap_or(if self.location.is_positive() {
+            0.04
+        } else {
+            0.24
+        });
+        (gap, gap + self.width)
+    }
+
+    /// Return the room that the colorbar, its tick labels and its label take outside of the
+    /// canvas, relative to the canvas
+    pub(crate) fn room(&self) -> f64 {
+        let label_room = if self.label.content() != "" { 0.08 } else { 0.0 };
+        self.strip_offsets().1 + 0.1 + label_room
+    }
+
+    pub(crate) fn location(&self) -> ColorbarLocation {
+        self.location
+    }
+
+    /// Return the frame of the colorbar strip, relative to the canvas
+    fn local_frame(&self) -> shape::Rectangle {
+        let (near, far) = self.strip_offsets();
+        let (left, right, bottom, top) = match self.location {
+            ColorbarLocation::Right => (1.0 + near, 1.0 + far, 0.0, 1.0),
+            ColorbarLocation::Left => (-far, -near, 0.0, 1.0),
+            ColorbarLocation::Top => (0.0, 1.0, 1.0 + near, 1.0 + far),
+            ColorbarLocation::Bottom => (0.0, 1.0, -far, -near),
+        };
+        let mut frame = shape::Rectangle::with_boundaries(left, right, bottom, top);
+        frame.set_color_internal(color::CustomColor::CanvasBorder.as_srgba());
+        frame.set_border_thickness(0.0025);
+        frame.display_border(self.display_frame);
+        frame
+    }
+
+    /// Fit the colorbar to the canvas. The colorbar describes the first chart that is colored by a
+    /// colormap, and is left out if there is none, or if its value range is empty.
+    pub(crate) fn fit(
+        &mut self,
+        charts: &[chart::Chart],
+        canvas_frame: &shape::Rectangle,
+    ) -> Result<(), Error> {
+        self.axis = None;
+        let (colormap, (value_min, value_max), value_scale) =
+            match charts.iter().filter_map(|chart| chart.color_mapping()).next() {
+                Some(mapping) => mapping,
+                None => return Ok(()),
+            };
+        if !(value_min.is_finite() && value_max.is_finite() && value_max > value_min) {
+            return Ok(());
+        }
+        self.colormap = colormap;
+
+        let local_frame = self.local_frame();
+        self.global_frame = local_frame.relative_to(canvas_frame);
+        self.global_frame.scale_size(canvas_frame.diag_len());
+
+        // The axis runs along the outer side of the strip, with ticks and labels outside of it
+        let (start, end) = match self.location {
+            ColorbarLocation::Right => ((local_frame.right(), 0.0), (local_frame.right(), 1.0)),
+            ColorbarLocation::Left => ((local_frame.left(), 0.0), (local_frame.left(), 1.0)),
+            ColorbarLocation::Top => ((0.0, local_frame.top()), (1.0, local_frame.top())),
+            ColorbarLocation::Bottom => ((0.0, local_frame.bottom()), (1.0, local_frame.bottom())),
+        };
+        let mut axis = axis::Axis::with_boundaries(
+            &coord::Coord::with_coordinates(start.0, start.1),
+            &coord::Coord::with_coordinates(end.0, end.1),
+        );
+        axis.set_data_range(value_min, value_max);
+        axis.set_fixed_range(true);
+        axis.set_scale(&value_scale);
+        axis.set_locator(&self.locator);
+        axis.set_formatter(&self.formatter);
+        axis.compute_marks()?;
+
+        let (sign, tick_lengths) = if self.location.is_positive() {
+            (1.0, (0.01, 0.0))
+        } else {
+            (-1.0, (0.0, 0.01))
+        };
+        axis.display_line(false);
+        axis.set_positive_tick_length(tick_lengths.0);
+        axis.set_negative_tick_length(tick_lengths.1);
+        axis.set_tick_width(0.0025);
+        axis.set_minor_tick_length(0.0);
+        axis.set_tick_label_font_size(0.02);
+        axis.set_tick_label_frame_gaps(0.0, 0.0, 0.0, 0.0);
+        axis.set_tick_label_offset(sign * 0.05);
+
+        let mut label = self.label.clone();
+        if self.location.is_vertical() {
+            label.set_angle(f64::consts::PI / 2.0);
+            label.set_centroid(start.0 + sign * 0.13, 0.5);
+        } else {
+            label.set_centroid(0.5, start.1 + sign * 0.11);
+        }
+        axis.set_label(&label);
+
+        axis.fit(canvas_frame);
+        self.axis = Some(axis);
+        Ok(())
+    }
+
+    /// Return the frames that the colorbar strip and its texts cover in the figure. The colorbar
+    /// must be fitted first.
+    pub(crate) fn extents(
+        &self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) -> Vec<shape::Rectangle> {
+        match self.axis {
+            Some(ref axis) => {
+                let mut extents = axis.text_extents(cr, fig_rel_height, fig_rel_width);
+                extents.push(self.global_frame.clone());
+                extents
+            }
+            None => Vec::<shape::Rectangle>::new(),
+        }
+    }
+
+    /// Draw the colormap gradient, the frame and the axis of the colorbar
+    pub(crate) fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
+        let axis = match self.axis {
+            Some(ref axis) => axis,
+            None => return,
+        };
+
+        // Antialiasing would leave visible seams between neighbouring bands
+        let antialias = cr.get_antialias();
+        cr.set_antialias(Antialias::None);
+        let band_len = 1.0 / NUM_GRADIENT_BANDS as f64;
+        for band in 0..NUM_GRADIENT_BANDS {
+            let band_start = band as f64 * band_len;
+            let color = self.colormap.as_srgba(band_start + 0.5 * band_len);
+            cr.set_source_rgba(
+                f64::from(color.red),
+                f64::from(color.green),
+                f64::from(color.blue),
+                f64::from(color.alpha),
+            );
+            let frame = &self.global_frame;
+            if self.location.is_vertical() {
+                cr.rectangle(
+                    frame.left(),
+                    frame.bottom() + band_start * frame.height(),
+                    frame.width(),
+                    band_len * frame.height(),
+                );
+            } else {
+                cr.rectangle(
+                    frame.left() + band_start * frame.width(),
+                    frame.bottom(),
+                    band_len * frame.width(),
+                    frame.height(),
+                );
+            }
+            cr.fill();
+        }
+        cr.set_antialias(antialias);
+
+        self.global_frame.draw(cr, fig_rel_height, fig_rel_width);
+        axis.draw(cr, fig_rel_height, fig_rel_width);
+    }
+}
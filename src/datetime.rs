@@ -0,0 +1,254 @@
+//! Date and time coordinates, and calendar marks for time axes
+//!
+
+use std::f64;
+use std::i32;
+use failure::{err_msg, Error};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+
+/// The number of days from 0001-01-01 to 1970-01-01, the UNIX epoch
+const EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// ## TimeCoord
+///
+/// A point in time that can be used as a data coordinate. Internally, times are represented as
+/// seconds since the UNIX epoch, in UTC.
+pub trait TimeCoord {
+    /// Return the number of seconds since 1970-01-01T00:00:00 UTC
+    fn epoch_seconds(&self) -> f64;
+}
+
+impl TimeCoord for NaiveDateTime {
+    fn epoch_seconds(&self) -> f64 {
+        let days = i64::from(self.date().num_days_from_ce()) - EPOCH_DAYS_FROM_CE;
+        let seconds = days * SECONDS_PER_DAY + i64::from(self.time().num_seconds_from_midnight());
+        seconds as f64 + f64::from(self.time().nanosecond()) * 1e-9
+    }
+}
+
+impl TimeCoord for NaiveDate {
+    fn epoch_seconds(&self) -> f64 {
+        let days = i64::from(self.num_days_from_ce()) - EPOCH_DAYS_FROM_CE;
+        (days * SECONDS_PER_DAY) as f64
+    }
+}
+
+impl<Tz: TimeZone> TimeCoord for DateTime<Tz> {
+    fn epoch_seconds(&self) -> f64 {
+        self.naive_utc().epoch_seconds()
+    }
+}
+
+/// Return the UTC date and time of a number of seconds since the UNIX epoch, or None if it is not
+/// finite or outside of the supported dates
+pub(crate) fn from_epoch_seconds(seconds: f64) -> Option<NaiveDateTime> {
+    if !seconds.is_finite() {
+        return None;
+    }
+    let whole_seconds = seconds.floor() as i64;
+    let days_from_ce = whole_seconds.div_euclid(SECONDS_PER_DAY) + EPOCH_DAYS_FROM_CE;
+    if days_from_ce < i64::from(i32::MIN) || days_from_ce > i64::from(i32::MAX) {
+        return None;
+    }
+    let seconds_of_day = whole_seconds.rem_euclid(SECONDS_PER_DAY) as u32;
+    let date = NaiveDate::from_num_days_from_ce_opt(days_from_ce as i32)?;
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(seconds_of_day, 0)?;
+    Some(NaiveDateTime::new(date, time))
+}
+
+/// The distance between two marks on a time axis
+#[derive(Clone, Copy, Debug)]
+enum TimeStep {
+    Seconds(i64),
+    Weeks(i64),
+    Months(i64),
+    Years(i64),
+}
+
+impl TimeStep {
+    /// Return the approximate length of the step in seconds
+    fn approx_seconds(&self) -> f64 {
+        match *self {
+            TimeStep::Seconds(n) => n as f64,
+            TimeStep::Weeks(n) => (n * 7 * SECONDS_PER_DAY) as f64,
+            TimeStep::Months(n) => n as f64 * 30.44 * SECONDS_PER_DAY as f64,
+            TimeStep::Years(n) => n as f64 * 365.25 * SECONDS_PER_DAY as f64,
+        }
+    }
+
+    /// Return the strftime format of the mark labels, given the data span in seconds
+    fn default_format(&self, span: f64) -> &'static str {
+        match *self {
+            TimeStep::Seconds(n) if n < 60 => "%H:%M:%S",
+            TimeStep::Seconds(n) if n < SECONDS_PER_DAY => if span > SECONDS_PER_DAY as f64 {
+                "%m-%d %H:%M"
+            } else {
+                "%H:%M"
+            },
+            TimeStep::Seconds(_) | TimeStep::Weeks(_) => "%Y-%m-%d",
+            TimeStep::Months(_) => "%b %Y",
+            TimeStep::Years(_) => "%Y",
+        }
+    }
+
+    /// Return the last mark at or before `time`, or None if it is outside of the supported dates
+    fn floor(&self, time: f64) -> Option<f64> {
+        match *self {
+            TimeStep::Seconds(n) => Some((time / n as f64).floor() * n as f64),
+            TimeStep::Weeks(n) => {
+                // 1970-01-01 was a thursday, and weeks start on mondays
+                let days = (time / SECONDS_PER_DAY as f64).floor() as i64 + 3;
+                let week_days = 7 * n;
+                Some((days.div_euclid(week_days) * week_days - 3) as f64 * SECONDS_PER_DAY as f64)
+            }
+            TimeStep::Months(n) => {
+                let date = from_epoch_seconds(time)?.date();
+                let month_index = i64::from(date.year()) * 12 + i64::from(date.month0());
+                month_start(month_index.div_euclid(n) * n)
+            }
+            TimeStep::Years(n) => {
+                let year = i64::from(from_epoch_seconds(time)?.date().year());
+                month_start(year.div_euclid(n) * n * 12)
+            }
+        }
+    }
+
+    /// Return the mark following the mark at `time`, or None if it is outside of the supported
+    /// dates
+    fn next(&self, time: f64) -> Option<f64> {
+        match *self {
+            TimeStep::Seconds(_) | TimeStep::Weeks(_) => Some(time + self.approx_seconds()),
+            TimeStep::Months(n) => {
+                let date = from_epoch_seconds(time)?.date();
+                month_start(i64::from(date.year()) * 12 + i64::from(date.month0()) + n)
+            }
+            TimeStep::Years(n) => {
+                let year = i64::from(from_epoch_seconds(time)?.date().year());
+                month_start((year + n) * 12)
+            }
+        }
+    }
+}
+
+/// Return the start of a month, counted as `12 * year + month0`, in seconds since the epoch, or
+/// None if it is outside of the supported dates
+fn month_start(month_index: i64) -> Option<f64> {
+    let year = month_index.div_euclid(12);
+    if year < i64::from(i32::MIN) || year > i64::from(i32::MAX) {
+        return None;
+    }
+    let month = month_index.rem_euclid(12) as u32 + 1;
+    NaiveDate::from_ymd_opt(year as i32, month, 1).map(|date| date.epoch_seconds())
+}
+
+/// Return the error of a time range that can not be marked
+fn range_error(time_min: f64, time_max: f64) -> Error {
+    err_msg(format!(
+        "Could not place time marks on the data range [{}, {}], which must be finite and within \
+         the supported dates",
+        time_min, time_max
+    ))
+}
+
+/// Return the candidate steps between time marks, from the smallest to the largest
+fn candidate_steps() -> Vec<TimeStep> {
+    let mut steps = Vec::<TimeStep>::new();
+    for &seconds in &[1, 2, 5, 10, 15, 30] {
+        steps.push(TimeStep::Seconds(seconds));
+    }
+    for &minutes in &[1, 2, 5, 10, 15, 30] {
+        steps.push(TimeStep::Seconds(minutes * 60));
+    }
+    for &hours in &[1, 2, 3, 6, 12] {
+        steps.push(TimeStep::Seconds(hours * 3600));
+    }
+    for &days in &[1, 2] {
+        steps.push(TimeStep::Seconds(days * SECONDS_PER_DAY));
+    }
+    steps.push(TimeStep::Weeks(1));
+    for &months in &[1, 2, 3, 6] {
+        steps.push(TimeStep::Months(months));
+    }
+    for &years in &[1, 2, 5, 10, 20, 50, 100, 200, 500, 1000] {
+        steps.push(TimeStep::Years(years));
+    }
+    steps
+}
+
+/// Return marks snapped to calendar units for a time axis spanning `[time_min, time_max]` seconds
+/// since the epoch, as `(data location, label)` pairs.
+///
+/// The step between the marks is the smallest calendar unit (seconds, minutes, hours, days, weeks,
+/// months, or years) that gives at most `num_marks` marks. The first mark is at or before
+/// `time_min`, and the last mark at or after `time_max`. The labels are formatted with
+/// `label_format`, or with a format that fits the step if it is None. An error is returned if the
+/// range is not finite, or if any mark is outside of the supported dates.
+pub(crate) fn time_marks(
+    time_min: f64,
+    time_max: f64,
+    num_marks: usize,
+    label_format: Option<&str>,
+) -> Result<Vec<(f64, String)>, Error> {
+    if !(time_min.is_finite() && time_max.is_finite()) {
+        return Err(range_error(time_min, time_max));
+    }
+    let span = time_max - time_min;
+    let max_num_intervals = num_marks.max(2) - 1;
+    let steps = candidate_steps();
+    let step = steps
+        .iter()
+        .find(|step| span / step.approx_seconds() <= max_num_intervals as f64)
+        .unwrap_or_else(|| steps.last().expect("No time steps"));
+    let label_format = label_format.unwrap_or_else(|| step.default_format(span));
+
+    let mut marks = Vec::<(f64, String)>::new();
+    let mut time = step
+        .floor(time_min)
+        .ok_or_else(|| range_error(time_min, time_max))?;
+    loop {
+        let label = from_epoch_seconds(time)
+            .ok_or_else(|| range_error(time_min, time_max))?
+            .format(label_format)
+            .to_string();
+        marks.push((time, label));
+        if time >= time_max && marks.len() > 1 {
+            break;
+        }
+        time = step
+            .next(time)
+            .ok_or_else(|| range_error(time_min, time_max))?;
+    }
+    Ok(marks)
+}
+
+/// Return the labels of marks at the given times, in seconds since the epoch. The labels are
+/// formatted with `label_format`, or with a format that fits the smallest distance between
+/// neighbouring marks if it is None. An error is returned if any time is not finite, or outside
+/// of the supported dates.
+pub(crate) fn time_labels(times: &[f64], label_format: Option<&str>) -> Result<Vec<String>, Error> {
+    let span = match (times.first(), times.last()) {
+        (Some(first), Some(last)) => last - first,
+        _ => 0.0,
+    };
+    let min_distance = times
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .fold(f64::MAX, f64::min);
+    let steps = candidate_steps();
+    let step = steps
+        .iter()
+        .rev()
+        .find(|step| step.approx_seconds() <= min_distance)
+        .unwrap_or_else(|| steps.first().expect("No time steps"));
+    let label_format = label_format.unwrap_or_else(|| step.default_format(span));
+    times
+        .iter()
+        .map(|&time| match from_epoch_seconds(time) {
+            Some(date_time) => Ok(date_time.format(label_format).to_string()),
+            None => Err(err_msg(format!("Could not label the time mark at {} seconds", time))),
+        })
+        .collect()
+}
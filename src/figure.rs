@@ -6,18 +6,20 @@ use failure::{err_msg, Error};
 
 use cairo::{Context, FontSlant, FontWeight, Format, ImageSurface, Matrix, MatrixTrait};
 
-use {color, label, plot, shape};
+use {color, label, layout, plot, shape};
 
 /// A Figure holds plots, and can be viewed on screen or saved as a png image.
 #[derive(Clone)]
 pub struct Figure {
     plots: Vec<plot::Plot>,
+    grid: layout::Grid,
     title: label::Label,
     window_title: String,
     height: usize,
     width: usize,
     color: color::Color,
     local_frame: shape::Rectangle,
+    tight_layout: bool,
 }
 
 impl Default for Figure {
@@ -37,12 +39,14 @@ impl Figure {
         title.set_font_size(0.02);
         Figure {
             plots: Vec::<plot::Plot>::new(),
+            grid: layout::Grid::new(),
             title: title,
             window_title: String::from("Astrup"),
             height: 800,
             width: 1000,
             color: color::Color::with_custom(&color::CustomColor::FigureBackground),
             local_frame: local_frame,
+            tight_layout: false,
         }
     }
 
@@ -91,7 +95,8 @@ impl Figure {
 
     /// Set gaps around figure title.
     ///
-    /// NOTE: This has currently no visible effect
+    /// NOTE: This only has a visible effect with a tight layout, see `Figure::set_tight_layout()`,
+    /// where the gaps are kept free around the title.
     pub fn set_title_frame_gaps(&mut self, left: f64, right: f64, bottom: f64, top: f64) -> &mut Self {
         self.title.set_frame_gaps(left, right, bottom, top);
         self
@@ -258,12 +263,92 @@ impl Figure {
         self
     }
 
+    /// Whether or not to fit the figure with a tight layout. The titles, axis labels and tick
+    /// labels are then measured as they will be drawn, and every canvas is made as large as
+    /// possible, without any text overlapping or being clipped by its plot. The canvas frames set
+    /// with `Plot::set_canvas_local_frame()` are overridden. Default is false.
+    pub fn set_tight_layout(&mut self, val: bool) -> &mut Self {
+        self.tight_layout = val;
+        self
+    }
+
     /// Add plots to figure
     pub fn add_plot(&mut self, plot: &plot::Plot) -> &mut Self {
         self.plots.push(plot.clone());
         self
     }
 
+    // ----------------- GRID LAYOUT --------------------------------------- //
+
+    /// Divide the figure into a grid with `num_rows x num_cols` cells. Plots added with
+    /// `add_plot_at()` or `add_plot_spanning()` are placed in these cells, and their local frames
+    /// are computed when the figure is drawn. Rows are counted from the top, and columns from the
+    /// left, starting at zero.
+    pub fn set_grid(&mut self, num_rows: usize, num_cols: usize) -> &mut Self {
+        self.grid.set_size(num_rows, num_cols);
+        self
+    }
+
+    /// Set the height of the grid rows, relative to each other, from the top. There must be one
+    /// positive ratio for each row. By default, all rows are equally high.
+    pub fn set_grid_height_ratios(&mut self, ratios: &[f64]) -> &mut Self {
+        self.grid.set_height_ratios(ratios);
+        self
+    }
+
+    /// Set the width of the grid columns, relative to each other, from the left. There must be one
+    /// positive ratio for each column. By default, all columns are equally wide.
+    pub fn set_grid_width_ratios(&mut self, ratios: &[f64]) -> &mut Self {
+        self.grid.set_width_ratios(ratios);
+        self
+    }
+
+    /// Set the space between neighbouring grid columns and rows, relative to the figure. Default
+    /// is 0.01 for both.
+    pub fn set_grid_spacing(&mut self, horizontal: f64, vertical: f64) -> &mut Self {
+        self.grid.set_spacing(horizontal, vertical);
+        self
+    }
+
+    /// Whether the plots in the same grid columns share their horizontal data range. The shared
+    /// range covers the data of all of them, unless it is set on any of them, e.g. with
+    /// `Plot::set_x_range()`. The tick labels of the horizontal axis are only displayed on the
+    /// bottom plot of each column. Default is false.
+    pub fn set_grid_shared_x(&mut self, val: bool) -> &mut Self {
+        self.grid.set_share_x(val);
+        self
+    }
+
+    /// Whether the plots in the same grid rows share their vertical data range. The shared range
+    /// covers the data of all of them, unless it is set on any of them, e.g. with
+    /// `Plot::set_y_range()`. The tick labels of the vertical axis are only displayed on the
+    /// leftmost plot of each row. Default is false.
+    pub fn set_grid_shared_y(&mut self, val: bool) -> &mut Self {
+        self.grid.set_share_y(val);
+        self
+    }
+
+    /// Add a plot to the grid cell at `row` and `col`
+    pub fn add_plot_at(&mut self, plot: &plot::Plot, row: usize, col: usize) -> &mut Self {
+        self.add_plot_spanning(plot, row, col, 1, 1)
+    }
+
+    /// Add a plot to the grid, with its upper left cell at `row` and `col`, spanning `row_span`
+    /// rows downwards and `col_span` columns to the right.
+    pub fn add_plot_spanning(
+        &mut self,
+        plot: &plot::Plot,
+        row: usize,
+        col: usize,
+        row_span: usize,
+        col_span: usize,
+    ) -> &mut Self {
+        let mut plot = plot.clone();
+        plot.set_grid_cell(layout::GridCell::new(row, col, row_span, col_span));
+        self.plots.push(plot);
+        self
+    }
+
     pub fn save(&mut self, filename: &str) -> Result<&mut Self, Error> {
         // Since both save() and show() can be called, and since all drawing is happening in both,
         // multiple calls to fit() will be made, and this can mess up things if we call it on self.
@@ -286,17 +371,89 @@ impl Figure {
         Ok(self)
     }
 
+    /// Share the data ranges of the plots in the same grid columns, and of the plots in the same
+    /// grid rows, and hide the tick labels of the shared axes on the inner plots.
+    fn share_axes(&mut self) {
+        // The cells and the data frames of the plots in the grid
+        let grid_plots: Vec<(layout::GridCell, shape::Rectangle)> = self.plots
+            .iter()
+            .filter_map(|plot| plot.grid_cell().map(|cell| (cell, plot.unshared_data_frame())))
+            .collect();
+
+        for plot in &mut self.plots {
+            let cell = match plot.grid_cell() {
+                Some(cell) => cell,
+                None => continue,
+            };
+            if self.grid.share_x() {
+                let column: Vec<&(layout::GridCell, shape::Rectangle)> = grid_plots
+                    .iter()
+                    .filter(|other| other.0.is_in_columns_of(&cell))
+                    .collect();
+                let frames: Vec<&shape::Rectangle> = column.iter().map(|other| &other.1).collect();
+                let (x_min, x_max) = layout::shared_range(&frames, true);
+                plot.set_shared_x_range(x_min, x_max);
+                if column.iter().any(|other| other.0.end_row() > cell.end_row()) {
+                    plot.display_x_tick_labels(false);
+                }
+            }
+            if self.grid.share_y() {
+                let row: Vec<&(layout::GridCell, shape::Rectangle)> = grid_plots
+                    .iter()
+                    .filter(|other| other.0.is_in_rows_of(&cell))
+                    .collect();
+                let frames: Vec<&shape::Rectangle> = row.iter().map(|other| &other.1).collect();
+                let (y_min, y_max) = layout::shared_range(&frames, false);
+                plot.set_shared_y_range(y_min, y_max);
+                if row.iter().any(|other| other.0.col() < cell.col()) {
+                    plot.display_y_tick_labels(false);
+                }
+            }
+        }
+    }
+
     pub(crate) fn fit(&mut self) -> Result<(), Error> {
+        if self.grid.share_x() || self.grid.share_y() {
+            self.share_axes();
+        }
+
         // TODO: Issue #13
         self.title.fit(&shape::Rectangle::new());
-        for plot in &mut self.plots {
-            let new_top = if self.title.content() == "" {
-                plot.top()
+        let relative_height = self.height() as f64 / self.height().max(self.width()) as f64;
+        let relative_width = self.width() as f64 / self.height().max(self.width()) as f64;
+
+        // With a tight layout, the plots are placed right below the measured figure title
+        let measuring_context = if self.tight_layout {
+            Some(layout::measuring_context(self.width, self.height)?)
+        } else {
+            None
+        };
+        let plots_top = match measuring_context {
+            Some(ref cr) => match self.title.extent(cr, relative_height, relative_width) {
+                Some(extent) => extent.bottom() - layout::TIGHT_LAYOUT_PADDING,
+                None => 1.0,
+            },
+            None => if self.title.content() == "" {
+                1.0
             } else {
-                plot.top().min(0.93)
-            };
+                0.93
+            },
+        };
+
+        let mut grid_area = shape::Rectangle::new();
+        grid_area.set_top(plots_top);
+        for plot in &mut self.plots {
+            if let Some(cell) = plot.grid_cell() {
+                let frame = self.grid.cell_frame(&cell, &grid_area)?;
+                let (left, right) = (frame.left(), frame.right());
+                plot.set_local_frame_mut_ref(left, right, frame.bottom(), frame.top());
+            }
+            let new_top = plot.top().min(plots_top);
             plot.set_top_mut_ref(new_top);
-            plot.fit()?;
+            match measuring_context {
+                Some(ref cr) => plot.fit_tight(cr, relative_height, relative_width)?,
+                None => plot.fit()?,
+            }
         }
 
         Ok(())
@@ -0,0 +1,222 @@
+//! Definition of the Formatter enum, which determines the tick labels of an axis
+//!
+
+use std::f64;
+use std::fmt;
+use std::rc::Rc;
+
+use {scale, utils};
+
+/// The SI prefixes from 10⁻²⁴ to 10²⁴, in steps of 10³
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// The index of the empty prefix in `SI_PREFIXES`
+const SI_UNIT_INDEX: i32 = 8;
+
+/// Scientific labels share an offset when the values are this many times larger than the
+/// distance between them, e.g. for 1000001, 1000002 and 1000003.
+const OFFSET_RATIO: f64 = 1e4;
+
+/// Determines how the data locations of the marks of an axis are written as tick labels.
+///
+/// | Variant         | Example, for 12345.6                                                    |
+/// | --------------- | ----------------------------------------------------------------------- |
+/// | Auto            | 1.23e4 (default). Log, time and other axes keep their own labels.       |
+/// | Decimals(2)     | 12345.60                                                                |
+/// | Scientific(2)   | 1.23, with a shared ×10⁴ at the end of the axis                         |
+/// | Percent(0)      | 1234560%                                                                |
+/// | SiPrefix(1)     | 12.3k                                                                   |
+/// | Thousands(1)    | 12,345.6                                                                |
+/// | Engineering(2)  | 12.35e3                                                                 |
+/// | Custom(..)      | Any closure, e.g. from `Formatter::custom`                              |
+///
+/// The number in each variant is the number of decimals. With Scientific, all labels share the
+/// same power of ten, and an offset if the values are large compared to the distance between
+/// them, e.g. "×10⁻³ +1×10⁶". This is written at the end of the axis. Labels on time axes are
+/// given the time in seconds since the UNIX epoch, and category labels are never formatted.
+#[derive(Clone)]
+pub enum Formatter {
+    Auto,
+    Decimals(usize),
+    Scientific(usize),
+    Percent(usize),
+    SiPrefix(usize),
+    Thousands(usize),
+    Engineering(usize),
+    Custom(Rc<dyn Fn(f64) -> String>),
+}
+
+impl fmt::Debug for Formatter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Formatter::Auto => write!(f, "Auto"),
+            Formatter::Decimals(decimals) => write!(f, "Decimals({})", decimals),
+            Formatter::Scientific(decimals) => write!(f, "Scientific({})", decimals),
+            Formatter::Percent(decimals) => write!(f, "Percent({})", decimals),
+            Formatter::SiPrefix(decimals) => write!(f, "SiPrefix({})", decimals),
+            Formatter::Thousands(decimals) => write!(f, "Thousands({})", decimals),
+            Formatter::Engineering(decimals) => write!(f, "Engineering({})", decimals),
+            Formatter::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Formatter::Auto
+    }
+}
+
+impl Formatter {
+    /// Create and return a formatter that writes every tick label with the given closure
+    pub fn custom<F>(format: F) -> Formatter
+    where
+        F: Fn(f64) -> String + 'static,
+    {
+        Formatter::Custom(Rc::new(format))
+    }
+
+    /// Whether or not this is the default formatter, which leaves the labels of the axis as they
+    /// are
+    pub(crate) fn is_auto(&self) -> bool {
+        match *self {
+            Formatter::Auto => true,
+            _ => false,
+        }
+    }
+
+    /// Return the tick labels of marks at the given data locations, and the text shared by all
+    /// of them, which is empty unless the labels are scientific.
+    pub(crate) fn format(&self, data_locations: &[f64]) -> (Vec<String>, String) {
+        let format_each = |format: &dyn Fn(f64) -> String| -> Vec<String> {
+            data_locations.iter().map(|&val| format(val)).collect()
+        };
+        let labels = match *self {
+            Formatter::Auto => format_each(&utils::prettify),
+            Formatter::Decimals(decimals) => format_each(&|val| fixed(val, decimals)),
+            Formatter::Scientific(decimals) => return scientific(data_locations, decimals),
+            Formatter::Percent(decimals) => {
+                format_each(&|val| format!("{}%", fixed(100.0 * val, decimals)))
+            }
+            Formatter::SiPrefix(decimals) => format_each(&|val| si_prefix(val, decimals)),
+            Formatter::Thousands(decimals) => format_each(&|val| thousands(val, decimals)),
+            Formatter::Engineering(decimals) => format_each(&|val| engineering(val, decimals)),
+            Formatter::Custom(ref format) => format_each(&**format),
+        };
+        (labels, String::new())
+    }
+}
+
+/// Write `val` with the given number of decimals, without a sign if it is rounded to zero
+fn fixed(val: f64, decimals: usize) -> String {
+    let val = if val.abs() < 0.5 * 10.0_f64.powi(-(decimals as i32)) {
+        0.0
+    } else {
+        val
+    };
+    format!("{:.*}", decimals, val)
+}
+
+/// Return `val` as a mantissa and an exponent that is a multiple of three, such that the mantissa
+/// is in `[1, 1000)` after rounding to the given number of decimals
+fn mantissa_with_exponent(val: f64, decimals: usize) -> (f64, i32) {
+    let mut exponent = 3 * (utils::order_of_magnitude(val) as f64 / 3.0).floor() as i32;
+    let mut mantissa = val / 10.0_f64.powi(exponent);
+    let rounding = 10.0_f64.powi(decimals as i32);
+    if (mantissa.abs() * rounding).round() / rounding >= 1000.0 {
+        exponent += 3;
+        mantissa /= 1000.0;
+    }
+    (mantissa, exponent)
+}
+
+/// Write `val` with an SI prefix, e.g. "1.5k" or "20.0µ"
+fn si_prefix(val: f64, decimals: usize) -> String {
+    let (_, exponent) = mantissa_with_exponent(val, decimals);
+    let index = (exponent / 3 + SI_UNIT_INDEX)
+        .max(0)
+        .min(SI_PREFIXES.len() as i32 - 1);
+    let mantissa = val / 10.0_f64.powi(3 * (index - SI_UNIT_INDEX));
+    format!("{}{}", fixed(mantissa, decimals), SI_PREFIXES[index as usize])
+}
+
+/// Write `val` in engineering notation, with an exponent that is a multiple of three
+fn engineering(val: f64, decimals: usize) -> String {
+    let (mantissa, exponent) = mantissa_with_exponent(val, decimals);
+    format!("{}e{}", fixed(mantissa, decimals), exponent)
+}
+
+/// Write `val` with commas between the thousands, e.g. "-1,234,567.89"
+fn thousands(val: f64, decimals: usize) -> String {
+    let written = fixed(val, decimals);
+    let (sign, unsigned) = if written.starts_with('-') {
+        ("-", &written[1..])
+    } else {
+        ("", &written[..])
+    };
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(index) => unsigned.split_at(index),
+        None => (unsigned, ""),
+    };
+    let mut grouped = String::new();
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("{}{}{}", sign, grouped, fraction)
+}
+
+/// Write `val` as a mantissa times a power of ten, without trailing zeros, e.g. "1.5×10⁶"
+fn power_of_ten(val: f64) -> String {
+    let exponent = utils::order_of_magnitude(val);
+    let mantissa = val / 10.0_f64.powi(exponent);
+    let mantissa = (mantissa * 1e6).round() / 1e6;
+    if exponent == 0 {
+        format!("{}", mantissa)
+    } else {
+        format!("{}×10{}", mantissa, scale::superscript(exponent))
+    }
+}
+
+/// Return scientific labels sharing one power of ten, and possibly an offset, together with the
+/// text describing the shared multiplier and offset, e.g. "×10⁻³ +1×10⁶".
+fn scientific(data_locations: &[f64], decimals: usize) -> (Vec<String>, String) {
+    if data_locations.is_empty() {
+        return (Vec::<String>::new(), String::new());
+    }
+    let min = data_locations.iter().cloned().fold(f64::MAX, f64::min);
+    let max = data_locations.iter().cloned().fold(f64::MIN, f64::max);
+    let span = max - min;
+    let largest = min.abs().max(max.abs());
+    let offset = if span > 0.0 && largest / span >= OFFSET_RATIO {
+        let rounding = 10.0_f64.powi(utils::order_of_magnitude(span) + 1);
+        (min / rounding).floor() * rounding
+    } else {
+        0.0
+    };
+    let largest_shifted = data_locations
+        .iter()
+        .map(|val| (val - offset).abs())
+        .fold(0.0, f64::max);
+    let exponent = utils::order_of_magnitude(largest_shifted);
+    let multiplier = 10.0_f64.powi(exponent);
+    let labels = data_locations
+        .iter()
+        .map(|val| fixed((val - offset) / multiplier, decimals))
+        .collect();
+
+    let mut shared_text = Vec::<String>::new();
+    if exponent != 0 {
+        shared_text.push(format!("×10{}", scale::superscript(exponent)));
+    }
+    if offset > 0.0 {
+        shared_text.push(format!("+{}", power_of_ten(offset)));
+    } else if offset < 0.0 {
+        shared_text.push(format!("-{}", power_of_ten(-offset)));
+    }
+    (labels, shared_text.join(" "))
+}
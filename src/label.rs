@@ -1,6 +1,8 @@
 //! Definition of the Label struct
 //!
 
+use std::f64;
+
 use palette::Srgba;
 use cairo::{Context, FontSlant, FontWeight, Matrix, MatrixTrait};
 
@@ -10,8 +12,8 @@ use {coord, shape, text};
 ///
 /// A struct to hold a single label. Each label is some text, centered at `(center_x, center_y)`, and
 /// with a certain angle (with zero at horisontal, and increasing counterclockwise). Every label
-/// have a gap in each direction. This gap is currently only used to display a frame for debug
-/// reasons, but can be useful in the future for spacing.
+/// have a gap in each direction. This gap is used to display a frame for debug reasons, and as
+/// spacing around the label when a figure is fitted with a tight layout.
 ///
 /// Example for a label with angle = 0:
 ///
@@ -126,6 +128,11 @@ impl Label {
         self.local_centroid.set_coordinates(x_coord, y_coord);
     }
 
+    /// Return the centroid, relative to the parent frame
+    pub fn centroid(&self) -> coord::Coord {
+        self.local_centroid.clone()
+    }
+
     pub fn set_frame_gaps(&mut self, left: f64, right: f64, bottom: f64, top: f64) {
         self.rel_left_gap = left;
         self.rel_right_gap = right;
@@ -142,6 +149,18 @@ impl Label {
         self.text.content()
     }
 
+    /// Return the font size
+    pub fn font_size(&self) -> f64 {
+        self.text.font_size()
+    }
+
+    /// Return a rough estimate of the width of the text, in the same units as the font size. The
+    /// exact width is only known when the text is drawn.
+    pub fn estimated_width(&self) -> f64 {
+        // An average character is about half as wide as the font size
+        0.5 * self.text.content().chars().count() as f64 * self.text.font_size()
+    }
+
     #[allow(dead_code)] // TODO: Issue #13
     pub fn rel_left_gap(&self) -> f64 {
         self.rel_left_gap
@@ -177,6 +196,47 @@ impl Label {
         self.scale_size(parent_frame.diag_len())
     }
 
+    /// Return the frame that the label, and the gaps around it, covers in the figure, or `None`
+    /// if the label is empty. The label must be fitted first.
+    pub fn extent(
+        &self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) -> Option<shape::Rectangle> {
+        if self.text.content() == "" {
+            return None;
+        }
+        let (text_width, text_height) =
+            self.text.extents(cr, fig_rel_height, fig_rel_width, self.angle);
+        let corners = [
+            (-0.5 * text_width - self.rel_left_gap, -0.5 * text_height - self.rel_bottom_gap),
+            (0.5 * text_width + self.rel_right_gap, -0.5 * text_height - self.rel_bottom_gap),
+            (0.5 * text_width + self.rel_right_gap, 0.5 * text_height + self.rel_top_gap),
+            (-0.5 * text_width - self.rel_left_gap, 0.5 * text_height + self.rel_top_gap),
+        ];
+
+        // The frame of a rotated label is the bounding box of its rotated corners
+        let (sin, cos) = self.angle.sin_cos();
+        let mut frame = shape::Rectangle::with_boundaries(
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        );
+        for &(x, y) in &corners {
+            let x_rot = self.global_centroid.x() + x * cos - y * sin;
+            let y_rot = self.global_centroid.y() + x * sin + y * cos;
+            frame.set_boundaries(
+                frame.left().min(x_rot),
+                frame.right().max(x_rot),
+                frame.bottom().min(y_rot),
+                frame.top().max(y_rot),
+            );
+        }
+        Some(frame)
+    }
+
     pub fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
         cr.move_to(self.global_centroid.x(), self.global_centroid.y());
 
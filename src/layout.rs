@@ -0,0 +1,274 @@
+//! Definition of the Grid struct, which places plots in the cells of a figure, and of helpers
+//! for the tight layout of a figure
+//!
+
+use std::f64;
+use failure::{err_msg, Error};
+
+use cairo::{Context, Format, ImageSurface};
+
+use shape;
+
+/// Free space kept around the texts of a figure with a tight layout, relative to the figure
+pub(crate) const TIGHT_LAYOUT_PADDING: f64 = 0.01;
+
+/// Number of times a plot is fitted and measured in a tight layout. The texts are scaled with the
+/// canvas, so the canvas frame is found by repeated adjustments.
+pub(crate) const TIGHT_LAYOUT_ITERATIONS: usize = 4;
+
+/// A cell in the figure grid. Rows are counted from the top, and columns from the left, starting
+/// at zero. A cell can span multiple rows and columns.
+#[derive(Clone, Debug)]
+pub(crate) struct GridCell {
+    row: usize,
+    col: usize,
+    row_span: usize,
+    col_span: usize,
+}
+
+impl GridCell {
+    pub(crate) fn new(row: usize, col: usize, row_span: usize, col_span: usize) -> GridCell {
+        GridCell {
+            row: row,
+            col: col,
+            row_span: row_span,
+            col_span: col_span,
+        }
+    }
+
+    /// Whether the cells cover the same grid columns
+    pub(crate) fn is_in_columns_of(&self, other: &GridCell) -> bool {
+        self.col == other.col && self.col_span == other.col_span
+    }
+
+    /// Whether the cells cover the same grid rows
+    pub(crate) fn is_in_rows_of(&self, other: &GridCell) -> bool {
+        self.row == other.row && self.row_span == other.row_span
+    }
+
+    /// Return the row below the cell
+    pub(crate) fn end_row(&self) -> usize {
+        self.row + self.row_span
+    }
+
+    pub(crate) fn col(&self) -> usize {
+        self.col
+    }
+}
+
+/// A grid of `num_rows x num_cols` cells, dividing a figure into plot frames. The height of the
+/// rows and the width of the columns are relative to each other by their ratios, and there is a
+/// spacing between neighbouring cells.
+#[derive(Clone, Debug)]
+pub(crate) struct Grid {
+    num_rows: usize,
+    num_cols: usize,
+    height_ratios: Vec<f64>,
+    width_ratios: Vec<f64>,
+    horizontal_spacing: f64,
+    vertical_spacing: f64,
+    share_x: bool,
+    share_y: bool,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Grid {
+    pub(crate) fn new() -> Grid {
+        Grid {
+            num_rows: 1,
+            num_cols: 1,
+            height_ratios: Vec::<f64>::new(),
+            width_ratios: Vec::<f64>::new(),
+            horizontal_spacing: 0.01,
+            vertical_spacing: 0.01,
+            share_x: false,
+            share_y: false,
+        }
+    }
+
+    pub(crate) fn set_size(&mut self, num_rows: usize, num_cols: usize) {
+        self.num_rows = num_rows;
+        self.num_cols = num_cols;
+    }
+
+    pub(crate) fn set_height_ratios(&mut self, ratios: &[f64]) {
+        self.height_ratios = ratios.to_vec();
+    }
+
+    pub(crate) fn set_width_ratios(&mut self, ratios: &[f64]) {
+        self.width_ratios = ratios.to_vec();
+    }
+
+    pub(crate) fn set_spacing(&mut self, horizontal: f64, vertical: f64) {
+        self.horizontal_spacing = horizontal;
+        self.vertical_spacing = vertical;
+    }
+
+    pub(crate) fn set_share_x(&mut self, val: bool) {
+        self.share_x = val;
+    }
+
+    pub(crate) fn set_share_y(&mut self, val: bool) {
+        self.share_y = val;
+    }
+
+    /// Whether the plots in the same columns share their horizontal data range
+    pub(crate) fn share_x(&self) -> bool {
+        self.share_x
+    }
+
+    /// Whether the plots in the same rows share their vertical data range
+    pub(crate) fn share_y(&self) -> bool {
+        self.share_y
+    }
+
+    /// Return the frame of a cell, inside of the area that is covered by the grid
+    pub(crate) fn cell_frame(
+        &self,
+        cell: &GridCell,
+        area: &shape::Rectangle,
+    ) -> Result<shape::Rectangle, Error> {
+        if self.num_rows == 0 || self.num_cols == 0 {
+            return Err(err_msg("The figure grid must have at least one row and one column"));
+        }
+        if cell.row_span == 0 || cell.col_span == 0 {
+            return Err(err_msg("A plot must span at least one row and one column"));
+        }
+        if cell.row + cell.row_span > self.num_rows || cell.col + cell.col_span > self.num_cols {
+            return Err(err_msg(format!(
+                "Cell at row {} and column {}, spanning {} x {}, is outside of the {} x {} grid",
+                cell.row,
+                cell.col,
+                cell.row_span,
+                cell.col_span,
+                self.num_rows,
+                self.num_cols
+            )));
+        }
+
+        let heights = track_lengths(
+            &self.height_ratios,
+            self.num_rows,
+            area.height(),
+            self.vertical_spacing,
+        )?;
+        let widths = track_lengths(
+            &self.width_ratios,
+            self.num_cols,
+            area.width(),
+            self.horizontal_spacing,
+        )?;
+
+        let top = area.top() - heights[..cell.row].iter().sum::<f64>()
+            - cell.row as f64 * self.vertical_spacing;
+        let bottom = top - heights[cell.row..cell.row + cell.row_span].iter().sum::<f64>()
+            - (cell.row_span - 1) as f64 * self.vertical_spacing;
+        let left = area.left() + widths[..cell.col].iter().sum::<f64>()
+            + cell.col as f64 * self.horizontal_spacing;
+        let right = left + widths[cell.col..cell.col + cell.col_span].iter().sum::<f64>()
+            + (cell.col_span - 1) as f64 * self.horizontal_spacing;
+
+        let mut frame = shape::Rectangle::new();
+        frame.set_boundaries(left, right, bottom, top);
+        Ok(frame)
+    }
+}
+
+/// Divide a length into the lengths of the rows or columns of the grid, after subtracting the
+/// spacing between them. Without ratios, all rows or columns are equally sized.
+fn track_lengths(
+    ratios: &[f64],
+    num_tracks: usize,
+    total_length: f64,
+    spacing: f64,
+) -> Result<Vec<f64>, Error> {
+    let ratios = if ratios.is_empty() {
+        vec![1.0; num_tracks]
+    } else {
+        ratios.to_vec()
+    };
+    if ratios.len() != num_tracks {
+        return Err(err_msg(format!(
+            "Got {} grid ratios, but the grid has {} rows or columns",
+            ratios.len(),
+            num_tracks
+        )));
+    }
+    if ratios.iter().any(|ratio| !(ratio.is_finite() && *ratio > 0.0)) {
+        return Err(err_msg("Grid ratios must be positive"));
+    }
+
+    let available_length = total_length - (num_tracks - 1) as f64 * spacing;
+    if available_length <= 0.0 {
+        return Err(err_msg("The grid spacing leaves no room for the plots"));
+    }
+
+    let ratio_sum: f64 = ratios.iter().sum();
+    Ok(ratios
+        .iter()
+        .map(|ratio| available_length * ratio / ratio_sum)
+        .collect())
+}
+
+/// Return the horizontal or vertical data range that is shared by data frames. A side that is set
+/// by the user in any of the frames, i.e. marked as updated, is only decided by those frames, such
+/// that a range set on one plot applies to all of them. The other sides cover all of the frames.
+pub(crate) fn shared_range(frames: &[&shape::Rectangle], is_horizontal: bool) -> (f64, f64) {
+    let sides: Vec<(f64, bool, f64, bool)> = frames
+        .iter()
+        .map(|frame| {
+            if is_horizontal {
+                (frame.left(), frame.is_left_updated(), frame.right(), frame.is_right_updated())
+            } else {
+                (frame.bottom(), frame.is_bottom_updated(), frame.top(), frame.is_top_updated())
+            }
+        })
+        .collect();
+    let any_min_set = sides.iter().any(|side| side.1);
+    let any_max_set = sides.iter().any(|side| side.3);
+    let shared_min = sides
+        .iter()
+        .filter(|side| side.1 || !any_min_set)
+        .fold(f64::INFINITY, |acc, side| acc.min(side.0));
+    let shared_max = sides
+        .iter()
+        .filter(|side| side.3 || !any_max_set)
+        .fold(f64::NEG_INFINITY, |acc, side| acc.max(side.2));
+    (shared_min, shared_max)
+}
+
+/// Return a context where texts are measured as they will be drawn on a figure of the given size
+pub(crate) fn measuring_context(width: usize, height: usize) -> Result<Context, Error> {
+    // Text extents depend on the transformation, and not on the size of the surface
+    let surface = match ImageSurface::create(Format::ARgb32, 1, 1) {
+        Ok(val) => val,
+        Err(msg) => return Err(err_msg(format!("{:?}", msg))),
+    };
+    let cr = Context::new(&surface);
+    cr.scale(width as f64, height as f64);
+    Ok(cr)
+}
+
+/// Return the smallest frame that covers all of the frames
+pub(crate) fn bounding_frame(frames: &[shape::Rectangle]) -> shape::Rectangle {
+    let mut bounds = shape::Rectangle::with_boundaries(
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    );
+    for frame in frames {
+        bounds.set_boundaries(
+            bounds.left().min(frame.left()),
+            bounds.right().max(frame.right()),
+            bounds.bottom().min(frame.bottom()),
+            bounds.top().max(frame.top()),
+        );
+    }
+    bounds
+}
@@ -0,0 +1,456 @@
+//! Definition of the Legend struct
+//!
+
+use palette::Srgba;
+
+use cairo::{Context, FontSlant, FontWeight};
+
+use {chart, color, coord, label, shape};
+
+/// Determines where the legend is placed, relative to the canvas.
+///
+/// | Variant         | Placement                                                               |
+/// | --------------- | ----------------------------------------------------------------------- |
+/// | Best            | The inside corner that covers the fewest data points (default).         |
+/// | UpperRight      | In the upper right corner of the canvas.                                |
+/// | UpperLeft       | In the upper left corner of the canvas.                                 |
+/// | LowerLeft       | In the lower left corner of the canvas.                                 |
+/// | LowerRight      | In the lower right corner of the canvas.                                |
+/// | OutsideRight    | To the right of the canvas, at its top. The canvas is narrowed for it.  |
+///
+/// With Best, the data points of lines and scatter charts are taken into account, and the first
+/// corner in the order above is used when several corners cover equally few points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LegendLocation {
+    Best,
+    UpperRight,
+    UpperLeft,
+    LowerLeft,
+    LowerRight,
+    OutsideRight,
+}
+
+impl Default for LegendLocation {
+    fn default() -> Self {
+        LegendLocation::Best
+    }
+}
+
+/// ## Legend
+///
+/// A legend lists the charts of a plot that have a label, e.g. from `Line::set_label()`, with a
+/// sample of each chart next to its label: the line or the points of lines and scatter charts, and
+/// a filled patch for charts that cover an area, such as bars. The entries are listed in the order
+/// the charts were added, down each column, and then across the columns.
+///
+/// Sizes are relative to the canvas, as for the labels of the axes.
+#[derive(Clone, Debug)]
+pub struct Legend {
+    location: LegendLocation,
+    num_columns: usize,
+    display_frame: bool,
+    frame_color: color::Color,
+    frame_width: f64,
+    background_color: color::Color,
+    label_style: label::Label,
+    labels: Vec<label::Label>,
+    sample_frames: Vec<shape::Rectangle>,
+    global_frame: shape::Rectangle,
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Legend {
+    /// Create and return a new legend
+    pub fn new() -> Legend {
+        let mut label_style = label::Label::new();
+        label_style.set_font_size(0.02);
+        label_style.set_color_internal(color::CustomColor::TickLabel.as_srgba());
+        let mut background_color = color::Color::with_custom(&color::CustomColor::CanvasBackground);
+        let mut background = background_color.as_srgba();
+        background.alpha = 0.8;
+        background_color.set_color(background);
+        Legend {
+            location: LegendLocation::default(),
+            num_columns: 1,
+            display_frame: true,
+            frame_color: color::Color::with_custom(&color::CustomColor::CanvasBorder),
+            frame_width: 0.001,
+            background_color: background_color,
+            label_style: label_style,
+            labels: Vec::<label::Label>::new(),
+            sample_frames: Vec::<shape::Rectangle>::new(),
+            global_frame: shape::Rectangle::new(),
+        }
+    }
+
+    // ----------------- PLACEMENT ----------------------------------------- //
+
+    /// Set where the legend is placed. See the LegendLocation enum for reference.
+    pub fn set_location(&mut self, location: LegendLocation) -> &mut Self {
+        self.location = location;
+        self
+    }
+
+    /// Set the number of columns the entries are listed in. The default is one column.
+    pub fn set_num_columns(&mut self, val: usize) -> &mut Self {
+        self.num_columns = val.max(1);
+        self
+    }
+
+    // ----------------- APPEARANCE ---------------------------------------- //
+
+    /// Whether or not to draw a frame around the legend
+    pub fn display_frame(&mut self, val: bool) -> &mut Self {
+        self.display_frame = val;
+        self
+    }
+
+    /// Set the line width of the legend frame
+    pub fn set_frame_width(&mut self, val: f64) -> &mut Self {
+        self.frame_width = val;
+        self
+    }
+
+    /// Set the color of the legend frame
+    pub fn set_frame_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.frame_color.set_color_custom(color);
+        self
+    }
+
+    /// Set the color of the legend frame
+    pub fn set_frame_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        self.frame_color.set_color_rgb(red, green, blue);
+        self
+    }
+
+    /// Set the color of the legend frame
+    pub fn set_frame_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        self.frame_color.set_color_rgba(red, green, blue, alpha);
+        self
+    }
+
+    /// Set the color of the legend frame
+    pub fn set_frame_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        self.frame_color.set_color_rgb_u8(red, green, blue);
+        self
+    }
+
+    /// Set the color of the legend frame
+    pub fn set_frame_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        self.frame_color.set_color_rgba_u8(red, green, blue, alpha);
+        self
+    }
+
+    /// Set the color of the legend frame
+    pub fn set_frame_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.frame_color.set_color_html(color);
+        self
+    }
+
+    /// Set the opacity of the legend background, from 0.0 (transparent) to 1.0 (opaque). The
+    /// default is 0.8, such that data behind the legend can still be seen.
+    pub fn set_background_alpha(&mut self, val: f32) -> &mut Self {
+        let mut background = self.background_color.as_srgba();
+        background.alpha = val.max(0.0).min(1.0);
+        self.background_color.set_color(background);
+        self
+    }
+
+    // ----------------- LABELS -------------------------------------------- //
+
+    /// Set the font size of the labels
+    pub fn set_font_size(&mut self, val: f64) -> &mut Self {
+        self.label_style.set_font_size(val);
+        self
+    }
+
+    /// Set the font slant of the labels
+    pub fn set_font_slant(&mut self, font_slant: FontSlant) -> &mut Self {
+        self.label_style.set_font_slant(font_slant);
+        self
+    }
+
+    /// Set the font weight of the labels
+    pub fn set_font_weight(&mut self, font_weight: FontWeight) -> &mut Self {
+        self.label_style.set_font_weight(font_weight);
+        self
+    }
+
+    /// Set the color of the labels
+    pub fn set_font_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.set_font_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the labels
+    pub fn set_font_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.set_font_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the labels
+    pub fn set_font_color_rgba(&mut self, red: f32, green: f32, blue: f32, alpha: f32) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.set_font_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the labels
+    pub fn set_font_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.set_font_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the labels
+    pub fn set_font_color_rgba_u8(&mut self, red: u8, green: u8, blue: u8, alpha: u8) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.set_font_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the color of the labels
+    pub fn set_font_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.set_font_color_internal(color.as_srgba());
+        self
+    }
+
+    fn set_font_color_internal(&mut self, color: Srgba) {
+        self.label_style.set_color_internal(color);
+    }
+
+    // ----------------- GENERAL INTERNAL ---------------------------------- //
+
+    /// Whether or not the legend is placed outside of the canvas, to the right of it
+    pub(crate) fn is_outside(&self) -> bool {
+        self.location == LegendLocation::OutsideRight
+    }
+
+    /// Return the labels of the charts that are listed in the legend, styled as the legend labels
+    fn chart_labels(&self, charts: &[chart::Chart]) -> Vec<label::Label> {
+        charts
+            .iter()
+            .filter_map(|chart| chart.legend_label())
+            .map(|content| {
+                let mut label = self.label_style.clone();
+                label.set_content(&content);
+                label
+            })
+            .collect()
+    }
+
+    /// Return the width and the height of the legend, relative to the figure, with the labels of
+    /// the given charts, on a canvas with the given diagonal length. The second return value is
+    /// the width of every column.
+    fn extent(&self, labels: &[label::Label], canvas_diag_len: f64) -> (f64, f64, Vec<f64>) {
+        let text_height = self.label_style.font_size() * canvas_diag_len;
+        let (padding, sample_width, sample_gap, row_height) = Legend::spacing(text_height);
+        let num_rows = Legend::num_rows(labels.len(), self.num_columns);
+        let column_widths: Vec<f64> = labels
+            .chunks(num_rows.max(1))
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|label| label.estimated_width() * canvas_diag_len)
+                    .fold(0.0, f64::max) + sample_width + sample_gap
+            })
+            .collect();
+        let width = 2.0 * padding + column_widths.iter().sum::<f64>()
+            + (column_widths.len().max(1) - 1) as f64 * 2.0 * sample_gap;
+        let height = 2.0 * padding + num_rows as f64 * row_height;
+        (width, height, column_widths)
+    }
+
+    /// Return the padding inside the frame, the width of a chart sample, the gap between a sample
+    /// and its label, and the height of a row, for the given text height
+    fn spacing(text_height: f64) -> (f64, f64, f64, f64) {
+        (0.6 * text_height, 2.5 * text_height, 0.6 * text_height, 1.5 * text_height)
+    }
+
+    /// Return the number of rows needed for `num_entries` entries in `num_columns` columns
+    fn num_rows(num_entries: usize, num_columns: usize) -> usize {
+        (num_entries + num_columns - 1) / num_columns
+    }
+
+    /// Return the width of the legend, relative to the figure, when it lists the given charts on a
+    /// canvas with the given diagonal length. This is used to make room for a legend outside of
+    /// the canvas.
+    pub(crate) fn width(&self, charts: &[chart::Chart], canvas_diag_len: f64) -> f64 {
+        let labels = self.chart_labels(charts);
+        if labels.is_empty() {
+            return 0.0;
+        }
+        self.extent(&labels, canvas_diag_len).0
+    }
+
+    /// Return the distance between the legend and the edges of the canvas, relative to the figure
+    pub(crate) fn margin(canvas_diag_len: f64) -> f64 {
+        0.015 * canvas_diag_len
+    }
+
+    /// Return the frame of a legend with the given width and height at the given location, given
+    /// the global frame of the canvas
+    fn frame_at(
+        location: LegendLocation,
+        width: f64,
+        height: f64,
+        canvas_frame: &shape::Rectangle,
+    ) -> shape::Rectangle {
+        let margin = Legend::margin(canvas_frame.diag_len());
+        let (left, bottom) = match location {
+            LegendLocation::UpperLeft => (
+                canvas_frame.left() + margin,
+                canvas_frame.top() - margin - height,
+            ),
+            LegendLocation::LowerLeft => (
+                canvas_frame.left() + margin,
+                canvas_frame.bottom() + margin,
+            ),
+            LegendLocation::LowerRight => (
+                canvas_frame.right() - margin - width,
+                canvas_frame.bottom() + margin,
+            ),
+            LegendLocation::OutsideRight => {
+                (canvas_frame.right() + margin, canvas_frame.top() - height)
+            }
+            LegendLocation::UpperRight | LegendLocation::Best => (
+                canvas_frame.right() - margin - width,
+                canvas_frame.top() - margin - height,
+            ),
+        };
+        shape::Rectangle::with_boundaries(left, left + width, bottom, bottom + height)
+    }
+
+    /// Return the inside corner where a legend of the given size covers the fewest data points
+    fn best_location(
+        charts: &[chart::Chart],
+        width: f64,
+        height: f64,
+        canvas_frame: &shape::Rectangle,
+    ) -> LegendLocation {
+        let coords: Vec<coord::Coord> = charts
+            .iter()
+            .flat_map(|chart| chart.canvas_coords())
+            .collect();
+        let mut best_location = LegendLocation::UpperRight;
+        let mut fewest_covered = usize::max_value();
+        for &location in &[
+            LegendLocation::UpperRight,
+            LegendLocation::UpperLeft,
+            LegendLocation::LowerLeft,
+            LegendLocation::LowerRight,
+        ] {
+            let frame = Legend::frame_at(location, width, height, canvas_frame);
+            let num_covered = coords
+                .iter()
+                .filter(|coord| {
+                    coord.x() >= frame.left() && coord.x() <= frame.right()
+                        && coord.y() >= frame.bottom() && coord.y() <= frame.top()
+                })
+                .count();
+            if num_covered < fewest_covered {
+                fewest_covered = num_covered;
+                best_location = location;
+            }
+        }
+        best_location
+    }
+
+    /// Place the legend on the canvas, with an entry for every chart with a label. This is called
+    /// after the charts are fitted, such that their data points are known.
+    pub(crate) fn fit(&mut self, charts: &[chart::Chart], canvas_frame: &shape::Rectangle) {
+        self.labels = self.chart_labels(charts);
+        self.sample_frames.clear();
+        if self.labels.is_empty() {
+            return;
+        }
+        let canvas_diag_len = canvas_frame.diag_len();
+        let (width, height, column_widths) = self.extent(&self.labels, canvas_diag_len);
+        let location = match self.location {
+            LegendLocation::Best => Legend::best_location(charts, width, height, canvas_frame),
+            location => location,
+        };
+        self.global_frame = Legend::frame_at(location, width, height, canvas_frame);
+        self.global_frame.set_color_internal(self.frame_color.as_srgba());
+        self.global_frame
+            .set_border_thickness(self.frame_width * canvas_diag_len);
+        self.global_frame.display_border(self.display_frame);
+
+        let text_height = self.label_style.font_size() * canvas_diag_len;
+        let (padding, sample_width, sample_gap, row_height) = Legend::spacing(text_height);
+        let num_rows = Legend::num_rows(self.labels.len(), self.num_columns);
+        let mut column_left = self.global_frame.left() + padding;
+        for (column, column_labels) in self.labels.chunks_mut(num_rows).enumerate() {
+            for (row, label) in column_labels.iter_mut().enumerate() {
+                let row_top = self.global_frame.top() - padding - row as f64 * row_height;
+                let row_middle = row_top - 0.5 * row_height;
+                self.sample_frames.push(shape::Rectangle::with_boundaries(
+                    column_left,
+                    column_left + sample_width,
+                    row_top - row_height,
+                    row_top,
+                ));
+                // Labels are centered on their centroid, which is relative to the canvas
+                let label_x = column_left + sample_width + sample_gap
+                    + 0.5 * label.estimated_width() * canvas_diag_len;
+                label.set_centroid(
+                    (label_x - canvas_frame.left()) / canvas_frame.width(),
+                    (row_middle - canvas_frame.bottom()) / canvas_frame.height(),
+                );
+                label.fit(canvas_frame);
+            }
+            column_left += column_widths[column] + 2.0 * sample_gap;
+        }
+    }
+
+    /// Return the frame of the legend, relative to the figure. The legend must be fitted first.
+    pub(crate) fn frame(&self) -> shape::Rectangle {
+        self.global_frame.clone()
+    }
+
+    /// Draw the legend, with samples of the given charts. These are the charts the legend was
+    /// fitted to, with the colors they are drawn in.
+    pub(crate) fn draw(
+        &self,
+        cr: &Context,
+        charts: &[chart::Chart],
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) {
+        if self.labels.is_empty() {
+            return;
+        }
+        let bg_color = self.background_color.as_srgba();
+        cr.set_source_rgba(
+            f64::from(bg_color.red),
+            f64::from(bg_color.green),
+            f64::from(bg_color.blue),
+            f64::from(bg_color.alpha),
+        );
+        cr.rectangle(
+            self.global_frame.left(),
+            self.global_frame.bottom(),
+            self.global_frame.width(),
+            self.global_frame.height(),
+        );
+        cr.fill();
+        self.global_frame.draw(cr, fig_rel_height, fig_rel_width);
+
+        let labelled_charts = charts
+            .iter()
+            .filter(|chart| chart.legend_label().is_some());
+        for ((chart, sample_frame), label) in labelled_charts
+            .zip(self.sample_frames.iter())
+            .zip(self.labels.iter())
+        {
+            chart.draw_legend_sample(cr, sample_frame, fig_rel_height, fig_rel_width);
+            label.draw(cr, fig_rel_height, fig_rel_width);
+        }
+    }
+}
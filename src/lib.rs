@@ -148,11 +148,17 @@
 //! #### [`Figure`](figure/struct.Figure.html)
 //! The main structure, and can contain multiple plots. You can determine the size of the figure,
 //! and also save the figure as a `.png` image.
+//! Plots are either placed by hand with `Plot::set_local_frame()`, or in the cells of a grid set
+//! with `Figure::set_grid()`, where they can span multiple rows and columns. Plots in the same
+//! grid columns or rows can share their data ranges, see `Figure::set_grid_shared_x()`.
+//! With `Figure::set_tight_layout()`, the canvases are made as large as their measured titles,
+//! axis labels and tick labels allow.
 //!
 //! #### [`Plot`](plot/struct.Plot.html)
 //! A structure that holds the visual that is to be displayed. It contains a canvas (and can
 //! contain more, but this is rarely needed), and some space around the canvas, to make space for
-//! titles, legends, labels, and tick-labels.
+//! titles, legends, labels, and tick-labels. A colorbar beside the canvas shows the colormap of an
+//! image or a heatmap, see `Plot::set_colorbar()`.
 //!
 //! #### [`Canvas`](plot/struct.Canvas.html)
 //! The structure where the charts are drawn on. It can contain multiple charts and multiple axes.
@@ -168,18 +174,23 @@
 //! | -------------- | --------- |
 //! | Scatter chart  | Yes       |
 //! | Line chart     | Yes       |
-//! | Bar chart      | No        |
-//! | Histogram      | No        |
-//! | Matrix heatmap | No        |
-//! | Image          | No        |
-//! | Filled curves  | No        |
-//! | Box plot       | No        |
-//! | Pie chart      | No        |
-//!
-//! #### [`Axis`](axis/struct.Axis.html)
-//! An axis is used to give context to the chart, as it displays a reference to the data points. It
-//! is intended that a single canvas can have multiple axes, but currently, it is the safest to
-//! only use the two default (ordinate and abcissa).
+//! | Bar chart      | Yes       |
+//! | Histogram      | Yes       |
+//! | Matrix heatmap | Yes       |
+//! | Image          | Yes       |
+//! | Filled curves  | Yes       |
+//! | Box plot       | Yes       |
+//! | Pie chart      | Yes       |
+//! | Error bars     | Yes       |
+//!
+//! #### [`Axis`](struct.Axis.html)
+//! An axis is used to give context to the chart, as it displays a reference to the data points.
+//! Each canvas has a default horizontal and vertical axis (abcissa and ordinate) along its edges.
+//! These can be placed at zero, at another data value, or outward from the canvas, see
+//! `AxisPosition`, and their lines, the spines, can be hidden or completed with a box frame.
+//! Additional axes can be added with `Plot::add_axis()`, e.g. through the origin or along the top
+//! of the canvas. These are defined by their start and end point in data coordinates, and have
+//! their own marks, label and tick style.
 //!
 //! #### [`Mark`](mark/struct.Mark.html)
 //! A mark is the location on a axis where a data point reference can be placed. It is used
@@ -198,14 +209,16 @@
 //! ```
 //!
 //! **Note**
-//! It is possible to set min and max data ranges, but they will (currently) be
+//! It is possible to set min and max data ranges, but they will by default be
 //! considered as ``soft suggestions'', as the above aestetic rules will overrule the exact
-//! suggestion.
+//! suggestion. With `Plot::set_hard_limits()`, the ranges are used exactly as they are, marks are
+//! only placed inside them, and data outside of them is clipped at the canvas boundary.
 //!
 //! #### [`Tick`](mark/struct.Tick.html)
 //! A small visualisation of the location of a mark, and is labeled with the data value of the
-//! mark. Currently, only one type of tick is supported, but minor and major ticks will be
-//! implemented sometime in the future.
+//! mark. Axes have major and minor marks, each placed by a [`Locator`](enum.Locator.html). Minor
+//! marks are unlabeled, and have shorter ticks by default. The labels are written by a
+//! [`Formatter`](enum.Formatter.html), e.g. with a fixed number of decimals or as percentages.
 //!
 //! #### [`GridLine`](mark/struct.GridLine.html)
 //! Much the same as a tick, but stretches across the whole canvas, perpendicular on the axis it
@@ -213,6 +226,7 @@
 //!
 //!
 extern crate cairo;
+extern crate chrono;
 extern crate failure;
 extern crate gio;
 extern crate gtk;
@@ -222,8 +236,19 @@ extern crate palette;
 pub use view::View;
 pub use figure::Figure;
 pub use plot::Plot;
-pub use chart::{Chart, Line, LineStyle, Scatter, StrokeStyle};
-pub use color::{CustomColor, HtmlColor};
+pub use axis::{Axis, AxisPosition};
+pub use chart::{AxisPair, Bar, BarLayout, BarOrientation, Bins, BoxOrientation, BoxPlot, Chart,
+                ErrorBar, FillBetween, Heatmap, Histogram, HistogramStyle, Image,
+                ImageInterpolation, ImageOrigin, Line, LineStyle, Pie, PieDirection, Scatter,
+                StrokeStyle, Whiskers};
+pub use color::{Colormap, CustomColor, HtmlColor};
+pub use scale::{AxisTransform, Scale};
+pub use category::CategoryOrder;
+pub use locator::Locator;
+pub use formatter::Formatter;
+pub use legend::{Legend, LegendLocation};
+pub use colorbar::{Colorbar, ColorbarLocation};
+pub use datetime::TimeCoord;
 
 mod view;
 mod figure;
@@ -236,5 +261,13 @@ mod utils;
 mod shape;
 mod coord;
 mod label;
+mod scale;
+mod datetime;
+mod category;
+mod locator;
+mod formatter;
+mod legend;
+mod colorbar;
+mod layout;
 mod text;
 pub mod color;
@@ -0,0 +1,242 @@
+//! Definition of the Locator enum, which determines where the marks of an axis are placed
+//!
+
+use std::f64;
+use failure::{err_msg, Error};
+
+use utils;
+
+/// The largest number of marks a locator places on an axis, e.g. with a tiny fixed step
+const MAX_NUM_MARKS: usize = 1000;
+
+/// A small tolerance, relative to the step between marks, such that data exactly at a mark does
+/// not add an extra mark
+const STEP_TOLERANCE: f64 = 1e-9;
+
+/// Determines where the marks, and hence the ticks and gridlines, of an axis are placed.
+///
+/// | Variant         | Marks                                                                   |
+/// | --------------- | ----------------------------------------------------------------------- |
+/// | Auto(n)         | About n marks at multiples of 1, 2 or 5 times a power of ten (default). |
+/// | MaxN(n)         | At most n marks at multiples of 1, 2 or 5 times a power of ten.         |
+/// | FixedStep(s)    | Every integer multiple of s.                                            |
+/// | Explicit(..)    | The given data locations.                                               |
+/// | LogDecades      | Every integer power of the base. Only on logarithmic axes.              |
+/// | Subdivisions(n) | n equal intervals between neighbouring major marks, or across the axis. |
+///
+/// On a linear axis, Auto, MaxN and FixedStep extend the data range to the nearest marks outside
+/// of it, unless the limits are hard. The other locators leave the data range as it is. On time
+/// axes, data locations and steps are in seconds, and on logarithmic, time and other non-linear
+/// axes, Auto and MaxN place the marks that suit the axis, e.g. at calendar units.
+///
+/// Minor marks are unlabeled, and are placed by a locator of their own. Subdivisions is mostly
+/// useful for these, e.g. `Subdivisions(5)` places four minor marks between every two major marks.
+#[derive(Clone, Debug)]
+pub enum Locator {
+    Auto(usize),
+    MaxN(usize),
+    FixedStep(f64),
+    Explicit(Vec<f64>),
+    LogDecades,
+    Subdivisions(usize),
+}
+
+impl Default for Locator {
+    fn default() -> Self {
+        Locator::Auto(6)
+    }
+}
+
+impl Locator {
+    /// Create and return a locator placing marks at the given data locations
+    pub fn explicit(locations: &[f64]) -> Locator {
+        Locator::Explicit(locations.to_vec())
+    }
+
+    /// Return the desired number of marks, or None if the locator does not place marks by number
+    pub(crate) fn num_marks(&self) -> Option<usize> {
+        match *self {
+            Locator::Auto(num_marks) | Locator::MaxN(num_marks) => Some(num_marks),
+            _ => None,
+        }
+    }
+
+    /// Whether or not the data range of a linear axis is extended to the outermost marks
+    pub(crate) fn extends_range(&self) -> bool {
+        match *self {
+            Locator::Auto(_) | Locator::MaxN(_) | Locator::FixedStep(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Return the data locations of the marks of a linear axis spanning `[data_min, data_max]`,
+    /// in increasing order. When the locator extends the data range, the first location is at or
+    /// below `data_min`, and the last location at or above `data_max`.
+    pub(crate) fn locations(&self, data_min: f64, data_max: f64) -> Result<Vec<f64>, Error> {
+        if !(data_min.is_finite() && data_max.is_finite()) {
+            return Err(err_msg(format!(
+                "Could not place marks on the data range [{}, {}]",
+                data_min, data_max
+            )));
+        }
+        match *self {
+            Locator::Auto(num_marks) => auto_locations(data_min, data_max, num_marks),
+            Locator::MaxN(num_marks) => max_n_locations(data_min, data_max, num_marks),
+            Locator::FixedStep(step) => {
+                if !(step > 0.0 && step.is_finite()) {
+                    return Err(err_msg(format!(
+                        "The step between marks must be positive, but it is {}",
+                        step
+                    )));
+                }
+                stepped_locations(data_min, data_max, step)
+            }
+            Locator::Explicit(ref locations) => {
+                let mut locations: Vec<f64> = locations
+                    .iter()
+                    .cloned()
+                    .filter(|location| location.is_finite())
+                    .collect();
+                locations.sort_by(|a, b| a.partial_cmp(b).expect("Unexpected NaN"));
+                locations.dedup();
+                Ok(locations)
+            }
+            Locator::LogDecades => Err(err_msg(
+                "Marks can only be placed at the powers of the base on logarithmic axes",
+            )),
+            Locator::Subdivisions(num_intervals) => {
+                let num_intervals = num_intervals.max(1);
+                Ok((0..num_intervals + 1)
+                    .map(|k| {
+                        utils::map_range(k as f64, 0.0, num_intervals as f64, data_min, data_max)
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+/// Return `num_intervals - 1` locations evenly spaced between every two neighbouring major
+/// locations. Before the first and after the last major location, the neighbouring interval is
+/// repeated, such that there are minor locations all the way to the ends of the axis. On
+/// logarithmic axes, the intervals are repeated by ratio in stead of by difference.
+pub(crate) fn subdivisions(
+    major_locations: &[f64],
+    num_intervals: usize,
+    is_log: bool,
+) -> Vec<f64> {
+    if major_locations.len() < 2 || num_intervals < 2 {
+        return Vec::<f64>::new();
+    }
+    let extend = |from: f64, to: f64| if is_log { to * to / from } else { 2.0 * to - from };
+    let num_majors = major_locations.len();
+    let mut bounds = vec![extend(major_locations[1], major_locations[0])];
+    bounds.extend_from_slice(major_locations);
+    bounds.push(extend(major_locations[num_majors - 2], major_locations[num_majors - 1]));
+
+    let mut locations = Vec::<f64>::new();
+    for pair in bounds.windows(2) {
+        for k in 1..num_intervals {
+            locations.push(utils::map_range(
+                k as f64,
+                0.0,
+                num_intervals as f64,
+                pair[0],
+                pair[1],
+            ));
+        }
+    }
+    locations
+}
+
+/// Return about `num_marks` locations at a multiple of 1, 2 or 5 times a power of ten, covering
+/// `[data_min, data_max]`.
+///
+/// ### Method
+///
+/// 1. Find the order of magnitude of the desired distance between the marks. Call this *p*.
+/// 2. Let the mark distance be the desired distance rounded to the nearest *k 10^p*, for the k in
+///    (1, 2, 5) that gives the smallest rounding error.
+/// 3. Let the first mark be data_min rounded down to the nearest *k 10^p*.
+/// 4. Then, let mark_k = first_mark + k*mark_distance, for k = 0 until mark_k is greater than
+///    data_max.
+fn auto_locations(data_min: f64, data_max: f64, num_marks: usize) -> Result<Vec<f64>, Error> {
+    let data_diff = data_max - data_min;
+    let ca_dist = data_diff / (num_marks.max(2) as f64 - 1.0);
+    let omagn = utils::order_of_magnitude(ca_dist);
+
+    // Find for what k in (1, 2, 5) we shall round to the nearest ten power of
+    let mut smallest_diff = f64::MAX;
+    let mut round_number = 0f64;
+    for &i in &[1.0, 2.0, 5.0] {
+        let nearest = utils::round_nearest(ca_dist, omagn, i);
+        let diff = (ca_dist - nearest).abs();
+        if diff < smallest_diff {
+            smallest_diff = diff;
+            round_number = i;
+        }
+    }
+
+    let actual_min_point = utils::round_down(data_min, omagn, round_number);
+    let mark_distance = utils::round_nearest(ca_dist, omagn, round_number);
+    if mark_distance.is_nan() || mark_distance <= 0.0 {
+        return Err(err_msg(format!(
+            "Could not place marks on the data range [{}, {}]",
+            data_min, data_max
+        )));
+    }
+
+    let mut data_locations = vec![actual_min_point];
+    let mut data_location_k = actual_min_point;
+    while data_location_k <= data_max {
+        if data_locations.len() > MAX_NUM_MARKS {
+            return Err(err_msg("Too many marks on the axis"));
+        }
+        data_location_k += mark_distance;
+        data_locations.push(data_location_k);
+    }
+    Ok(data_locations)
+}
+
+/// Return at most `num_marks` locations at a multiple of 1, 2 or 5 times a power of ten, covering
+/// `[data_min, data_max]`. The smallest such step is used.
+fn max_n_locations(data_min: f64, data_max: f64, num_marks: usize) -> Result<Vec<f64>, Error> {
+    let num_marks = num_marks.max(2);
+    let min_step = (data_max - data_min) / (num_marks as f64 - 1.0);
+    let mut omagn = utils::order_of_magnitude(min_step);
+    loop {
+        for &i in &[1.0, 2.0, 5.0] {
+            let step = i * 10.0_f64.powi(omagn);
+            let (first_index, last_index) = step_indices(data_min, data_max, step);
+            if last_index - first_index < num_marks as f64 {
+                return stepped_locations(data_min, data_max, step);
+            }
+        }
+        omagn += 1;
+    }
+}
+
+/// Return the locations at every integer multiple of `step`, from the last one at or below
+/// `data_min`, to the first one at or above `data_max`.
+fn stepped_locations(data_min: f64, data_max: f64, step: f64) -> Result<Vec<f64>, Error> {
+    let (first_index, last_index) = step_indices(data_min, data_max, step);
+    if last_index - first_index >= MAX_NUM_MARKS as f64 {
+        return Err(err_msg(format!(
+            "A step of {} gives too many marks on the data range [{}, {}]",
+            step, data_min, data_max
+        )));
+    }
+    let num_steps = (last_index - first_index) as usize;
+    Ok((0..num_steps + 1)
+        .map(|k| (first_index + k as f64) * step)
+        .collect())
+}
+
+/// Return the indices of the multiples of `step` at or just below `data_min`, and at or just above
+/// `data_max`
+fn step_indices(data_min: f64, data_max: f64, step: f64) -> (f64, f64) {
+    (
+        (data_min / step + STEP_TOLERANCE).floor(),
+        (data_max / step - STEP_TOLERANCE).ceil(),
+    )
+}
@@ -22,7 +22,6 @@ pub struct Mark {
 
 impl Mark {
     /// Create and return a new mark
-    #[allow(dead_code)]
     pub fn new() -> Mark {
         let mut label = label::Label::new();
         label.set_color_internal(color::CustomColor::TickLabel.as_srgba());
@@ -36,6 +35,7 @@ impl Mark {
     }
 
     /// Create and return a new mark
+    #[allow(dead_code)]
     pub fn with_location(coord: coord::Coord) -> Mark {
         let mut label = label::Label::new();
         label.set_color_internal(color::CustomColor::TickLabel.as_srgba());
@@ -49,7 +49,6 @@ impl Mark {
     }
 
     /// Set local mark coordinate
-    #[allow(dead_code)]
     pub fn set_local(&mut self, coord: coord::Coord) {
         self.local = coord;
     }
@@ -100,6 +99,11 @@ impl Mark {
         self.label.set_frame_gaps(left, right, bottom, top);
     }
 
+    /// Set the angle of the tick label, with zero at horisontal, and increasing counterclockwise.
+    pub fn set_label_angle(&mut self, angle: f64) {
+        self.label.set_angle(angle);
+    }
+
     /// Set the centroid of the associated tick label.
     pub fn set_label_centroid(&mut self, x_coord: f64, y_coord: f64) {
         self.label.set_centroid(x_coord, y_coord);
@@ -110,13 +114,12 @@ impl Mark {
         self.tick.set_color_internal(color);
     }
 
-    #[allow(dead_code)] // TODO: When axis becomes public
+    /// Set the tick width
     pub fn set_tick_width(&mut self, val: f64) {
         self.tick.set_width(val);
     }
 
     /// Set the length of the tick, in both positive and negative extent
-    #[allow(dead_code)] // TODO: When axis becomes public
     pub fn set_tick_length(&mut self, val: f64) {
         self.tick.set_length(val);
     }
@@ -139,7 +142,6 @@ impl Mark {
     }
 
     /// Return the local coordinate
-    #[allow(dead_code)] // TODO: When axis becomes public
     pub fn local_coord(&self) -> coord::Coord {
         self.local.clone()
     }
@@ -172,7 +174,6 @@ impl Mark {
     }
 
     /// Return the label
-    #[allow(dead_code)] // TODO: Issue #13
     pub fn label(&self) -> label::Label {
         self.label.clone()
     }
@@ -259,13 +260,11 @@ impl Tick {
     }
 
     /// Set the tick width
-    #[allow(dead_code)] // TODO: When axis becomes public
     pub fn set_width(&mut self, val: f64) {
         self.width = val;
     }
 
     /// Set both the positive and negative tick length
-    #[allow(dead_code)] // TODO: When axis becomes public
     pub fn set_length(&mut self, val: f64) {
         self.positive_length = val;
         self.negative_length = val;
@@ -6,7 +6,8 @@ use failure::Error;
 
 use cairo::{Context, FontSlant, FontWeight};
 
-use {canvas, chart, color, label, shape};
+use {axis, canvas, category, chart, color, colorbar, formatter, label, layout, legend, locator,
+     scale, shape};
 
 /// Determines a single plot. A plot is part of a figure, and contains a canvas where things are
 /// drawn. By default, there is some space around the canvas, to make space for labels, ticks,
@@ -16,6 +17,7 @@ pub struct Plot {
     title: label::Label,
     color: color::Color,
     local_frame: shape::Rectangle,
+    grid_cell: Option<layout::GridCell>,
     canvas: canvas::Canvas,
 }
 
@@ -40,6 +42,7 @@ impl Plot {
             title: title,
             color: color::Color::with_custom(&color::CustomColor::PlotBackground),
             local_frame: local_frame,
+            grid_cell: None,
             canvas: canvas::Canvas::new(),
         }
     }
@@ -93,7 +96,8 @@ impl Plot {
 
     /// Set gaps around plot title.
     ///
-    /// NOTE: This has currently no visible effect
+    /// NOTE: This only has a visible effect with a tight layout, see `Figure::set_tight_layout()`,
+    /// where the gaps are kept free around the title.
     pub fn set_title_frame_gaps(&mut self, left: f64, right: f64, bottom: f64, top: f64) -> &mut Self {
         self.title.set_frame_gaps(left, right, bottom, top);
         self
@@ -228,6 +232,30 @@ impl Plot {
         self.local_frame.set_top(val);
     }
 
+    /// Place the plot in a cell of the figure grid. Its local frame is computed by the figure.
+    pub(crate) fn set_grid_cell(&mut self, cell: layout::GridCell) {
+        self.grid_cell = Some(cell);
+    }
+
+    pub(crate) fn grid_cell(&self) -> Option<layout::GridCell> {
+        self.grid_cell.clone()
+    }
+
+    /// Return the data frame of the canvas, without any data ranges shared with other plots
+    pub(crate) fn unshared_data_frame(&self) -> shape::Rectangle {
+        self.canvas.unshared_data_frame()
+    }
+
+    /// Share the horizontal data range with other plots
+    pub(crate) fn set_shared_x_range(&mut self, x_min: f64, x_max: f64) {
+        self.canvas.set_shared_x_range(x_min, x_max);
+    }
+
+    /// Share the vertical data range with other plots
+    pub(crate) fn set_shared_y_range(&mut self, y_min: f64, y_max: f64) {
+        self.canvas.set_shared_y_range(y_min, y_max);
+    }
+
     /// Whether or not to display a border around the plot
     pub fn display_border(&mut self, val: bool) -> &mut Self {
         self.local_frame.display_border(val);
@@ -286,7 +314,8 @@ impl Plot {
     ///
     /// *Note*:
     /// This is a soft suggestion, and can be overwritten before the final result for aestethics.
-    /// See more [here](struct.Canvas.html#method.set_data_range).
+    /// See more [here](struct.Canvas.html#method.set_data_range). Use `set_hard_limits()` to
+    /// use the range exactly as it is.
     pub fn set_data_range(&mut self, x_min: f64, x_max: f64, y_min: f64, y_max: f64) -> &mut Self {
         self.canvas.set_data_range(x_min, x_max, y_min, y_max);
         self
@@ -352,6 +381,101 @@ impl Plot {
         self
     }
 
+    /// Whether or not to use the data range of both axes exactly as it is set, or as it is found
+    /// from the data. Marks are only placed inside the range, in stead of extending the range to
+    /// the nearest marks outside of it, and data outside of the range is clipped at the canvas
+    /// boundary.
+    pub fn set_hard_limits(&mut self, val: bool) -> &mut Self {
+        self.canvas.set_hard_x_limits(val);
+        self.canvas.set_hard_y_limits(val);
+        self
+    }
+
+    /// Whether or not to use the horizontal data range exactly as it is. See `set_hard_limits()`.
+    pub fn set_hard_x_limits(&mut self, val: bool) -> &mut Self {
+        self.canvas.set_hard_x_limits(val);
+        self
+    }
+
+    /// Whether or not to use the vertical data range exactly as it is. See `set_hard_limits()`.
+    pub fn set_hard_y_limits(&mut self, val: bool) -> &mut Self {
+        self.canvas.set_hard_y_limits(val);
+        self
+    }
+
+    /// Whether or not to invert the horizontal direction, such that the data locations decrease
+    /// to the right. This applies to the charts, the grid and every horizontal axis, also the
+    /// secondary one and those added with `add_axis()`.
+    pub fn invert_x_axis(&mut self, val: bool) -> &mut Self {
+        self.canvas.invert_x_axis(val);
+        self
+    }
+
+    /// Whether or not to invert the vertical direction, such that the data locations decrease
+    /// upwards, e.g. for depth profiles. This applies to the charts, the grid and every vertical
+    /// axis, also the secondary one and those added with `add_axis()`.
+    pub fn invert_y_axis(&mut self, val: bool) -> &mut Self {
+        self.canvas.invert_y_axis(val);
+        self
+    }
+
+    // ----------------- SCALE --------------------------------------------- //
+
+    /// Set the scale of the horizontal axis. The default is linear. See `Scale` for the options.
+    ///
+    /// Some scales only accept part of the real line, e.g. a logarithmic axis can only display
+    /// positive data. Data outside of the domain of the scale gives an error when the figure is
+    /// saved or shown.
+    pub fn set_x_scale(&mut self, scale: scale::Scale) -> &mut Self {
+        self.canvas.set_x_scale(scale);
+        self
+    }
+
+    /// Set the scale of the vertical axis. The default is linear. See `Scale` for the options.
+    ///
+    /// Some scales only accept part of the real line, e.g. a logarithmic axis can only display
+    /// positive data. Data outside of the domain of the scale gives an error when the figure is
+    /// saved or shown.
+    pub fn set_y_scale(&mut self, scale: scale::Scale) -> &mut Self {
+        self.canvas.set_y_scale(scale);
+        self
+    }
+
+    /// Whether or not to place unlabeled marks between the powers of logarithmic axes, e.g. at
+    /// 2, 3, ..., 9 times 10^n for base 10. The subdivisions are only placed when every power is
+    /// marked.
+    pub fn display_log_subdivisions(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_log_subdivisions(val);
+        self
+    }
+
+    /// Set the strftime format of the tick labels on the horizontal axis, when it is a time axis,
+    /// e.g. "%Y-%m-%d %H:%M". By default, the format is chosen from the spacing of the marks.
+    pub fn set_x_time_format(&mut self, format: &str) -> &mut Self {
+        self.canvas.set_x_time_format(format);
+        self
+    }
+
+    // ----------------- CATEGORIES ---------------------------------------- //
+
+    /// Set the order of the categories on categorical axes. By default, the categories are placed
+    /// in the order they first appear in the charts. See `CategoryOrder` for the options.
+    ///
+    /// An axis becomes categorical when a chart with categories is added, e.g. with
+    /// `Bar::with_categories()` or `Scatter::with_categories()`.
+    pub fn set_category_order(&mut self, order: category::CategoryOrder) -> &mut Self {
+        self.canvas.set_category_order(order);
+        self
+    }
+
+    /// Set the gap between neighbouring categories on categorical axes, as a fraction of the
+    /// distance between the category centers. Bars fill the rest of the distance, unless they
+    /// have a width of their own. The default is 0.2.
+    pub fn set_category_padding(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_category_padding(val);
+        self
+    }
+
     // ----------------- CANVAS -------------------------------------------- //
 
     /// Set local frame coordinates of the canvas (relative to its plot).
@@ -414,6 +538,59 @@ impl Plot {
         self
     }
 
+    /// Set where the default horizontal axis is placed: along the bottom of the canvas (default),
+    /// at zero or another value on the vertical axis, or outward from the canvas. See the
+    /// AxisPosition enum.
+    pub fn set_x_axis_position(&mut self, position: axis::AxisPosition) -> &mut Self {
+        self.canvas.set_x_axis_position(position);
+        self
+    }
+
+    /// Set where the default vertical axis is placed: along the left side of the canvas
+    /// (default), at zero or another value on the horizontal axis, or outward from the canvas. See
+    /// the AxisPosition enum.
+    pub fn set_y_axis_position(&mut self, position: axis::AxisPosition) -> &mut Self {
+        self.canvas.set_y_axis_position(position);
+        self
+    }
+
+    /// Whether or not to draw the line of the default horizontal axis, wherever it is placed. The
+    /// ticks and tick labels are drawn regardless.
+    pub fn display_bottom_spine(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_bottom_spine(val);
+        self
+    }
+
+    /// Whether or not to draw the line of the default vertical axis, wherever it is placed. The
+    /// ticks and tick labels are drawn regardless.
+    pub fn display_left_spine(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_left_spine(val);
+        self
+    }
+
+    /// Whether or not to draw a line along the top of the canvas. This is the line of the
+    /// secondary horizontal axis, if any chart is drawn against it, and it is only drawn by
+    /// default in that case.
+    pub fn display_top_spine(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_top_spine(val);
+        self
+    }
+
+    /// Whether or not to draw a line along the right side of the canvas. This is the line of the
+    /// secondary vertical axis, if any chart is drawn against it, and it is only drawn by default
+    /// in that case.
+    pub fn display_right_spine(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_right_spine(val);
+        self
+    }
+
+    /// Whether or not to draw a border around the whole canvas, also when the axes are placed
+    /// away from its edges
+    pub fn display_box_frame(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_box_frame(val);
+        self
+    }
+
     /// Set the line width of all axes
     pub fn set_axes_line_width(&mut self, val: f64) -> &mut Self {
         self.canvas.set_axes_line_width(val);
@@ -635,6 +812,84 @@ impl Plot {
         self
     }
 
+    // ----------------- SECONDARY AXES ------------------------------------ //
+
+    /// Set the label of the secondary horisontal axis, along the top of the canvas. The axis is
+    /// displayed when a chart is drawn against it, see `AxisPair`.
+    pub fn set_secondary_x_label(&mut self, content: &str) -> &mut Self {
+        self.canvas.set_secondary_x_axis_label_content(content);
+        self
+    }
+
+    /// Set the label of the secondary vertical axis, along the right side of the canvas. The axis
+    /// is displayed when a chart is drawn against it, see `AxisPair`.
+    pub fn set_secondary_y_label(&mut self, content: &str) -> &mut Self {
+        self.canvas.set_secondary_y_axis_label_content(content);
+        self
+    }
+
+    /// Set the data range of the secondary horisontal axis. By default, it spans the charts
+    /// drawn against it.
+    ///
+    /// *Note*:
+    /// This is a soft suggestion, and can be overwritten before the final result for aestethics.
+    /// See more [here](struct.Canvas.html#method.set_data_range).
+    pub fn set_secondary_x_range(&mut self, x_min: f64, x_max: f64) -> &mut Self {
+        self.canvas.set_secondary_x_range(x_min, x_max);
+        self
+    }
+
+    /// Set the data range of the secondary vertical axis. By default, it spans the charts drawn
+    /// against it.
+    ///
+    /// *Note*:
+    /// This is a soft suggestion, and can be overwritten before the final result for aestethics.
+    /// See more [here](struct.Canvas.html#method.set_data_range).
+    pub fn set_secondary_y_range(&mut self, y_min: f64, y_max: f64) -> &mut Self {
+        self.canvas.set_secondary_y_range(y_min, y_max);
+        self
+    }
+
+    /// Set the scale of the secondary horisontal axis. The default is linear.
+    pub fn set_secondary_x_scale(&mut self, scale: scale::Scale) -> &mut Self {
+        self.canvas.set_secondary_x_scale(scale);
+        self
+    }
+
+    /// Set the scale of the secondary vertical axis. The default is linear.
+    pub fn set_secondary_y_scale(&mut self, scale: scale::Scale) -> &mut Self {
+        self.canvas.set_secondary_y_scale(scale);
+        self
+    }
+
+    /// Set the locator of the marks on the secondary horisontal axis. See the Locator enum for
+    /// reference.
+    pub fn set_secondary_x_tick_locator(&mut self, locator: locator::Locator) -> &mut Self {
+        self.canvas.set_secondary_x_locator(locator);
+        self
+    }
+
+    /// Set the locator of the marks on the secondary vertical axis. See the Locator enum for
+    /// reference.
+    pub fn set_secondary_y_tick_locator(&mut self, locator: locator::Locator) -> &mut Self {
+        self.canvas.set_secondary_y_locator(locator);
+        self
+    }
+
+    /// Set the formatter of the tick labels on the secondary horisontal axis. See the Formatter
+    /// enum for reference.
+    pub fn set_secondary_x_tick_formatter(&mut self, formatter: formatter::Formatter) -> &mut Self {
+        self.canvas.set_secondary_x_formatter(formatter);
+        self
+    }
+
+    /// Set the formatter of the tick labels on the secondary vertical axis. See the Formatter
+    /// enum for reference.
+    pub fn set_secondary_y_tick_formatter(&mut self, formatter: formatter::Formatter) -> &mut Self {
+        self.canvas.set_secondary_y_formatter(formatter);
+        self
+    }
+
     // ----------------- TICKS --------------------------------------------- //
 
     /// Set the tick color
@@ -750,6 +1005,152 @@ impl Plot {
         self
     }
 
+    /// Set the angle of the tick labels on the horizontal axis, in radians, increasing
+    /// counterclockwise. By default, the labels are rotated when they would overlap, e.g. with
+    /// long category names.
+    pub fn set_x_tick_label_angle(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_x_tick_label_angle(val);
+        self
+    }
+
+    /// Set the angle of the tick labels on the vertical axis, in radians, increasing
+    /// counterclockwise.
+    pub fn set_y_tick_label_angle(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_y_tick_label_angle(val);
+        self
+    }
+
+    /// Whether or not to display the tick labels of the default horizontal axis. The ticks are
+    /// drawn regardless.
+    pub fn display_x_tick_labels(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_x_tick_labels(val);
+        self
+    }
+
+    /// Whether or not to display the tick labels of the default vertical axis. The ticks are drawn
+    /// regardless.
+    pub fn display_y_tick_labels(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_y_tick_labels(val);
+        self
+    }
+
+    /// Set the locator of the ticks on the horizontal axis, e.g. `Locator::FixedStep(0.5)`. By
+    /// default, this is `Locator::Auto(6)`. See the Locator enum for reference.
+    pub fn set_x_tick_locator(&mut self, locator: locator::Locator) -> &mut Self {
+        self.canvas.set_x_locator(locator);
+        self
+    }
+
+    /// Set the locator of the ticks on the vertical axis. See the Locator enum for reference.
+    pub fn set_y_tick_locator(&mut self, locator: locator::Locator) -> &mut Self {
+        self.canvas.set_y_locator(locator);
+        self
+    }
+
+    /// Set the locator of the minor ticks on the horizontal axis, e.g.
+    /// `Locator::Subdivisions(5)`. By default, there are no minor ticks.
+    pub fn set_x_minor_tick_locator(&mut self, locator: locator::Locator) -> &mut Self {
+        self.canvas.set_x_minor_locator(locator);
+        self
+    }
+
+    /// Set the locator of the minor ticks on the vertical axis. By default, there are no minor
+    /// ticks.
+    pub fn set_y_minor_tick_locator(&mut self, locator: locator::Locator) -> &mut Self {
+        self.canvas.set_y_minor_locator(locator);
+        self
+    }
+
+    /// Set the formatter of the tick labels on the horizontal axis, e.g. `Formatter::Percent(0)`
+    /// or `Formatter::custom(|x| format!("{} m", x))`. See the Formatter enum for reference.
+    pub fn set_x_tick_formatter(&mut self, formatter: formatter::Formatter) -> &mut Self {
+        self.canvas.set_x_formatter(formatter);
+        self
+    }
+
+    /// Set the formatter of the tick labels on the vertical axis. See the Formatter enum for
+    /// reference.
+    pub fn set_y_tick_formatter(&mut self, formatter: formatter::Formatter) -> &mut Self {
+        self.canvas.set_y_formatter(formatter);
+        self
+    }
+
+    /// Set the tick length
+    pub fn set_tick_length(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_tick_length(val);
+        self
+    }
+
+    /// Set the tick width
+    pub fn set_tick_width(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_tick_width(val);
+        self
+    }
+
+    /// Set the minor tick length
+    pub fn set_minor_tick_length(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_minor_tick_length(val);
+        self
+    }
+
+    /// Set the minor tick width
+    pub fn set_minor_tick_width(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_minor_tick_width(val);
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.canvas.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.canvas.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgba(
+        &mut self,
+        red: f32,
+        green: f32,
+        blue: f32,
+        alpha: f32,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.canvas.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.canvas.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_rgba_u8(
+        &mut self,
+        red: u8,
+        green: u8,
+        blue: u8,
+        alpha: u8,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.canvas.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor tick color
+    pub fn set_minor_tick_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.canvas.set_minor_tick_color_internal(color.as_srgba());
+        self
+    }
+
     // ----------------- CANVAS GRID --------------------------------------- //
 
     /// Whether or not to display horizontal gridlines
@@ -810,6 +1211,76 @@ impl Plot {
         self
     }
 
+    /// Whether or not to display horizontal gridlines at the minor ticks
+    pub fn display_minor_horizontal_gridlines(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_minor_horizontal_gridlines(val);
+        self
+    }
+
+    /// Whether or not to display vertical gridlines at the minor ticks
+    pub fn display_minor_vertical_gridlines(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_minor_vertical_gridlines(val);
+        self
+    }
+
+    /// Set the line width of the minor gridlines
+    pub fn set_minor_gridline_width(&mut self, val: f64) -> &mut Self {
+        self.canvas.set_minor_gridline_width(val);
+        self
+    }
+
+    /// Set the minor grid line color
+    pub fn set_minor_grid_color(&mut self, color: &color::CustomColor) -> &mut Self {
+        self.canvas.set_minor_grid_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor grid line color
+    pub fn set_minor_grid_color_rgb(&mut self, red: f32, green: f32, blue: f32) -> &mut Self {
+        let color = color::Color::with_rgb(red, green, blue);
+        self.canvas.set_minor_grid_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor grid line color
+    pub fn set_minor_grid_color_rgba(
+        &mut self,
+        red: f32,
+        green: f32,
+        blue: f32,
+        alpha: f32,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba(red, green, blue, alpha);
+        self.canvas.set_minor_grid_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor grid line color
+    pub fn set_minor_grid_color_rgb_u8(&mut self, red: u8, green: u8, blue: u8) -> &mut Self {
+        let color = color::Color::with_rgb_u8(red, green, blue);
+        self.canvas.set_minor_grid_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor grid line color
+    pub fn set_minor_grid_color_rgba_u8(
+        &mut self,
+        red: u8,
+        green: u8,
+        blue: u8,
+        alpha: u8,
+    ) -> &mut Self {
+        let color = color::Color::with_rgba_u8(red, green, blue, alpha);
+        self.canvas.set_minor_grid_color_internal(color.as_srgba());
+        self
+    }
+
+    /// Set the minor grid line color
+    pub fn set_minor_grid_color_html(&mut self, color: &color::HtmlColor) -> &mut Self {
+        self.canvas.set_minor_grid_color_internal(color.as_srgba());
+        self
+    }
+
     // ----------------- GETTERS ------------------------------------------- //
 
     /// Return the frame of the plot, relative to the figure
@@ -855,6 +1326,42 @@ impl Plot {
         self
     }
 
+    /// Display a legend on the canvas, listing the charts that have a label, e.g. from
+    /// `Line::set_label()`. See the Legend struct for its placement and appearance.
+    pub fn set_legend(&mut self, legend: &legend::Legend) -> &mut Self {
+        self.canvas.set_legend(legend.clone());
+        self
+    }
+
+    /// Whether or not to display a legend, listing the charts that have a label. A default legend
+    /// is used, unless one is set with `set_legend()`.
+    pub fn display_legend(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_legend(val);
+        self
+    }
+
+    /// Display a colorbar next to the canvas, showing the colormap of an image or a heatmap, with
+    /// its value range and value scale. See the Colorbar struct for its placement and appearance.
+    pub fn set_colorbar(&mut self, colorbar: &colorbar::Colorbar) -> &mut Self {
+        self.canvas.set_colorbar(colorbar.clone());
+        self
+    }
+
+    /// Whether or not to display a colorbar next to the canvas. A default colorbar is used,
+    /// unless one is set with `set_colorbar()`.
+    pub fn display_colorbar(&mut self, val: bool) -> &mut Self {
+        self.canvas.display_colorbar(val);
+        self
+    }
+
+    /// Add an axis to the plot, in addition to the default axes. The axis is defined in data
+    /// coordinates, e.g. `Axis::new(f64::NEG_INFINITY, 0.0, f64::INFINITY, 0.0)` through the
+    /// origin, and has its own marks, label and tick style. See the Axis struct for reference.
+    pub fn add_axis(&mut self, axis: &axis::Axis) -> &mut Self {
+        self.canvas.add_axis(axis.clone());
+        self
+    }
+
     fn scale_size(&mut self, factor: f64) {
         self.local_frame.scale_size(factor);
         self.title.scale_size(factor);
@@ -877,6 +1384,60 @@ impl Plot {
         Ok(())
     }
 
+    /// Fit the plot with a tight layout. The texts around the canvas are measured as they will be
+    /// drawn, and the canvas frame is adjusted until they fit within the plot, below the plot
+    /// title, without being clipped or overlapping it.
+    pub(crate) fn fit_tight(
+        &mut self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+    ) -> Result<(), Error> {
+        let padding = layout::TIGHT_LAYOUT_PADDING;
+        self.canvas.set_tight_layout(true);
+        for _ in 0..layout::TIGHT_LAYOUT_ITERATIONS {
+            let mut plot = self.clone();
+            plot.fit()?;
+
+            let mut room = shape::Rectangle::with_boundaries(
+                self.left() + padding,
+                self.right() - padding,
+                self.bottom() + padding,
+                self.top() - padding,
+            );
+            if let Some(title) = plot.title.extent(cr, fig_rel_height, fig_rel_width) {
+                let room_top = room.top().min(title.bottom() - padding);
+                room.set_top(room_top);
+            }
+
+            self.canvas.clear_axis_labels(&plot.canvas, cr, fig_rel_height, fig_rel_width);
+
+            let canvas_frame = plot.canvas.global_frame();
+            let mut extents = plot.canvas.text_extents(cr, fig_rel_height, fig_rel_width);
+            extents.push(canvas_frame.clone());
+            let used = layout::bounding_frame(&extents);
+
+            // Every side of the canvas is moved by the amount the texts on that side overflow
+            // the room, or by the amount of room that is left unused
+            let left = canvas_frame.left() + room.left() - used.left();
+            let right = canvas_frame.right() + room.right() - used.right();
+            let bottom = canvas_frame.bottom() + room.bottom() - used.bottom();
+            let top = canvas_frame.top() + room.top() - used.top();
+            if left >= right || bottom >= top {
+                // The plot is too small for its texts, so the last canvas frame is kept
+                break;
+            }
+            self.canvas.set_local_frame(
+                (left - self.left()) / self.width(),
+                (right - self.left()) / self.width(),
+                (bottom - self.bottom()) / self.height(),
+                (top - self.bottom()) / self.height(),
+            );
+        }
+
+        self.fit()
+    }
+
     /// Do the actual drawing of the plot
     pub(crate) fn draw(&self, cr: &Context, fig_rel_height: f64, fig_rel_width: f64) {
         // Fill background
@@ -0,0 +1,342 @@
+//! Definition of the Scale enum and the AxisTransform trait
+//!
+
+use std::f64;
+use std::fmt;
+use std::rc::Rc;
+
+use utils;
+
+/// ## AxisTransform
+///
+/// A transform maps data coordinates to a space where the axis is linear, and back. All charts
+/// map their data to the canvas through the transform of the canvas axes, and the marks of an axis
+/// are placed by its transform.
+///
+/// Implement this trait to use a custom transform with `Scale::Custom`. For simple transforms,
+/// `Scale::custom` takes a pair of closures in stead.
+pub trait AxisTransform: fmt::Debug {
+    /// Map a data coordinate to the space where the axis is linear
+    fn forward(&self, val: f64) -> f64;
+
+    /// Map a coordinate in the space where the axis is linear back to data coordinates
+    fn inverse(&self, val: f64) -> f64;
+
+    /// Whether or not a data coordinate can be displayed with this transform
+    fn in_domain(&self, val: f64) -> bool {
+        self.forward(val).is_finite()
+    }
+
+    /// Return the marks of an axis spanning `[data_min, data_max]`, as `(data location, label)`
+    /// pairs. `num_marks` is a guide to how many marks are wanted.
+    ///
+    /// By default, the marks are evenly spaced in the transformed space, and rounded to two
+    /// significant digits in the data space.
+    fn marks(&self, data_min: f64, data_max: f64, num_marks: usize) -> Vec<(f64, String)> {
+        evenly_spaced_marks(self, data_min, data_max, num_marks)
+    }
+}
+
+/// Return marks that are evenly spaced in the transformed space, rounded to two significant digits
+fn evenly_spaced_marks<T: AxisTransform + ?Sized>(
+    transform: &T,
+    data_min: f64,
+    data_max: f64,
+    num_marks: usize,
+) -> Vec<(f64, String)> {
+    let transformed_min = transform.forward(data_min);
+    let transformed_max = transform.forward(data_max);
+    let num_intervals = num_marks.max(2) - 1;
+    let mut marks = Vec::<(f64, String)>::new();
+    for k in 0..num_intervals + 1 {
+        let transformed_location = utils::map_range(
+            k as f64,
+            0.0,
+            num_intervals as f64,
+            transformed_min,
+            transformed_max,
+        );
+        let data_location = transform.inverse(transformed_location);
+        let rounding = 10.0_f64.powi(utils::order_of_magnitude(data_location) - 1);
+        let rounded_location = (data_location / rounding).round() * rounding;
+        let data_location = if rounded_location >= data_min.min(data_max)
+            && rounded_location <= data_min.max(data_max)
+        {
+            rounded_location
+        } else {
+            data_location
+        };
+        let is_duplicate = marks
+            .last()
+            .map_or(false, |&(prev, _)| (prev - data_location).abs() < f64::EPSILON);
+        if !is_duplicate {
+            marks.push((data_location, utils::prettify(data_location)));
+        }
+    }
+    marks
+}
+
+/// Return the exponent written in superscript digits, e.g. "⁻³"
+pub(crate) fn superscript(exponent: i32) -> String {
+    exponent
+        .to_string()
+        .chars()
+        .map(|digit| match digit {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
+/// A transform given by a pair of closures
+struct FnTransform {
+    forward: Box<dyn Fn(f64) -> f64>,
+    inverse: Box<dyn Fn(f64) -> f64>,
+}
+
+impl fmt::Debug for FnTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FnTransform")
+    }
+}
+
+impl AxisTransform for FnTransform {
+    fn forward(&self, val: f64) -> f64 {
+        (self.forward)(val)
+    }
+
+    fn inverse(&self, val: f64) -> f64 {
+        (self.inverse)(val)
+    }
+}
+
+/// The scale of an axis, which determines how data coordinates are mapped to the canvas.
+///
+/// | Variant      | Transform                                                          |
+/// | ------------ | ------------------------------------------------------------------ |
+/// | Linear       | None                                                               |
+/// | Log10        | Logarithm with base 10. Only positive data.                        |
+/// | Log2         | Logarithm with base 2. Only positive data.                         |
+/// | Ln           | Natural logarithm. Only positive data.                             |
+/// | SymLog(t)    | Linear in `[-t, t]`, logarithmic outside, for t > 0. Any data.     |
+/// | Logit        | `ln(p / (1 - p))`, for e.g. probabilities. Only data in `(0, 1)`.  |
+/// | Custom(..)   | Any `AxisTransform`, e.g. from `Scale::custom`                     |
+///
+/// With a logarithmic scale, equal ratios in the data are equal distances on the canvas.
+#[derive(Clone, Debug)]
+pub enum Scale {
+    Linear,
+    Log10,
+    Log2,
+    Ln,
+    SymLog(f64),
+    Logit,
+    Custom(Rc<dyn AxisTransform>),
+}
+
+impl Scale {
+    /// Create and return a custom scale from a forward transform and its inverse. The forward
+    /// transform maps data coordinates to the space where the axis is linear.
+    pub fn custom<F, G>(forward: F, inverse: G) -> Scale
+    where
+        F: Fn(f64) -> f64 + 'static,
+        G: Fn(f64) -> f64 + 'static,
+    {
+        Scale::Custom(Rc::new(FnTransform {
+            forward: Box::new(forward),
+            inverse: Box::new(inverse),
+        }))
+    }
+
+    /// Return the base of the logarithm, or None if this is not a logarithmic scale
+    pub(crate) fn base(&self) -> Option<f64> {
+        match *self {
+            Scale::Log10 => Some(10.0),
+            Scale::Log2 => Some(2.0),
+            Scale::Ln => Some(f64::consts::E),
+            _ => None,
+        }
+    }
+
+    /// Whether or not this is a logarithmic scale
+    pub(crate) fn is_log(&self) -> bool {
+        self.base().is_some()
+    }
+
+    /// Whether or not this is a linear scale
+    pub(crate) fn is_linear(&self) -> bool {
+        match *self {
+            Scale::Linear => true,
+            _ => false,
+        }
+    }
+
+    /// Map a data coordinate in `[data_min, data_max]` to `[canvas_min, canvas_max]`, according
+    /// to the scale.
+    pub(crate) fn map_range(
+        &self,
+        val: f64,
+        data_min: f64,
+        data_max: f64,
+        canvas_min: f64,
+        canvas_max: f64,
+    ) -> f64 {
+        utils::map_range(
+            self.forward(val),
+            self.forward(data_min),
+            self.forward(data_max),
+            canvas_min,
+            canvas_max,
+        )
+    }
+
+    /// Return the label of the mark at `base^exponent`, e.g. "10³".
+    pub(crate) fn power_label(&self, exponent: i32) -> String {
+        let base = match *self {
+            Scale::Log2 => "2",
+            Scale::Ln => "e",
+            _ => "10",
+        };
+        format!("{}{}", base, superscript(exponent))
+    }
+
+    /// Marks of a symmetric log axis are placed at zero and at powers of ten outside of the
+    /// linear region.
+    fn symlog_marks(
+        linear_threshold: f64,
+        data_min: f64,
+        data_max: f64,
+        num_marks: usize,
+    ) -> Vec<(f64, String)> {
+        let num_marks = num_marks.max(1);
+        let largest_magnitude = data_min.abs().max(data_max.abs());
+        if !(largest_magnitude > 0.0 && largest_magnitude.log10().is_finite()) {
+            // Only zero is within the data range, and there are no powers to place marks at
+            return vec![(0.0, String::from("0"))];
+        }
+        let min_exponent = linear_threshold.log10().ceil() as i32;
+        let max_exponent = largest_magnitude.log10().floor() as i32;
+        let num_signs = if data_min < 0.0 && data_max > 0.0 { 2 } else { 1 };
+        let num_powers =
+            (i64::from(max_exponent) - i64::from(min_exponent) + 1).max(0) as usize * num_signs;
+        let exponent_step = ((num_powers + num_marks - 1) / num_marks).max(1);
+
+        let mut marks = Vec::<(f64, String)>::new();
+        for exponent in (min_exponent..max_exponent + 1).rev().step_by(exponent_step) {
+            let location = -10.0_f64.powi(exponent);
+            if location >= data_min {
+                marks.push((location, format!("-10{}", superscript(exponent))));
+            }
+        }
+        if data_min <= 0.0 && data_max >= 0.0 {
+            marks.push((0.0, String::from("0")));
+        }
+        for exponent in (min_exponent..max_exponent + 1).step_by(exponent_step) {
+            let location = 10.0_f64.powi(exponent);
+            if location <= data_max {
+                marks.push((location, format!("10{}", superscript(exponent))));
+            }
+        }
+        marks.retain(|&(location, _)| location >= data_min && location <= data_max);
+        marks
+    }
+
+    /// Marks of a logit axis are placed at 0.5, and at powers of ten away from zero and one.
+    fn logit_marks(data_min: f64, data_max: f64, num_marks: usize) -> Vec<(f64, String)> {
+        let num_marks = num_marks.max(1);
+        let mut candidates = Vec::<(f64, String)>::new();
+        for decimals in (1..10).rev() {
+            let location = 10.0_f64.powi(-(decimals as i32));
+            candidates.push((location, format!("{:.*}", decimals, location)));
+        }
+        candidates.push((0.5, String::from("0.5")));
+        for decimals in 1..10 {
+            let location = 1.0 - 10.0_f64.powi(-(decimals as i32));
+            candidates.push((location, format!("{:.*}", decimals, location)));
+        }
+        candidates.retain(|&(location, _)| location >= data_min && location <= data_max);
+        let step = ((candidates.len() + num_marks - 1) / num_marks).max(1);
+        candidates.into_iter().step_by(step).collect()
+    }
+}
+
+impl AxisTransform for Scale {
+    fn forward(&self, val: f64) -> f64 {
+        match *self {
+            Scale::Linear => val,
+            Scale::Log10 | Scale::Log2 | Scale::Ln => {
+                val.log(self.base().expect("Logarithmic scale without base"))
+            }
+            Scale::SymLog(linear_threshold) => {
+                if val.abs() <= linear_threshold {
+                    val / linear_threshold
+                } else {
+                    val.signum() * (1.0 + (val.abs() / linear_threshold).log10())
+                }
+            }
+            Scale::Logit => (val / (1.0 - val)).ln(),
+            Scale::Custom(ref transform) => transform.forward(val),
+        }
+    }
+
+    fn inverse(&self, val: f64) -> f64 {
+        match *self {
+            Scale::Linear => val,
+            Scale::Log10 | Scale::Log2 | Scale::Ln => self.base()
+                .expect("Logarithmic scale without base")
+                .powf(val),
+            Scale::SymLog(linear_threshold) => {
+                if val.abs() <= 1.0 {
+                    val * linear_threshold
+                } else {
+                    val.signum() * linear_threshold * 10.0_f64.powf(val.abs() - 1.0)
+                }
+            }
+            Scale::Logit => 1.0 / (1.0 + (-val).exp()),
+            Scale::Custom(ref transform) => transform.inverse(val),
+        }
+    }
+
+    fn in_domain(&self, val: f64) -> bool {
+        match *self {
+            Scale::Linear => val.is_finite(),
+            Scale::SymLog(linear_threshold) => {
+                val.is_finite() && linear_threshold > 0.0 && linear_threshold.is_finite()
+            }
+            Scale::Log10 | Scale::Log2 | Scale::Ln => val.is_finite() && val > 0.0,
+            Scale::Logit => val > 0.0 && val < 1.0,
+            Scale::Custom(ref transform) => transform.in_domain(val),
+        }
+    }
+
+    fn marks(&self, data_min: f64, data_max: f64, num_marks: usize) -> Vec<(f64, String)> {
+        match *self {
+            Scale::SymLog(linear_threshold) => {
+                Scale::symlog_marks(linear_threshold, data_min, data_max, num_marks)
+            }
+            Scale::Logit => Scale::logit_marks(data_min, data_max, num_marks),
+            Scale::Custom(ref transform) => transform.marks(data_min, data_max, num_marks),
+            _ => evenly_spaced_marks(self, data_min, data_max, num_marks),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symlog_marks_of_zero_range() {
+        let marks = Scale::symlog_marks(100.0, 0.0, 0.0, 5);
+        assert_eq!(marks, vec![(0.0, String::from("0"))]);
+    }
+}
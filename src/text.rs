@@ -54,7 +54,6 @@ impl Text {
     }
 
     /// Return the text font size
-    #[allow(dead_code)] // TODO: Issue #13
     pub fn font_size(&self) -> f64 {
         self.font_size
     }
@@ -92,6 +91,54 @@ impl Text {
         self.font_size *= factor;
     }
 
+    /// Select the font of the text, and adjust its size to the figure. Return the font matrix
+    /// that was in use, such that it can be reset afterwards.
+    fn set_font(
+        &self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+        angle: f64,
+    ) -> Matrix {
+        cr.select_font_face(&self.font_family, self.font_slant, self.font_weight);
+
+        // Adjust font size
+        cr.set_font_size(self.font_size);
+        let font_matrix = cr.get_font_matrix();
+
+        // In case the text is rotated...
+        let norm_factor = angle.cos() + angle.sin();
+        let new_xx = font_matrix.xx * (angle.cos() * fig_rel_height + angle.sin() * fig_rel_width);
+        let new_yy = font_matrix.yy * (angle.sin() * fig_rel_height + angle.cos() * fig_rel_width);
+
+        cr.set_font_matrix(Matrix::new(
+            new_xx / norm_factor,
+            1.0 * font_matrix.yx,
+            1.0 * font_matrix.xy,
+            new_yy / norm_factor,
+            1.0 * font_matrix.x0,
+            1.0 * font_matrix.y0,
+        ));
+
+        font_matrix
+    }
+
+    /// Return the width and height of the text, along and across its direction, as it will be
+    /// drawn with the same arguments to `draw()`.
+    pub fn extents(
+        &self,
+        cr: &Context,
+        fig_rel_height: f64,
+        fig_rel_width: f64,
+        angle: f64,
+    ) -> (f64, f64) {
+        let font_matrix = self.set_font(cr, fig_rel_height, fig_rel_width, angle);
+        let extents = cr.text_extents(&self.content);
+        cr.set_font_matrix(font_matrix);
+
+        (extents.width, extents.height)
+    }
+
     /// Draw text
     #[allow(unknown_lints)]
     #[allow(too_many_arguments)]
@@ -114,7 +161,6 @@ impl Text {
         // NOTE 2: If the text angle is not in {0, pi/2, pi, 3pi/2}, it looks ugly when the figure
         // is not square.
 
-        cr.select_font_face(&self.font_family, self.font_slant, self.font_weight);
         let text_color = self.color.as_srgba();
         cr.set_source_rgba(
             f64::from(text_color.red),
@@ -123,23 +169,7 @@ impl Text {
             f64::from(text_color.alpha),
         );
 
-        // Adjust font size
-        cr.set_font_size(self.font_size);
-        let font_matrix = cr.get_font_matrix();
-
-        // In case the text is rotated...
-        let norm_factor = angle.cos() + angle.sin();
-        let new_xx = font_matrix.xx * (angle.cos() * fig_rel_height + angle.sin() * fig_rel_width);
-        let new_yy = font_matrix.yy * (angle.sin() * fig_rel_height + angle.cos() * fig_rel_width);
-
-        cr.set_font_matrix(Matrix::new(
-            new_xx / norm_factor,
-            1.0 * font_matrix.yx,
-            1.0 * font_matrix.xy,
-            new_yy / norm_factor,
-            1.0 * font_matrix.x0,
-            1.0 * font_matrix.y0,
-        ));
+        let font_matrix = self.set_font(cr, fig_rel_height, fig_rel_width, angle);
 
         let text_width = cr.text_extents(&self.content).width;
         let text_height = cr.text_extents(&self.content).height;
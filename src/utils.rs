@@ -247,6 +247,21 @@ pub fn map_range(old_number: f64, old_min: f64, old_max: f64, new_min: f64, new_
     }
 }
 
+/// Return the `q`-th percentile of `sorted_data`, with `q` in `[0, 100]`.
+///
+/// The data is assumed to be sorted in increasing order, and values between two data points are
+/// linearly interpolated. An empty input gives `NaN`.
+pub fn percentile(sorted_data: &[f64], q: f64) -> f64 {
+    if sorted_data.is_empty() {
+        return f64::NAN;
+    }
+    let rank = (q / 100.0).max(0.0).min(1.0) * (sorted_data.len() - 1) as f64;
+    let lower_index = rank.floor() as usize;
+    let upper_index = rank.ceil() as usize;
+    let fraction = rank - lower_index as f64;
+    sorted_data[lower_index] + (sorted_data[upper_index] - sorted_data[lower_index]) * fraction
+}
+
 /// Find the order of magnitude of a number.
 ///
 /// For a number **n > 0**, we define the order of magnitude **p** to be the integer such that
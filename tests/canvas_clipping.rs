@@ -0,0 +1,87 @@
+//! Tests that charts are clipped at the boundary of their canvas
+//!
+
+extern crate astrup;
+extern crate cairo;
+
+use std::env;
+use std::fs::File;
+
+use astrup::{Chart, Figure, Plot, Scatter};
+use cairo::ImageSurface;
+
+const FIGURE_WIDTH: usize = 1000;
+const FIGURE_HEIGHT: usize = 800;
+
+/// Return a figure with two large red points, one inside of the canvas, and one in the margin to
+/// the right of the canvas.
+///
+/// The default canvas spans [0.10, 0.95] of the plot in both directions, and with hard limits, the
+/// data range is exactly [0, 10] in both directions. The point at x = 9.0 is centered at 0.865 of
+/// the figure width, and the point at x = 10.4 is centered at 0.984 of the figure width.
+fn figure_with_points(clip_to_canvas: bool) -> Figure {
+    let mut scatter = Scatter::new(&[9.0, 10.4], &[5.0, 5.0]);
+    scatter.set_color_rgb(1.0, 0.0, 0.0)
+           .set_point_size(0.02)
+           .set_clip_to_canvas(clip_to_canvas);
+
+    let mut plot = Plot::new();
+    plot.set_x_range(0.0, 10.0)
+        .set_y_range(0.0, 10.0)
+        .set_hard_limits(true)
+        .add_chart(&Chart::Scatter(scatter));
+
+    let mut fig = Figure::new();
+    fig.set_width(FIGURE_WIDTH)
+       .set_height(FIGURE_HEIGHT)
+       .add_plot(&plot);
+    fig
+}
+
+/// Save the figure as a png image, and read it back
+fn render(fig: &mut Figure, file_name: &str) -> ImageSurface {
+    let path = env::temp_dir().join(file_name);
+    fig.save(path.to_str().expect("Invalid path"))
+        .expect("Could not save figure");
+    let mut file = File::open(&path).expect("Could not open image");
+    ImageSurface::create_from_png(&mut file).expect("Could not read image")
+}
+
+/// Whether or not the pixel at the relative figure location (x, y), with y increasing upwards, is
+/// red.
+fn is_red(surface: &mut ImageSurface, x: f64, y: f64) -> bool {
+    let column = (x * surface.get_width() as f64) as usize;
+    let row = ((1.0 - y) * surface.get_height() as f64) as usize;
+    let stride = surface.get_stride() as usize;
+    let offset = row * stride + 4 * column;
+    let data = surface.get_data().expect("Could not access image data");
+    // Pixels are stored as native endian 32 bit ARGB
+    let pixel = u32::from_ne_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ]);
+    let (red, green, blue) = ((pixel >> 16) & 0xff, (pixel >> 8) & 0xff, pixel & 0xff);
+    red > 200 && green < 60 && blue < 60
+}
+
+#[test]
+fn out_of_range_data_is_not_drawn_outside_of_the_canvas() {
+    let mut surface = render(&mut figure_with_points(true), "astrup_clipped.png");
+
+    // The point inside of the canvas is drawn
+    assert!(is_red(&mut surface, 0.865, 0.525));
+    // The point outside of the canvas is not drawn in the margin to the right of the canvas
+    assert!(!is_red(&mut surface, 0.975, 0.525));
+    assert!(!is_red(&mut surface, 0.984, 0.525));
+}
+
+#[test]
+fn unclipped_charts_are_drawn_outside_of_the_canvas() {
+    let mut surface = render(&mut figure_with_points(false), "astrup_unclipped.png");
+
+    assert!(is_red(&mut surface, 0.865, 0.525));
+    assert!(is_red(&mut surface, 0.975, 0.525));
+    assert!(is_red(&mut surface, 0.984, 0.525));
+}